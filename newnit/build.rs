@@ -0,0 +1,18 @@
+//! Sets the `linked` cfg on targets whose linker collects the units registered
+//! with `linkme` (see the `registry` module).
+
+/// Operating systems supported by the linker sections of `linkme`.
+const LINKED_OS: &[&str] = &[
+    "none", "linux", "macos", "ios", "tvos", "windows", "uefi", "android", "fuchsia", "illumos",
+    "freebsd", "openbsd", "psp",
+];
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(linked)");
+    println!("cargo::rerun-if-changed=build.rs");
+
+    let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if LINKED_OS.contains(&os.as_str()) {
+        println!("cargo::rustc-cfg=linked");
+    }
+}
@@ -0,0 +1,488 @@
+//! Units of plane angle.
+//!
+//! This module contains predefined newtypes for units of plane angle as
+//! defined in the International System of Units (SI). The base unit is the
+//! [`Radian`].
+//!
+//! Additional definitions provided for the degree (°), arcminute (′),
+//! arcsecond (″) and milliarcsecond (mas) accepted for use with the SI, the
+//! gradian (gon) and the turn (tr). Their factors are derived from
+//! [`Rational::PI`](crate::rational::Rational::PI), and as they aren't SI units
+//! they are of [`System::Other`](crate::unit::System::Other).
+//!
+//! As `Rational::PI` is truncated, conversions between these units and the
+//! radian are approximate, even with exact scalars (e.g. 180° converts to
+//! 3.141592653589793238 rad). Conversions among them don't involve π and are
+//! exact (e.g. 90° is exactly a quarter turn).
+//!
+//! The SI counts plane angles as dimensionless, but their units are of the
+//! dimension [`Angle`](dimension::Angle) here, so that angles aren't mistaken
+//! for ratios. Angles in degrees, minutes and seconds (e.g. `12°34'56.7"`) are
+//! displayed and parsed with [`Dms`].
+//!
+//! [`Radian`]: metric::Radian
+//!
+//! # Examples
+//! ```
+//! use newnit::angle::Angle;
+//! use newnit::angle::metric::{ArcSecond, Degree, Radian};
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::Meter;
+//!
+//! # #[cfg(feature = "std")] {
+//! let angle = Degree(30.0_f64);
+//! assert!((angle.sin() - 0.5).abs() < 1e-9);
+//!
+//! let slope: Degree = Degree::atan2(&Meter(1.0), &Foot(1.0 / 0.3048));
+//! assert!((slope.0 - 45.0).abs() < 1e-9);
+//! # }
+//!
+//! let angle: Radian = "12°34'56.7\"".parse().unwrap();
+//! assert_eq!(format!("{:.1}", angle.to_dms()), "12°34'56.7\"");
+//! assert!((ArcSecond::from(&angle).0 - 45_296.7).abs() < 1e-6);
+//! ```
+//!
+//! Trigonometry of angles stored as integers is a compile time error:
+//!
+//! ```compile_fail
+//! use newnit::angle::Angle;
+//! use newnit::angle::metric::Degree;
+//!
+//! let rounded = Degree(30_i32).sin();
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::parse::{ParseError, parse_unit};
+#[cfg(feature = "std")]
+use crate::scalar::Float;
+use crate::scalar::Scalar;
+use crate::solid_angle::metric::Steradian;
+use crate::{Quantity, Unit, dimension, parse};
+
+/// Types that are units of plane angle.
+///
+/// Provides trigonometric functions of the angle, constructors of angles from
+/// inverse trigonometric functions (both require the `std` feature and
+/// [`Float`](crate::scalar::Float) values, as integers would be rounded to whole radians), and
+/// multiplication methods, resulting in other units of quantity, based on the
+/// right hand side (`rhs`) in the multiplication.
+pub trait Angle<V: Scalar = f64>: Quantity<V, Dimension = dimension::Angle> {
+    /// Multiply a unit of plane angle with a unit of plane angle.
+    fn multiply_angle(&self, rhs: &dyn Angle<V>) -> Steradian<V> {
+        Steradian(self.to_base() * rhs.to_base())
+    }
+
+    /// The sine of the angle.
+    #[cfg(feature = "std")]
+    fn sin(&self) -> V
+    where
+        V: Float,
+    {
+        V::from_f64(self.to_base().to_f64().sin())
+    }
+
+    /// The cosine of the angle.
+    #[cfg(feature = "std")]
+    fn cos(&self) -> V
+    where
+        V: Float,
+    {
+        V::from_f64(self.to_base().to_f64().cos())
+    }
+
+    /// The tangent of the angle.
+    #[cfg(feature = "std")]
+    fn tan(&self) -> V
+    where
+        V: Float,
+    {
+        V::from_f64(self.to_base().to_f64().tan())
+    }
+
+    /// The angle whose sine is `ratio`, from -90° to 90°, or NaN if `ratio` is
+    /// outside of -1 to 1.
+    #[cfg(feature = "std")]
+    fn asin(ratio: V) -> Self
+    where
+        Self: Sized,
+        V: Float,
+    {
+        Self::from_base(V::from_f64(ratio.to_f64().asin()))
+    }
+
+    /// The angle whose cosine is `ratio`, from 0° to 180°, or NaN if `ratio`
+    /// is outside of -1 to 1.
+    #[cfg(feature = "std")]
+    fn acos(ratio: V) -> Self
+    where
+        Self: Sized,
+        V: Float,
+    {
+        Self::from_base(V::from_f64(ratio.to_f64().acos()))
+    }
+
+    /// The angle whose tangent is `ratio`, from -90° to 90°.
+    #[cfg(feature = "std")]
+    fn atan(ratio: V) -> Self
+    where
+        Self: Sized,
+        V: Float,
+    {
+        Self::from_base(V::from_f64(ratio.to_f64().atan()))
+    }
+
+    /// The angle of the point (`x`, `y`) from the positive `x` axis, from
+    /// -180° to 180° (see [`f64::atan2`]). The coordinates are quantities of
+    /// the same dimension, in any units (e.g. a rise and a run).
+    #[cfg(feature = "std")]
+    fn atan2<Y, X>(y: &Y, x: &X) -> Self
+    where
+        Self: Sized,
+        V: Float,
+        Y: Quantity<V> + ?Sized,
+        X: Quantity<V, Dimension = Y::Dimension> + ?Sized,
+    {
+        let angle = y.to_base().to_f64().atan2(x.to_base().to_f64());
+        Self::from_base(V::from_f64(angle))
+    }
+
+    /// The angle in degrees, minutes and seconds, for display.
+    fn to_dms(&self) -> Dms {
+        let arcseconds = metric::ArcSecond::<f64>::from_base(self.to_base().to_f64());
+        Dms {
+            arcseconds: arcseconds.0,
+        }
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Angle>> Angle<V> for T {}
+
+/// An angle in degrees, minutes and seconds of arc (e.g. `12°34'56.7"`).
+///
+/// Displays the seconds rounded to the precision of the formatter (e.g.
+/// `{:.1}`), or to at most three decimals without trailing zeros by default.
+/// Parses from the same notation, or from a value in any unit of angle (e.g.
+/// `12.5°`, `0.2 rad`).
+///
+/// # Examples
+/// ```
+/// use newnit::angle::Dms;
+/// use newnit::angle::metric::Degree;
+///
+/// let dms: Dms = "-33° 51′ 54″".parse().unwrap();
+/// assert_eq!(dms.to_string(), "-33°51'54\"");
+/// assert_eq!((dms.degrees(), dms.minutes()), (33, 51));
+/// assert!((Degree::from(&dms.to_angle()).0 + 33.865).abs() < 1e-9);
+///
+/// let dms: Dms = "1.5°".parse().unwrap();
+/// assert_eq!(format!("{dms:.2}"), "1°30'0.00\"");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Dms {
+    arcseconds: f64,
+}
+
+impl Dms {
+    /// Whether the angle is negative.
+    pub fn is_negative(&self) -> bool {
+        self.arcseconds < 0.0
+    }
+
+    /// The whole degrees of the magnitude of the angle, saturating at
+    /// [`u64::MAX`].
+    pub fn degrees(&self) -> u64 {
+        (self.arcseconds.abs() / 3600.0) as u64
+    }
+
+    /// The whole minutes of the magnitude of the angle, less the degrees.
+    pub fn minutes(&self) -> u32 {
+        (self.arcseconds.abs() / 60.0 % 60.0) as u32
+    }
+
+    /// The seconds of the magnitude of the angle, less the degrees and
+    /// minutes.
+    pub fn seconds(&self) -> f64 {
+        self.arcseconds.abs() % 60.0
+    }
+
+    /// The angle in arcseconds.
+    pub fn to_angle(&self) -> metric::ArcSecond {
+        metric::ArcSecond(self.arcseconds)
+    }
+}
+
+/// The largest integer up to which every `f64` integer is exact, 2⁵³.
+const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+
+/// The most decimals of arcseconds for which a whole turn in units of the last
+/// decimal still fits into a `u64`.
+const MAX_DECIMALS: usize = 15;
+
+impl fmt::Display for Dms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only as many decimals are computed as a `f64` holds exactly in units of
+        // the last decimal; any further requested ones are padded with zeros.
+        let magnitude = self.arcseconds.abs();
+        let requested = f.precision().unwrap_or(3);
+        let (mut decimals, mut scale) = (0, 1_u64);
+        while decimals < requested.min(MAX_DECIMALS) && magnitude * (scale * 10) as f64 <= MAX_EXACT
+        {
+            decimals += 1;
+            scale *= 10;
+        }
+        let padding = f.precision().map_or(0, |precision| precision - decimals);
+
+        // The magnitude is rounded once, in units of the last decimal, so the
+        // seconds never round up to 60.
+        let total = (magnitude * scale as f64 + 0.5) as u64;
+        let (degrees, rest) = (total / (3600 * scale), total % (3600 * scale));
+        let (minutes, rest) = (rest / (60 * scale), rest % (60 * scale));
+        let (seconds, mut fraction) = (rest / scale, rest % scale);
+
+        if f.precision().is_none() {
+            while decimals > 0 && fraction % 10 == 0 {
+                fraction /= 10;
+                decimals -= 1;
+            }
+        }
+
+        if self.is_negative() && total > 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{degrees}°{minutes}'{seconds}")?;
+        if decimals + padding > 0 {
+            write!(f, ".")?;
+        }
+        if decimals > 0 {
+            write!(f, "{fraction:0decimals$}")?;
+        }
+        write!(f, "{:0<padding$}\"", "")
+    }
+}
+
+impl FromStr for Dms {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let angle: metric::ArcSecond = parse_unit(s)?;
+        Ok(Self {
+            arcseconds: angle.0,
+        })
+    }
+}
+
+pub mod metric {
+    use super::Angle;
+    use crate::make_unit;
+    use crate::rational::Rational;
+    use newnit_derive::{Angle, Unit};
+
+    make_unit!(Radian, "rad", "radian", "radians", Metric, 1.0, Angle);
+    make_unit!(
+        MilliRadian,
+        "mrad",
+        "milliradian",
+        "milliradians",
+        Metric,
+        1E-3,
+        Angle
+    );
+    make_unit!(
+        MicroRadian,
+        "µrad",
+        "microradian",
+        "microradians",
+        Metric,
+        1E-6,
+        Angle
+    );
+
+    make_unit!(
+        #[unit(alias = "deg")]
+        Degree,
+        "°",
+        "degree",
+        "degrees",
+        Other,
+        Rational::PI / 180,
+        Angle
+    );
+    make_unit!(
+        #[unit(alias = "'", alias = "arcmin")]
+        ArcMinute,
+        "′",
+        "arcminute",
+        "arcminutes",
+        Other,
+        Rational::PI / 10_800,
+        Angle
+    );
+    make_unit!(
+        #[unit(alias = "\"", alias = "arcsec")]
+        ArcSecond,
+        "″",
+        "arcsecond",
+        "arcseconds",
+        Other,
+        Rational::PI / 648_000,
+        Angle
+    );
+    make_unit!(
+        MilliArcSecond,
+        "mas",
+        "milliarcsecond",
+        "milliarcseconds",
+        Other,
+        Rational::PI / 648_000_000,
+        Angle
+    );
+    make_unit!(
+        #[unit(alias = "grad")]
+        Gradian,
+        "gon",
+        "gradian",
+        "gradians",
+        Other,
+        Rational::PI / 200,
+        Angle
+    );
+    make_unit!(
+        #[unit(alias = "rev")]
+        Turn,
+        "tr",
+        "turn",
+        "turns",
+        Other,
+        2 * Rational::PI,
+        Angle
+    );
+}
+
+parse::units! {
+    /// Parse a plane angle in the unit named by its symbol (e.g. `"45°"`).
+    parse, UNITS: Angle, AngleUnit;
+    metric::{
+        Radian,
+        MilliRadian,
+        MicroRadian,
+        Degree,
+        ArcMinute,
+        ArcSecond,
+        MilliArcSecond,
+        Gradian,
+        Turn,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::approx::Tolerance;
+    use crate::assert_quantity_eq;
+    #[cfg(feature = "std")]
+    use crate::length::astronomical::{AstronomicalUnit, Parsec};
+    #[cfg(feature = "std")]
+    use crate::length::metric::Meter;
+    #[cfg(feature = "std")]
+    use crate::solid_angle::metric::SquareDegree;
+
+    #[test]
+    fn convert_exactly() {
+        assert_eq!(metric::ArcMinute::from(&metric::Degree(1.5)).0, 90.0);
+        assert_eq!(metric::Degree::from(&metric::Turn(0.25)).0, 90.0);
+        assert_eq!(metric::Gradian::from(&metric::Degree(9.0)).0, 10.0);
+        assert_eq!(
+            metric::MilliArcSecond::from(&metric::ArcSecond(2.0)).0,
+            2_000.0
+        );
+        assert_quantity_eq!(
+            metric::Degree(180.0),
+            metric::Radian(core::f64::consts::PI),
+            Tolerance::Absolute(1e-15)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn trigonometry() {
+        let angle = metric::Turn(0.125_f64);
+        assert!((angle.tan() - 1.0).abs() < 1e-9);
+        assert!((metric::Degree(60.0_f64).cos() - 0.5).abs() < 1e-9);
+        assert_quantity_eq!(metric::Degree::asin(0.5_f64), metric::Degree(30.0));
+        assert_quantity_eq!(metric::Degree::acos(-1.0_f64), metric::Degree(180.0));
+        assert_quantity_eq!(metric::Gradian::atan(1.0_f64), metric::Gradian(50.0));
+        let angle: metric::Degree = Angle::atan2(&Meter(-1.0), &Meter(-1.0));
+        assert_quantity_eq!(angle, metric::Degree(-135.0));
+
+        // A parsec is the distance at which one au subtends one arcsecond.
+        let parallax = metric::ArcSecond(1.0_f64);
+        let distance = AstronomicalUnit(1.0 / parallax.tan());
+        assert_quantity_eq!(distance, Parsec(1.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn multiply_angles() {
+        let field = metric::Degree(2.0_f64).multiply_angle(&metric::Degree(3.0));
+        assert_quantity_eq!(field, SquareDegree(6.0));
+
+        let square = metric::Radian(2.0_f64) * &metric::Radian(3.0);
+        assert_eq!(square.to_string(), "6 rad^2");
+        assert_eq!(
+            f64::from(square / &metric::Radian(2.0) / &metric::Radian(3.0)),
+            1.0
+        );
+        let per_angle = Meter(2.0_f64) / &metric::Radian(4.0);
+        assert_eq!(per_angle.to_string(), "0.5 m·rad^-1");
+        assert_quantity_eq!(Meter::from(&(per_angle * &metric::Radian(2.0))), Meter(1.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_dms() {
+        let dms = metric::Degree(12.582_416_666_666_667_f64).to_dms();
+        assert_eq!(dms.to_string(), "12°34'56.7\"");
+        assert_eq!(format!("{dms:.0}"), "12°34'57\"");
+        assert_eq!(format!("{dms:.3}"), "12°34'56.700\"");
+        assert_eq!(metric::Degree(-0.5).to_dms().to_string(), "-0°30'0\"");
+        assert_eq!(metric::Degree(0.0).to_dms().to_string(), "0°0'0\"");
+
+        // Seconds rounding up carry over into minutes and degrees.
+        let dms = metric::ArcSecond(3_599.96).to_dms();
+        assert_eq!(format!("{dms:.1}"), "1°0'0.0\"");
+        assert_eq!((dms.degrees(), dms.minutes()), (0, 59));
+        assert!((dms.seconds() - 59.96).abs() < 1e-9);
+
+        // The parts of large angles don't overflow.
+        let dms = metric::Turn(4_000.5).to_dms();
+        assert_eq!((dms.degrees(), dms.minutes()), (1_440_180, 0));
+        assert_eq!(dms.seconds(), 0.0);
+
+        // High precisions are padded rather than overflowing the scaling.
+        let dms: Dms = "1°30'".parse().unwrap();
+        assert_eq!(format!("{dms:.30}"), format!("1°30'0.{:030}\"", 0));
+        let dms = metric::Degree(0.0).to_dms();
+        assert_eq!(format!("{dms:.25}"), format!("0°0'0.{:025}\"", 0));
+        let dms = metric::ArcSecond(1e300).to_dms();
+        assert!(format!("{dms:.20}").ends_with(".00000000000000000000\""));
+    }
+
+    #[test]
+    fn parse_dms() {
+        let dms: Dms = "12°34'56.7\"".parse().unwrap();
+        assert_quantity_eq!(dms.to_angle(), metric::ArcSecond(45_296.7));
+        let angle: metric::Degree = "-12° 30′".parse().unwrap();
+        assert_eq!(angle, metric::Degree(-12.5));
+        assert_eq!("90 deg".parse(), Ok(metric::Turn(0.25)));
+        assert_eq!(
+            "1.5 rad".parse::<Dms>().unwrap().to_angle().0.round(),
+            309_397.0
+        );
+
+        assert_eq!("3 m".parse::<Dms>(), Err(ParseError::WrongQuantity));
+        assert_eq!("3 sr".parse::<Dms>(), Err(ParseError::WrongQuantity));
+        assert_eq!("12°34 m".parse::<Dms>(), Err(ParseError::MixedQuantities));
+    }
+}
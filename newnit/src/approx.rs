@@ -0,0 +1,304 @@
+//! Approximate equality of quantities.
+//!
+//! Values converted between units are rarely exactly equal, so quantities
+//! [compare](ApproxEq::approx_eq) approximately, in base units, within a
+//! [`Tolerance`]. Quantities in different units of the same quantity compare
+//! directly, while comparing different quantities fails to compile.
+//!
+//! [`assert_quantity_eq!`](crate::assert_quantity_eq) and
+//! [`assert_quantity_ne!`](crate::assert_quantity_ne) assert the approximate
+//! (in)equality of two quantities, printing both with their units on failure.
+//!
+//! With the `approx` feature, units also implement the `AbsDiffEq`,
+//! `RelativeEq` and `UlpsEq` traits of the [approx](https://docs.rs/approx)
+//! crate, comparing values of the same unit.
+//!
+//! # Examples
+//! ```
+//! use newnit::approx::{ApproxEq, Tolerance};
+//! use newnit::assert_quantity_eq;
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::{CentiMeter, Meter};
+//!
+//! assert!(Meter(0.3048).approx_eq(&Foot(1.0), Tolerance::Relative(1e-9)));
+//! assert!(!Meter(0.3).approx_eq(&Foot(1.0), Tolerance::Absolute(1e-3)));
+//!
+//! assert_quantity_eq!(Meter(0.3048), Foot(1.0));
+//! assert_quantity_eq!(Meter(0.3), Foot(1.0), Tolerance::Absolute(0.01));
+//! assert_quantity_eq!(CentiMeter(30.48), Foot(1.0), Tolerance::Ulps(4));
+//! ```
+//!
+//! Different quantities can't be compared:
+//!
+//! ```compile_fail
+//! use newnit::approx::{ApproxEq, Tolerance};
+//! use newnit::length::metric::Meter;
+//! use newnit::time::metric::Second;
+//!
+//! Meter(1.0).approx_eq(&Second(1.0), Tolerance::Relative(1e-9));
+//! ```
+
+use core::fmt;
+
+use crate::Quantity;
+use crate::dimension::BaseUnits;
+use crate::scalar::Scalar;
+
+#[cfg(feature = "approx")]
+pub use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Tolerance of an approximate comparison of quantities in base units.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Tolerance {
+    /// The values differ by at most the given amount in base units.
+    Absolute(f64),
+    /// The values differ by at most the given fraction of the larger of them.
+    Relative(f64),
+    /// The values are at most the given number of representable [`f64`]s
+    /// apart.
+    Ulps(u64),
+}
+
+impl Tolerance {
+    /// Whether the values `a` and `b` are equal within the tolerance.
+    pub fn accepts(self, a: f64, b: f64) -> bool {
+        if a == b {
+            return true;
+        }
+
+        match self {
+            Self::Absolute(tolerance) => (a - b).abs() <= tolerance,
+            Self::Relative(tolerance) => (a - b).abs() <= tolerance * a.abs().max(b.abs()),
+            Self::Ulps(ulps) => {
+                // Zeros of different sign are equal, so are caught above.
+                if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+                    return false;
+                }
+                a.to_bits().abs_diff(b.to_bits()) <= ulps
+            }
+        }
+    }
+}
+
+impl Default for Tolerance {
+    /// A relative tolerance of `1e-9`.
+    fn default() -> Self {
+        Self::Relative(1e-9)
+    }
+}
+
+/// Quantities that can be compared approximately.
+///
+/// Implemented for all quantities, including trait objects of quantity traits
+/// (e.g. `dyn Length`).
+pub trait ApproxEq<V: Scalar = f64>: Quantity<V> {
+    /// Whether `self` and `other` are equal within the `tolerance`, compared
+    /// in base units.
+    fn approx_eq<R>(&self, other: &R, tolerance: Tolerance) -> bool
+    where
+        R: Quantity<V, Dimension = Self::Dimension> + ?Sized,
+    {
+        tolerance.accepts(self.to_base().to_f64(), other.to_base().to_f64())
+    }
+}
+
+impl<V: Scalar, Q: Quantity<V> + ?Sized> ApproxEq<V> for Q {}
+
+/// Assert that two quantities are approximately equal (see [`ApproxEq`]).
+///
+/// Compares within the default [`Tolerance`] (relative `1e-9`), or the one
+/// given as the third argument, optionally followed by a panic message.
+///
+/// [`ApproxEq`]: crate::approx::ApproxEq
+/// [`Tolerance`]: crate::approx::Tolerance
+///
+/// # Examples
+/// ```
+/// use newnit::approx::Tolerance;
+/// use newnit::assert_quantity_eq;
+/// use newnit::length::imperial::Inch;
+/// use newnit::length::metric::MilliMeter;
+///
+/// assert_quantity_eq!(MilliMeter(25.4), Inch(1.0));
+/// assert_quantity_eq!(
+///     MilliMeter(25.0),
+///     Inch(1.0),
+///     Tolerance::Absolute(1e-3),
+///     "an inch is about {} mm",
+///     25
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_quantity_eq!(
+            $left,
+            $right,
+            <$crate::approx::Tolerance as ::core::default::Default>::default()
+        )
+    };
+
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx::assert_failed("≈", left, right, tolerance, None);
+                }
+            }
+        }
+    };
+
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx::assert_failed(
+                        "≈",
+                        left,
+                        right,
+                        tolerance,
+                        Some(::core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Assert that two quantities are not approximately equal (see
+/// [`assert_quantity_eq!`](crate::assert_quantity_eq)).
+#[macro_export]
+macro_rules! assert_quantity_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_quantity_ne!(
+            $left,
+            $right,
+            <$crate::approx::Tolerance as ::core::default::Default>::default()
+        )
+    };
+
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if $crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx::assert_failed("≉", left, right, tolerance, None);
+                }
+            }
+        }
+    };
+
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if $crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    $crate::approx::assert_failed(
+                        "≉",
+                        left,
+                        right,
+                        tolerance,
+                        Some(::core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Panic with both sides of a failed assertion.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_failed<L, R, V>(
+    op: &str,
+    left: &L,
+    right: &R,
+    tolerance: Tolerance,
+    message: Option<fmt::Arguments<'_>>,
+) -> !
+where
+    L: Quantity<V> + ?Sized,
+    R: Quantity<V> + ?Sized,
+    V: Scalar,
+{
+    let base = BaseUnits::of::<L::Dimension>();
+    let (left_value, left_symbol, left_base) = (left.to_value(), left.symbol(), left.to_base());
+    let (right_value, right_symbol, right_base) =
+        (right.to_value(), right.symbol(), right.to_base());
+    let sides = format_args!(
+        "  left: {left_value} {left_symbol} ({left_base} {base})\n right: {right_value} \
+         {right_symbol} ({right_base} {base})"
+    );
+
+    match message {
+        Some(message) => {
+            panic!("assertion `left {op} right` failed: {message} ({tolerance:?})\n{sides}")
+        }
+        None => panic!("assertion `left {op} right` failed ({tolerance:?})\n{sides}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::Length;
+    use crate::length::imperial::{Foot, Inch};
+    use crate::length::metric::Meter;
+    use crate::temperature::{Celsius, Fahrenheit};
+
+    #[test]
+    fn compare_across_units() {
+        assert!(Meter(0.3048).approx_eq(&Foot(1.0), Tolerance::Relative(1e-12)));
+        assert!(Celsius(100.0).approx_eq(&Fahrenheit(212.0), Tolerance::default()));
+
+        let length: &dyn Length = &Inch(12.0);
+        assert!(length.approx_eq(&Foot(1.0), Tolerance::Ulps(0)));
+        assert!(!length.approx_eq(&Foot(1.01), Tolerance::Relative(1e-3)));
+    }
+
+    #[test]
+    fn tolerances() {
+        assert!(Tolerance::Absolute(0.1).accepts(1.0, 1.1 - 1e-9));
+        assert!(!Tolerance::Absolute(0.1).accepts(1.0, 1.2));
+        assert!(Tolerance::Relative(0.01).accepts(100.0, 101.0));
+        assert!(!Tolerance::Relative(0.01).accepts(1.0, 1.02));
+
+        let next = f64::from_bits(1.0_f64.to_bits() + 2);
+        assert!(Tolerance::Ulps(2).accepts(1.0, next));
+        assert!(!Tolerance::Ulps(1).accepts(1.0, next));
+        assert!(Tolerance::Ulps(0).accepts(0.0, -0.0));
+        assert!(!Tolerance::Ulps(u64::MAX).accepts(f64::NAN, f64::NAN));
+    }
+
+    #[test]
+    fn assert_macros() {
+        assert_quantity_eq!(Inch(12.0), Foot(1.0));
+        assert_quantity_eq!(Meter(0.3), Foot(1.0), Tolerance::Absolute(0.01),);
+        assert_quantity_ne!(Meter(0.3), Foot(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "left: 0.3 m (0.3 m)\n right: 1 ft (0.3048 m)")]
+    fn print_units_on_failure() {
+        assert_quantity_eq!(Meter(0.3), Foot(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed: too short (Absolute(0.001))")]
+    fn print_message_on_failure() {
+        assert_quantity_eq!(
+            Meter(0.3),
+            Foot(1.0),
+            Tolerance::Absolute(1e-3),
+            "too {}",
+            "short"
+        );
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx_traits() {
+        ::approx::assert_relative_eq!(Meter(1.0), Meter(1.0 + 1e-12), max_relative = 1e-9);
+        ::approx::assert_abs_diff_eq!(Foot(1.0), Foot(1.05), epsilon = 0.1);
+        ::approx::assert_ulps_ne!(Meter(1.0), Meter(1.1));
+    }
+}
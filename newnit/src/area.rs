@@ -5,24 +5,91 @@
 //! - [`imperial`] - British Imperial units
 //! - [`metric`] - International System of Units (SI)
 
-use crate::Unit;
+use crate::force::metric::Newton;
+use crate::illuminance::Illuminance;
 use crate::length::Length;
 use crate::length::metric::Meter;
+use crate::luminance::Luminance;
+use crate::luminous_flux::metric::Lumen;
+use crate::luminous_intensity::metric::Candela;
+use crate::pressure::Pressure;
+use crate::scalar::Scalar;
 use crate::volume::metric::CubicMeter;
+use crate::{Quantity, dimension, parse};
 
 pub mod imperial;
 pub mod metric;
 
-pub trait Area: Unit {
+pub trait Area<V: Scalar = f64>: Quantity<V, Dimension = dimension::Area> {
     /// Multiply a unit of area with a unit of length
-    fn multiply_length(&self, rhs: &dyn Length) -> CubicMeter {
+    fn multiply_length(&self, rhs: &dyn Length<V>) -> CubicMeter<V> {
         CubicMeter(self.to_base() * rhs.to_base())
     }
 
     /// Divide a unit of area by a unit of length.
-    fn divide_length(&self, rhs: &dyn Length) -> Meter {
+    fn divide_length(&self, rhs: &dyn Length<V>) -> Meter<V> {
         Meter(self.to_base() / rhs.to_base())
     }
+
+    /// Multiply a unit of area with a unit of pressure.
+    fn multiply_pressure(&self, rhs: &dyn Pressure<V>) -> Newton<V> {
+        Newton(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of area with a unit of illuminance.
+    fn multiply_illuminance(&self, rhs: &dyn Illuminance<V>) -> Lumen<V> {
+        Lumen(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of area with a unit of luminance.
+    fn multiply_luminance(&self, rhs: &dyn Luminance<V>) -> Candela<V> {
+        Candela(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Area>> Area<V> for T {}
+
+parse::units! {
+    /// Parse an area in the unit named by its symbol (e.g. `"20 m²"`).
+    parse, UNITS: Area, AreaUnit;
+    metric::{
+        SquareQuettaMeter,
+        SquareRonnaMeter,
+        SquareYottaMeter,
+        SquareZettaMeter,
+        SquareExaMeter,
+        SquarePetaMeter,
+        SquareTeraMeter,
+        SquareGigaMeter,
+        SquareMegaMeter,
+        SquareKiloMeter,
+        SquareMeter,
+        SquareDeciMeter,
+        SquareCentiMeter,
+        SquareMilliMeter,
+        SquareMicroMeter,
+        SquareNanoMeter,
+        SquarePicoMeter,
+        SquareFemtoMeter,
+        SquareAttoMeter,
+        SquareZeptoMeter,
+        SquareYoctoMeter,
+        SquareRontoMeter,
+        SquareQuectoMeter,
+        SquareAngstrom,
+        Are,
+        HectAre,
+    },
+    imperial::{
+        SquareInch,
+        SquareFoot,
+        SquareYard,
+        SquareChain,
+        SquareFurlong,
+        SquareMile,
+        SquareLeague,
+        Acre,
+    },
 }
 
 #[cfg(test)]
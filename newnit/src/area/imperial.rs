@@ -4,17 +4,73 @@
 //! the British Imperial system. The base unit is the square meter.
 
 use super::Area;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Area, Unit};
 
 // Trivial squares of units of length
-make_unit!(SquareInch, 0.000_645_16, Area);
-make_unit!(SquareFoot, 0.092_903_04, Area);
-make_unit!(SquareYard, 0.836_127_36, Area);
-make_unit!(SquareChain, 404.685_642_24, Area);
-make_unit!(SquareFurlong, 40_468.564_224, Area);
-make_unit!(SquareMile, 2_589_988.110_34, Area);
-make_unit!(SquareLeague, 23_309_892.993_0, Area);
+make_unit!(
+    SquareInch,
+    "in²",
+    "square inch",
+    "square inches",
+    Imperial,
+    0.000_645_16,
+    Area
+);
+make_unit!(
+    SquareFoot,
+    "ft²",
+    "square foot",
+    "square feet",
+    Imperial,
+    0.092_903_04,
+    Area
+);
+make_unit!(
+    SquareYard,
+    "yd²",
+    "square yard",
+    "square yards",
+    Imperial,
+    0.836_127_36,
+    Area
+);
+make_unit!(
+    SquareChain,
+    "ch²",
+    "square chain",
+    "square chains",
+    Imperial,
+    404.685_642_24,
+    Area
+);
+make_unit!(
+    SquareFurlong,
+    "fur²",
+    "square furlong",
+    "square furlongs",
+    Imperial,
+    40_468.564_224,
+    Area
+);
+make_unit!(
+    SquareMile,
+    "mi²",
+    "square mile",
+    "square miles",
+    Imperial,
+    2_589_988.110_34,
+    Area
+);
+make_unit!(
+    SquareLeague,
+    "lea²",
+    "square league",
+    "square leagues",
+    Imperial,
+    23_309_892.993_0,
+    Area
+);
 
 // Non-trivial units
-make_unit!(Acre, 4_046.856_422_4, Area);
+make_unit!(Acre, "ac", "acre", "acres", Imperial, 4_046.856_422_4, Area);
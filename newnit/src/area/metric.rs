@@ -8,36 +8,228 @@
 //! - the Are and the HectAre
 
 use super::Area;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Area, Unit};
 
 // Trivial squares of units of length
-make_unit!(SquareQuettaMeter, 1E+60, Area);
-make_unit!(SquareRonnaMeter, 1E+54, Area);
-make_unit!(SquareYottaMeter, 1E+48, Area);
-make_unit!(SquareZettaMeter, 1E+42, Area);
-make_unit!(SquareExaMeter, 1E+36, Area);
-make_unit!(SquarePetaMeter, 1E+30, Area);
-make_unit!(SquareTeraMeter, 1E+24, Area);
-make_unit!(SquareGigaMeter, 1E+18, Area);
-make_unit!(SquareMegaMeter, 1E+12, Area);
-make_unit!(SquareKiloMeter, 1E+6, Area);
-make_unit!(SquareMeter, 1.0, Area);
-make_unit!(SquareDeciMeter, 1E-2, Area);
-make_unit!(SquareCentiMeter, 1E-4, Area);
-make_unit!(SquareMilliMeter, 1E-6, Area);
-make_unit!(SquareMicroMeter, 1E-12, Area);
-make_unit!(SquareNanoMeter, 1E-18, Area);
-make_unit!(SquarePicoMeter, 1E-24, Area);
-make_unit!(SquareFemtoMeter, 1E-30, Area);
-make_unit!(SquareAttoMeter, 1E-36, Area);
-make_unit!(SquareZeptoMeter, 1E-42, Area);
-make_unit!(SquareYoctoMeter, 1E-48, Area);
-make_unit!(SquareRontoMeter, 1E-54, Area);
-make_unit!(SquareQuectoMeter, 1E-60, Area);
+make_unit!(
+    SquareQuettaMeter,
+    "Qm²",
+    "square quettametre",
+    "square quettametres",
+    Metric,
+    1E+60,
+    Area
+);
+make_unit!(
+    SquareRonnaMeter,
+    "Rm²",
+    "square ronnametre",
+    "square ronnametres",
+    Metric,
+    1E+54,
+    Area
+);
+make_unit!(
+    SquareYottaMeter,
+    "Ym²",
+    "square yottametre",
+    "square yottametres",
+    Metric,
+    1E+48,
+    Area
+);
+make_unit!(
+    SquareZettaMeter,
+    "Zm²",
+    "square zettametre",
+    "square zettametres",
+    Metric,
+    1E+42,
+    Area
+);
+make_unit!(
+    SquareExaMeter,
+    "Em²",
+    "square exametre",
+    "square exametres",
+    Metric,
+    1E+36,
+    Area
+);
+make_unit!(
+    SquarePetaMeter,
+    "Pm²",
+    "square petametre",
+    "square petametres",
+    Metric,
+    1E+30,
+    Area
+);
+make_unit!(
+    SquareTeraMeter,
+    "Tm²",
+    "square terametre",
+    "square terametres",
+    Metric,
+    1E+24,
+    Area
+);
+make_unit!(
+    SquareGigaMeter,
+    "Gm²",
+    "square gigametre",
+    "square gigametres",
+    Metric,
+    1E+18,
+    Area
+);
+make_unit!(
+    SquareMegaMeter,
+    "Mm²",
+    "square megametre",
+    "square megametres",
+    Metric,
+    1E+12,
+    Area
+);
+make_unit!(
+    SquareKiloMeter,
+    "km²",
+    "square kilometre",
+    "square kilometres",
+    Metric,
+    1E+6,
+    Area
+);
+make_unit!(
+    SquareMeter,
+    "m²",
+    "square metre",
+    "square metres",
+    Metric,
+    1.0,
+    Area
+);
+make_unit!(
+    SquareDeciMeter,
+    "dm²",
+    "square decimetre",
+    "square decimetres",
+    Metric,
+    1E-2,
+    Area
+);
+make_unit!(
+    SquareCentiMeter,
+    "cm²",
+    "square centimetre",
+    "square centimetres",
+    Metric,
+    1E-4,
+    Area
+);
+make_unit!(
+    SquareMilliMeter,
+    "mm²",
+    "square millimetre",
+    "square millimetres",
+    Metric,
+    1E-6,
+    Area
+);
+make_unit!(
+    SquareMicroMeter,
+    "µm²",
+    "square micrometre",
+    "square micrometres",
+    Metric,
+    1E-12,
+    Area
+);
+make_unit!(
+    SquareNanoMeter,
+    "nm²",
+    "square nanometre",
+    "square nanometres",
+    Metric,
+    1E-18,
+    Area
+);
+make_unit!(
+    SquarePicoMeter,
+    "pm²",
+    "square picometre",
+    "square picometres",
+    Metric,
+    1E-24,
+    Area
+);
+make_unit!(
+    SquareFemtoMeter,
+    "fm²",
+    "square femtometre",
+    "square femtometres",
+    Metric,
+    1E-30,
+    Area
+);
+make_unit!(
+    SquareAttoMeter,
+    "am²",
+    "square attometre",
+    "square attometres",
+    Metric,
+    1E-36,
+    Area
+);
+make_unit!(
+    SquareZeptoMeter,
+    "zm²",
+    "square zeptometre",
+    "square zeptometres",
+    Metric,
+    1E-42,
+    Area
+);
+make_unit!(
+    SquareYoctoMeter,
+    "ym²",
+    "square yoctometre",
+    "square yoctometres",
+    Metric,
+    1E-48,
+    Area
+);
+make_unit!(
+    SquareRontoMeter,
+    "rm²",
+    "square rontometre",
+    "square rontometres",
+    Metric,
+    1E-54,
+    Area
+);
+make_unit!(
+    SquareQuectoMeter,
+    "qm²",
+    "square quectometre",
+    "square quectometres",
+    Metric,
+    1E-60,
+    Area
+);
 
-make_unit!(SquareAngstrom, 1E-20, Area);
+make_unit!(
+    SquareAngstrom,
+    "Å²",
+    "square ångström",
+    "square ångströms",
+    Metric,
+    1E-20,
+    Area
+);
 
 // Non-trivial units
-make_unit!(Are, 1E+2, Area);
-make_unit!(HectAre, 1E+4, Area);
+make_unit!(Are, "a", "are", "ares", Metric, 1E+2, Area);
+make_unit!(HectAre, "ha", "hectare", "hectares", Metric, 1E+4, Area);
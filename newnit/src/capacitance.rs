@@ -0,0 +1,286 @@
+//! Units of capacitance.
+//!
+//! This module contains predefined newtypes for units of capacitance as defined
+//! in the International System of Units (SI). The base unit is the [`Farad`].
+//!
+//! [`Farad`]: metric::Farad
+
+use crate::charge::metric::Coulomb;
+use crate::resistance::Resistance;
+use crate::scalar::Scalar;
+use crate::time::metric::Second;
+use crate::voltage::Voltage;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of capacitance.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+pub trait Capacitance<V: Scalar = f64>: Quantity<V, Dimension = dimension::Capacitance> {
+    /// Multiply a unit of capacitance with a unit of voltage.
+    fn multiply_voltage(&self, rhs: &dyn Voltage<V>) -> Coulomb<V> {
+        Coulomb(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of capacitance with a unit of resistance.
+    fn multiply_resistance(&self, rhs: &dyn Resistance<V>) -> Second<V> {
+        Second(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Capacitance>> Capacitance<V> for T {}
+
+pub mod metric {
+    use super::Capacitance;
+    use crate::make_unit;
+    use newnit_derive::{Capacitance, Unit};
+
+    make_unit!(
+        QuettaFarad,
+        "QF",
+        "quettafarad",
+        "quettafarads",
+        Metric,
+        1E+30,
+        Capacitance
+    );
+    make_unit!(
+        RonnaFarad,
+        "RF",
+        "ronnafarad",
+        "ronnafarads",
+        Metric,
+        1E+27,
+        Capacitance
+    );
+    make_unit!(
+        YottaFarad,
+        "YF",
+        "yottafarad",
+        "yottafarads",
+        Metric,
+        1E+24,
+        Capacitance
+    );
+    make_unit!(
+        ZettaFarad,
+        "ZF",
+        "zettafarad",
+        "zettafarads",
+        Metric,
+        1E+21,
+        Capacitance
+    );
+    make_unit!(
+        ExaFarad,
+        "EF",
+        "exafarad",
+        "exafarads",
+        Metric,
+        1E+18,
+        Capacitance
+    );
+    make_unit!(
+        PetaFarad,
+        "PF",
+        "petafarad",
+        "petafarads",
+        Metric,
+        1E+15,
+        Capacitance
+    );
+    make_unit!(
+        TeraFarad,
+        "TF",
+        "terafarad",
+        "terafarads",
+        Metric,
+        1E+12,
+        Capacitance
+    );
+    make_unit!(
+        GigaFarad,
+        "GF",
+        "gigafarad",
+        "gigafarads",
+        Metric,
+        1E+9,
+        Capacitance
+    );
+    make_unit!(
+        MegaFarad,
+        "MF",
+        "megafarad",
+        "megafarads",
+        Metric,
+        1E+6,
+        Capacitance
+    );
+    make_unit!(
+        KiloFarad,
+        "kF",
+        "kilofarad",
+        "kilofarads",
+        Metric,
+        1E+3,
+        Capacitance
+    );
+    make_unit!(Farad, "F", "farad", "farads", Metric, 1.0, Capacitance);
+    make_unit!(
+        DeciFarad,
+        "dF",
+        "decifarad",
+        "decifarads",
+        Metric,
+        1E-1,
+        Capacitance
+    );
+    make_unit!(
+        CentiFarad,
+        "cF",
+        "centifarad",
+        "centifarads",
+        Metric,
+        1E-2,
+        Capacitance
+    );
+    make_unit!(
+        MilliFarad,
+        "mF",
+        "millifarad",
+        "millifarads",
+        Metric,
+        1E-3,
+        Capacitance
+    );
+    make_unit!(
+        MicroFarad,
+        "µF",
+        "microfarad",
+        "microfarads",
+        Metric,
+        1E-6,
+        Capacitance
+    );
+    make_unit!(
+        NanoFarad,
+        "nF",
+        "nanofarad",
+        "nanofarads",
+        Metric,
+        1E-9,
+        Capacitance
+    );
+    make_unit!(
+        PicoFarad,
+        "pF",
+        "picofarad",
+        "picofarads",
+        Metric,
+        1E-12,
+        Capacitance
+    );
+    make_unit!(
+        FemtoFarad,
+        "fF",
+        "femtofarad",
+        "femtofarads",
+        Metric,
+        1E-15,
+        Capacitance
+    );
+    make_unit!(
+        AttoFarad,
+        "aF",
+        "attofarad",
+        "attofarads",
+        Metric,
+        1E-18,
+        Capacitance
+    );
+    make_unit!(
+        ZeptoFarad,
+        "zF",
+        "zeptofarad",
+        "zeptofarads",
+        Metric,
+        1E-21,
+        Capacitance
+    );
+    make_unit!(
+        YoctoFarad,
+        "yF",
+        "yoctofarad",
+        "yoctofarads",
+        Metric,
+        1E-24,
+        Capacitance
+    );
+    make_unit!(
+        RontoFarad,
+        "rF",
+        "rontofarad",
+        "rontofarads",
+        Metric,
+        1E-27,
+        Capacitance
+    );
+    make_unit!(
+        QuectoFarad,
+        "qF",
+        "quectofarad",
+        "quectofarads",
+        Metric,
+        1E-30,
+        Capacitance
+    );
+}
+
+parse::units! {
+    /// Parse a capacitance in the unit named by its symbol (e.g. `"100 µF"`).
+    parse, UNITS: Capacitance, CapacitanceUnit;
+    metric::{
+        QuettaFarad,
+        RonnaFarad,
+        YottaFarad,
+        ZettaFarad,
+        ExaFarad,
+        PetaFarad,
+        TeraFarad,
+        GigaFarad,
+        MegaFarad,
+        KiloFarad,
+        Farad,
+        DeciFarad,
+        CentiFarad,
+        MilliFarad,
+        MicroFarad,
+        NanoFarad,
+        PicoFarad,
+        FemtoFarad,
+        AttoFarad,
+        ZeptoFarad,
+        YoctoFarad,
+        RontoFarad,
+        QuectoFarad,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::charge::metric::MicroCoulomb;
+    use crate::resistance::metric::KiloOhm;
+    use crate::voltage::metric::Volt;
+
+    #[test]
+    fn multiply_with_voltage_and_resistance() {
+        let capacitance = metric::MicroFarad(100.0_f64);
+        assert_quantity_eq!(
+            capacitance.multiply_voltage(&Volt(5.0)),
+            MicroCoulomb(500.0)
+        );
+        assert_quantity_eq!(capacitance.multiply_resistance(&KiloOhm(10.0)), Second(1.0));
+    }
+}
@@ -0,0 +1,331 @@
+//! Units of electric charge.
+//!
+//! This module contains predefined newtypes for units of electric charge as
+//! defined in the International System of Units (SI). The base unit is the
+//! [`Coulomb`].
+//!
+//! Additional definitions provided for the ampere-hour (Ah) = 3600 C and the
+//! milliampere-hour (mAh) = 3.6 C.
+//!
+//! [`Coulomb`]: metric::Coulomb
+
+use crate::capacitance::metric::Farad;
+use crate::current::Current;
+use crate::current::metric::Ampere;
+use crate::energy::metric::Joule;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::time::metric::Second;
+use crate::voltage::Voltage;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of electric charge.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+pub trait Charge<V: Scalar = f64>: Quantity<V, Dimension = dimension::Charge> {
+    /// Divide a unit of electric charge by a unit of time.
+    fn divide_time(&self, rhs: &dyn Time<V>) -> Ampere<V> {
+        Ampere(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of electric charge by a unit of current.
+    fn divide_current(&self, rhs: &dyn Current<V>) -> Second<V> {
+        Second(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of electric charge by a unit of voltage.
+    fn divide_voltage(&self, rhs: &dyn Voltage<V>) -> Farad<V> {
+        Farad(self.to_base() / rhs.to_base())
+    }
+
+    /// Multiply a unit of electric charge with a unit of voltage.
+    fn multiply_voltage(&self, rhs: &dyn Voltage<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Charge>> Charge<V> for T {}
+
+pub mod metric {
+    use super::Charge;
+    use crate::make_unit;
+    use newnit_derive::{Charge, Unit};
+
+    make_unit!(
+        QuettaCoulomb,
+        "QC",
+        "quettacoulomb",
+        "quettacoulombs",
+        Metric,
+        1E+30,
+        Charge
+    );
+    make_unit!(
+        RonnaCoulomb,
+        "RC",
+        "ronnacoulomb",
+        "ronnacoulombs",
+        Metric,
+        1E+27,
+        Charge
+    );
+    make_unit!(
+        YottaCoulomb,
+        "YC",
+        "yottacoulomb",
+        "yottacoulombs",
+        Metric,
+        1E+24,
+        Charge
+    );
+    make_unit!(
+        ZettaCoulomb,
+        "ZC",
+        "zettacoulomb",
+        "zettacoulombs",
+        Metric,
+        1E+21,
+        Charge
+    );
+    make_unit!(
+        ExaCoulomb,
+        "EC",
+        "exacoulomb",
+        "exacoulombs",
+        Metric,
+        1E+18,
+        Charge
+    );
+    make_unit!(
+        PetaCoulomb,
+        "PC",
+        "petacoulomb",
+        "petacoulombs",
+        Metric,
+        1E+15,
+        Charge
+    );
+    make_unit!(
+        TeraCoulomb,
+        "TC",
+        "teracoulomb",
+        "teracoulombs",
+        Metric,
+        1E+12,
+        Charge
+    );
+    make_unit!(
+        GigaCoulomb,
+        "GC",
+        "gigacoulomb",
+        "gigacoulombs",
+        Metric,
+        1E+9,
+        Charge
+    );
+    make_unit!(
+        MegaCoulomb,
+        "MC",
+        "megacoulomb",
+        "megacoulombs",
+        Metric,
+        1E+6,
+        Charge
+    );
+    make_unit!(
+        KiloCoulomb,
+        "kC",
+        "kilocoulomb",
+        "kilocoulombs",
+        Metric,
+        1E+3,
+        Charge
+    );
+    make_unit!(Coulomb, "C", "coulomb", "coulombs", Metric, 1.0, Charge);
+    make_unit!(
+        DeciCoulomb,
+        "dC",
+        "decicoulomb",
+        "decicoulombs",
+        Metric,
+        1E-1,
+        Charge
+    );
+    make_unit!(
+        CentiCoulomb,
+        "cC",
+        "centicoulomb",
+        "centicoulombs",
+        Metric,
+        1E-2,
+        Charge
+    );
+    make_unit!(
+        MilliCoulomb,
+        "mC",
+        "millicoulomb",
+        "millicoulombs",
+        Metric,
+        1E-3,
+        Charge
+    );
+    make_unit!(
+        MicroCoulomb,
+        "µC",
+        "microcoulomb",
+        "microcoulombs",
+        Metric,
+        1E-6,
+        Charge
+    );
+    make_unit!(
+        NanoCoulomb,
+        "nC",
+        "nanocoulomb",
+        "nanocoulombs",
+        Metric,
+        1E-9,
+        Charge
+    );
+    make_unit!(
+        PicoCoulomb,
+        "pC",
+        "picocoulomb",
+        "picocoulombs",
+        Metric,
+        1E-12,
+        Charge
+    );
+    make_unit!(
+        FemtoCoulomb,
+        "fC",
+        "femtocoulomb",
+        "femtocoulombs",
+        Metric,
+        1E-15,
+        Charge
+    );
+    make_unit!(
+        AttoCoulomb,
+        "aC",
+        "attocoulomb",
+        "attocoulombs",
+        Metric,
+        1E-18,
+        Charge
+    );
+    make_unit!(
+        ZeptoCoulomb,
+        "zC",
+        "zeptocoulomb",
+        "zeptocoulombs",
+        Metric,
+        1E-21,
+        Charge
+    );
+    make_unit!(
+        YoctoCoulomb,
+        "yC",
+        "yoctocoulomb",
+        "yoctocoulombs",
+        Metric,
+        1E-24,
+        Charge
+    );
+    make_unit!(
+        RontoCoulomb,
+        "rC",
+        "rontocoulomb",
+        "rontocoulombs",
+        Metric,
+        1E-27,
+        Charge
+    );
+    make_unit!(
+        QuectoCoulomb,
+        "qC",
+        "quectocoulomb",
+        "quectocoulombs",
+        Metric,
+        1E-30,
+        Charge
+    );
+
+    make_unit!(
+        AmpereHour,
+        "Ah",
+        "ampere-hour",
+        "ampere-hours",
+        Metric,
+        3600.0,
+        Charge
+    );
+    make_unit!(
+        MilliAmpereHour,
+        "mAh",
+        "milliampere-hour",
+        "milliampere-hours",
+        Metric,
+        3.6,
+        Charge
+    );
+}
+
+parse::units! {
+    /// Parse an electric charge in the unit named by its symbol (e.g. `"2.5
+    /// Ah"`).
+    parse, UNITS: Charge, ChargeUnit;
+    metric::{
+        QuettaCoulomb,
+        RonnaCoulomb,
+        YottaCoulomb,
+        ZettaCoulomb,
+        ExaCoulomb,
+        PetaCoulomb,
+        TeraCoulomb,
+        GigaCoulomb,
+        MegaCoulomb,
+        KiloCoulomb,
+        Coulomb,
+        DeciCoulomb,
+        CentiCoulomb,
+        MilliCoulomb,
+        MicroCoulomb,
+        NanoCoulomb,
+        PicoCoulomb,
+        FemtoCoulomb,
+        AttoCoulomb,
+        ZeptoCoulomb,
+        YoctoCoulomb,
+        RontoCoulomb,
+        QuectoCoulomb,
+        AmpereHour,
+        MilliAmpereHour,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::current::metric::MilliAmpere;
+    use crate::energy::metric::WattHour;
+    use crate::time::metric::Hour;
+    use crate::voltage::metric::Volt;
+
+    #[test]
+    fn battery_capacity() {
+        let capacity = metric::MilliAmpereHour(3000.0_f64);
+        assert_quantity_eq!(capacity, metric::AmpereHour(3.0));
+
+        assert_quantity_eq!(capacity.divide_time(&Hour(2.0)), MilliAmpere(1500.0));
+        assert_quantity_eq!(capacity.divide_current(&MilliAmpere(500.0)), Hour(6.0));
+        assert_quantity_eq!(capacity.multiply_voltage(&Volt(3.7)), WattHour(11.1));
+        assert_eq!(
+            metric::Coulomb(2.0).divide_voltage(&Volt(4.0)).to_value(),
+            0.5
+        );
+    }
+}
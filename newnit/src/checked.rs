@@ -0,0 +1,434 @@
+//! Checked construction and arithmetic of units.
+//!
+//! Units accept any value of their scalar type, including NaN, infinities and
+//! values below the physical minimum of their quantity (e.g. negative masses
+//! or temperatures below absolute zero, see [`Quantity::minimum`]).
+//! [`Checked`] constructs, converts and operates on units returning a
+//! [`ValueError`] for such values instead.
+//!
+//! The wrappers [`Finite`] and [`NonNegative`] keep their guarantee through
+//! arithmetic: operations return a [`Result`] of the wrapper, which is an
+//! error if the result breaks the guarantee or overflows the scalar type.
+//!
+//! # Examples
+//! ```
+//! use newnit::checked::{Checked, NonNegative, ValueError};
+//! use newnit::length::metric::Meter;
+//! use newnit::mass::metric::KiloGram;
+//! use newnit::temperature::{Celsius, Kelvin};
+//!
+//! assert_eq!(Kelvin::try_new(-5.0), Err(ValueError::BelowMinimum));
+//! assert_eq!(KiloGram::try_new(-3.0), Err(ValueError::BelowMinimum));
+//! assert_eq!(Meter::try_new(f64::NAN), Err(ValueError::NaN));
+//! assert_eq!(Celsius::try_new(-300.0), Err(ValueError::BelowMinimum));
+//! assert_eq!(Meter::try_new(-2.0), Ok(Meter(-2.0)));
+//!
+//! let mass = KiloGram(2.0).checked_sub(&KiloGram(3.0));
+//! assert_eq!(mass, Err(ValueError::BelowMinimum));
+//!
+//! let distance = NonNegative::new(Meter(3.0)).unwrap();
+//! let total = (distance + &NonNegative::new(Meter(2.0)).unwrap()).unwrap();
+//! assert_eq!(total.get(), Meter(5.0));
+//! assert_eq!((distance * -1.0).unwrap_err(), ValueError::Negative);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Deref, Div, Mul, Neg, Sub};
+
+use crate::Quantity;
+use crate::scalar::Scalar;
+use crate::unit::Conversion;
+
+/// Error of a value invalid for its unit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ValueError {
+    /// The value is NaN.
+    NaN,
+    /// The value is infinite.
+    Infinite,
+    /// The value is below the physical minimum of the quantity (e.g. a
+    /// negative mass).
+    BelowMinimum,
+    /// The value is negative.
+    Negative,
+    /// The result of an operation overflows the scalar type (e.g. an integer
+    /// out of its range, or divided by zero).
+    Overflow,
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NaN => write!(f, "Value is not a number"),
+            Self::Infinite => write!(f, "Infinite value"),
+            Self::BelowMinimum => write!(f, "Value below the minimum of the quantity"),
+            Self::Negative => write!(f, "Negative value"),
+            Self::Overflow => write!(f, "Overflow of the scalar type"),
+        }
+    }
+}
+
+impl core::error::Error for ValueError {}
+
+/// Checked construction, conversion and arithmetic of units.
+///
+/// Implemented for all units. The checks reject NaN and infinite values, and
+/// values below the physical minimum of the quantity.
+pub trait Checked<V: Scalar = f64>: Quantity<V> + Conversion + Sized {
+    /// A unit of the given value, if valid.
+    fn try_new(value: V) -> Result<Self, ValueError> {
+        let unit = Self::from_value(value);
+        unit.validate()?;
+        Ok(unit)
+    }
+
+    /// A unit of the given value in base units, if valid in both units.
+    fn try_from_base(base: V) -> Result<Self, ValueError> {
+        check::<Self, V>(base, base)?;
+        Self::try_new(Self::from_base_value(base))
+    }
+
+    /// Whether the value of the unit is valid.
+    fn validate(&self) -> Result<(), ValueError> {
+        check::<Self, V>(self.to_value(), self.to_base())
+    }
+
+    /// The sum of `self` and `other`, if valid and in range of the scalar
+    /// type.
+    fn checked_add<'a, R>(self, other: &'a R) -> Result<Self, ValueError>
+    where
+        Self: Add<&'a R, Output = Self>,
+        R: Quantity<V> + Conversion,
+    {
+        let other = operand::<Self, R, V>(other.to_value())?;
+        checked(self.to_value().checked_add(other))
+    }
+
+    /// The difference of `self` and `other`, if valid and in range of the
+    /// scalar type.
+    fn checked_sub<'a, R>(self, other: &'a R) -> Result<Self, ValueError>
+    where
+        Self: Sub<&'a R, Output = Self>,
+        R: Quantity<V> + Conversion,
+    {
+        let other = operand::<Self, R, V>(other.to_value())?;
+        checked(self.to_value().checked_sub(other))
+    }
+
+    /// The product of `self` and the scalar `rhs`, if valid and in range of
+    /// the scalar type.
+    fn checked_mul(self, rhs: V) -> Result<Self, ValueError>
+    where
+        Self: Mul<V, Output = Self>,
+    {
+        checked(self.to_value().checked_mul(rhs))
+    }
+
+    /// The quotient of `self` and the scalar `rhs`, if valid and in range of
+    /// the scalar type.
+    fn checked_div(self, rhs: V) -> Result<Self, ValueError>
+    where
+        Self: Div<V, Output = Self>,
+    {
+        checked(self.to_value().checked_div(rhs))
+    }
+}
+
+impl<V: Scalar, U: Quantity<V> + Conversion> Checked<V> for U {}
+
+/// A unit with a value that is neither NaN nor infinite.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Finite<U, V = f64>(U, PhantomData<V>);
+
+impl<U: Quantity<V>, V: Scalar> Finite<U, V> {
+    /// Wrap the unit, if its value is finite.
+    pub fn new(unit: U) -> Result<Self, ValueError> {
+        check_finite(unit.to_value())?;
+        Ok(Self(unit, PhantomData))
+    }
+
+    /// The unit.
+    pub fn get(self) -> U {
+        self.0
+    }
+}
+
+/// A unit with a non-negative value, which isn't NaN.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct NonNegative<U, V = f64>(U, PhantomData<V>);
+
+impl<U: Quantity<V>, V: Scalar> NonNegative<U, V> {
+    /// Wrap the unit, if its value is non-negative.
+    pub fn new(unit: U) -> Result<Self, ValueError> {
+        let value = unit.to_value();
+        if is_nan(value) {
+            return Err(ValueError::NaN);
+        }
+        match value < V::default() {
+            true => Err(ValueError::Negative),
+            false => Ok(Self(unit, PhantomData)),
+        }
+    }
+
+    /// The unit.
+    pub fn get(self) -> U {
+        self.0
+    }
+}
+
+/// Impls common to the wrappers, keeping the guarantee of the wrapper `$name`
+/// through the operations, which return an error if the result breaks it or
+/// overflows the scalar type.
+macro_rules! impl_wrapper {
+    ($name:ident) => {
+        impl<U, V> Deref for $name<U, V> {
+            type Target = U;
+
+            fn deref(&self) -> &U {
+                &self.0
+            }
+        }
+
+        impl<U: fmt::Display, V> fmt::Display for $name<U, V> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<'a, U, R, V> Add<&'a $name<R, V>> for $name<U, V>
+        where
+            U: Quantity<V> + Conversion + Add<&'a R, Output = U>,
+            R: Quantity<V> + Conversion,
+            V: Scalar,
+        {
+            type Output = Result<Self, ValueError>;
+
+            fn add(self, other: &'a $name<R, V>) -> Self::Output {
+                let other = operand::<U, R, V>(other.0.to_value())?;
+                Self::new(in_range(self.0.to_value().checked_add(other))?)
+            }
+        }
+
+        impl<'a, U, R, V> Sub<&'a $name<R, V>> for $name<U, V>
+        where
+            U: Quantity<V> + Conversion + Sub<&'a R, Output = U>,
+            R: Quantity<V> + Conversion,
+            V: Scalar,
+        {
+            type Output = Result<Self, ValueError>;
+
+            fn sub(self, other: &'a $name<R, V>) -> Self::Output {
+                let other = operand::<U, R, V>(other.0.to_value())?;
+                Self::new(in_range(self.0.to_value().checked_sub(other))?)
+            }
+        }
+
+        impl<U, V> Mul<V> for $name<U, V>
+        where
+            U: Quantity<V> + Mul<V, Output = U>,
+            V: Scalar,
+        {
+            type Output = Result<Self, ValueError>;
+
+            fn mul(self, rhs: V) -> Self::Output {
+                Self::new(in_range(self.0.to_value().checked_mul(rhs))?)
+            }
+        }
+
+        impl<U, V> Div<V> for $name<U, V>
+        where
+            U: Quantity<V> + Div<V, Output = U>,
+            V: Scalar,
+        {
+            type Output = Result<Self, ValueError>;
+
+            fn div(self, rhs: V) -> Self::Output {
+                Self::new(in_range(self.0.to_value().checked_div(rhs))?)
+            }
+        }
+    };
+}
+
+impl_wrapper!(Finite);
+impl_wrapper!(NonNegative);
+
+impl<U: Quantity<V> + Neg<Output = U>, V: Scalar> Neg for Finite<U, V> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0, PhantomData)
+    }
+}
+
+/// Check the value of a unit and its value in base units.
+fn check<U: Quantity<V>, V: Scalar>(value: V, base: V) -> Result<(), ValueError> {
+    check_finite(value)?;
+    check_finite(base)?;
+
+    match U::minimum() {
+        Some(minimum) if base < V::from_rational(minimum) => Err(ValueError::BelowMinimum),
+        _ => Ok(()),
+    }
+}
+
+/// Check that the value is neither NaN nor infinite.
+fn check_finite<V: Scalar>(value: V) -> Result<(), ValueError> {
+    if is_nan(value) {
+        return Err(ValueError::NaN);
+    }
+    match value.to_f64().is_infinite() {
+        true => Err(ValueError::Infinite),
+        false => Ok(()),
+    }
+}
+
+/// The unit of the value, if it didn't overflow and is valid.
+fn checked<U: Checked<V>, V: Scalar>(value: Option<V>) -> Result<U, ValueError> {
+    U::try_new(value.ok_or(ValueError::Overflow)?)
+}
+
+/// The unit of the value, if it didn't overflow.
+fn in_range<U: Quantity<V>, V: Scalar>(value: Option<V>) -> Result<U, ValueError> {
+    value.map(U::from_value).ok_or(ValueError::Overflow)
+}
+
+/// The value of an operand of the unit `R` in the unit `U`, if in range.
+///
+/// Operands of arithmetic never have an offset (e.g. intervals of
+/// temperatures), so they convert by the ratio of the factors alone.
+fn operand<U: Conversion, R: Conversion, V: Scalar>(value: V) -> Result<V, ValueError> {
+    let factor = const { R::FACTOR.quotient(U::FACTOR) };
+    match factor.is_one() {
+        true => Ok(value),
+        false => value.checked_scale(factor).ok_or(ValueError::Overflow),
+    }
+}
+
+/// Whether the value is NaN, i.e. incomparable even to itself.
+fn is_nan<V: Scalar>(value: V) -> bool {
+    value.partial_cmp(&value).is_none()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::metric::SquareMeter;
+    use crate::force::Force;
+    use crate::force::metric::Newton;
+    use crate::length::imperial::{Foot, Inch};
+    use crate::length::metric::{KiloMeter, Meter};
+    use crate::mass::metric::{Gram, KiloGram};
+    use crate::pressure::metric::{Pascal, PascalDifferential};
+    use crate::temperature::{Celsius, DeltaKelvin, Fahrenheit, Kelvin};
+    use crate::time::metric::Second;
+
+    #[test]
+    fn construct_units() {
+        assert_eq!(Kelvin::try_new(0.0), Ok(Kelvin(0.0)));
+        assert_eq!(Kelvin::try_new(-5.0), Err(ValueError::BelowMinimum));
+        assert_eq!(Fahrenheit::try_new(-460.0), Err(ValueError::BelowMinimum));
+        assert_eq!(KiloGram::try_new(-1), Err(ValueError::BelowMinimum));
+        assert_eq!(Second::try_new(f64::INFINITY), Err(ValueError::Infinite));
+        assert_eq!(Meter::try_new(f32::NAN), Err(ValueError::NaN));
+        assert_eq!(Meter(-1.0).validate(), Ok(()));
+    }
+
+    #[test]
+    fn convert_from_base() {
+        assert_eq!(KiloMeter::try_from_base(1500.0), Ok(KiloMeter(1.5)));
+        assert_eq!(Celsius::try_from_base(-1.0), Err(ValueError::BelowMinimum));
+        assert_eq!(
+            KiloMeter::try_from_base(f64::NEG_INFINITY),
+            Err(ValueError::Infinite)
+        );
+        // Overflows in the unit, although finite in base units.
+        assert_eq!(
+            crate::length::metric::QuectoMeter::try_from_base(f64::MAX),
+            Err(ValueError::Infinite)
+        );
+    }
+
+    #[test]
+    fn minimum_of_absolute_pressures() {
+        assert_eq!(Pascal::try_new(-1.0), Err(ValueError::BelowMinimum));
+        assert_eq!(
+            PascalDifferential::try_new(-1.0),
+            Ok(PascalDifferential(-1.0))
+        );
+
+        let pressure = Newton(-5.0).divide_area(&SquareMeter(1.0));
+        assert_eq!(pressure.validate(), Err(ValueError::BelowMinimum));
+        let stress = Newton(-5.0) / &SquareMeter(1.0);
+        assert_eq!(stress.validate(), Ok(()));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(Foot(1.0).checked_add(&Inch(6.0)), Ok(Foot(1.5)));
+        assert_eq!(
+            KiloGram(1.0).checked_sub(&Gram(1500.0)),
+            Err(ValueError::BelowMinimum)
+        );
+        assert_eq!(
+            Kelvin(1.0).checked_sub(&DeltaKelvin(2.0)),
+            Err(ValueError::BelowMinimum)
+        );
+        assert_eq!(Meter(f64::MAX).checked_mul(2.0), Err(ValueError::Infinite));
+        assert_eq!(Meter(0.0).checked_div(0.0), Err(ValueError::NaN));
+        assert_eq!(Meter(3.0).checked_div(2.0), Ok(Meter(1.5)));
+    }
+
+    #[test]
+    fn checked_integer_overflow() {
+        assert_eq!(Meter(i32::MAX).checked_mul(2), Err(ValueError::Overflow));
+        assert_eq!(
+            KiloGram(0_u32).checked_sub(&KiloGram(1)),
+            Err(ValueError::Overflow)
+        );
+        assert_eq!(
+            Meter(i32::MAX).checked_add(&Meter(1)),
+            Err(ValueError::Overflow)
+        );
+        assert_eq!(Meter(1_i32).checked_div(0), Err(ValueError::Overflow));
+        assert_eq!(Meter(i32::MIN).checked_div(-1), Err(ValueError::Overflow));
+        assert_eq!(
+            Meter(0_i32).checked_add(&KiloMeter(i32::MAX)),
+            Err(ValueError::Overflow)
+        );
+        assert_eq!(Meter(1_i32).checked_add(&KiloMeter(2)), Ok(Meter(2_001)));
+        assert_eq!(
+            Celsius(-270_i16).checked_sub(&DeltaKelvin(5)),
+            Err(ValueError::BelowMinimum)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn finite_units() {
+        let length = Finite::new(Meter(f64::MAX)).unwrap();
+        assert_eq!((length + &length).unwrap_err(), ValueError::Infinite);
+        assert_eq!((length - &length).unwrap().get(), Meter(0.0));
+        assert_eq!((-length).to_value(), -f64::MAX);
+        assert_eq!(Finite::new(Meter(f64::NAN)), Err(ValueError::NaN));
+        assert_eq!(Finite::new(Meter(1.0)).unwrap().to_string(), "1 m");
+    }
+
+    #[test]
+    fn non_negative_units() {
+        let length = NonNegative::new(Foot(1.0)).unwrap();
+        let inches = NonNegative::new(Inch(18.0)).unwrap();
+        assert_eq!((length + &inches).unwrap().get(), Foot(2.5));
+        assert_eq!((inches - &length).unwrap().get(), Inch(6.0));
+        assert_eq!((length - &inches).unwrap_err(), ValueError::Negative);
+        assert_eq!((length / f64::NAN).unwrap_err(), ValueError::NaN);
+        assert_eq!(NonNegative::new(Meter(-0.0)).unwrap().get(), Meter(-0.0));
+        assert_eq!(NonNegative::new(Meter(-1)), Err(ValueError::Negative));
+
+        let length = NonNegative::new(Meter(i32::MAX)).unwrap();
+        let sum = length + &NonNegative::new(Meter(1)).unwrap();
+        assert_eq!(sum, Err(ValueError::Overflow));
+        assert_eq!((length * 2).unwrap_err(), ValueError::Overflow);
+    }
+}
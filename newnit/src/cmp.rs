@@ -0,0 +1,213 @@
+//! Comparison of quantities across units.
+//!
+//! Units compare with any unit of the same quantity (e.g. `Meter(1.0) <
+//! Foot(4.0)`), in base units, while comparing different quantities fails to
+//! compile. Values of the same unit compare directly, exactly as the values
+//! themselves, and values of scalar types representing them exactly (e.g.
+//! integers) compare exactly in any units (e.g. `MilliMeter(1_499) <
+//! Meter(2)`, though both are 1 m rounded in base units).
+//!
+//! [`MinMax`] picks the lesser or greater of two quantities in mixed units, or
+//! clamps a quantity to an interval, in the unit of the receiver.
+//!
+//! # Examples
+//! ```
+//! use newnit::cmp::MinMax;
+//! use newnit::length::imperial::{Foot, Inch};
+//! use newnit::length::metric::{CentiMeter, Meter};
+//!
+//! assert!(Meter(1.0) < Foot(4.0));
+//! assert!(Inch(12.0) == Foot(1.0));
+//!
+//! assert_eq!(Meter(1.0).min(&Foot(3.0)), Meter(0.9144));
+//! assert_eq!(Meter(1.0).max(&Foot(3.0)), Meter(1.0));
+//! assert_eq!(Inch(10.0).clamp(&CentiMeter(30.48), &Foot(2.0)), Inch(12.0));
+//! ```
+//!
+//! Different quantities can't be compared:
+//!
+//! ```compile_fail
+//! use newnit::length::metric::Meter;
+//! use newnit::time::metric::Second;
+//!
+//! assert!(Meter(1.0) < Second(2.0));
+//! ```
+
+use core::cmp::Ordering;
+
+use crate::Quantity;
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::unit::{Conversion, affine};
+
+/// Compare two units of the same quantity.
+///
+/// Units converting to the base unit alike compare their values directly.
+/// Others compare their exact values in base units if the scalar type
+/// represents them exactly (see [`Scalar::to_rational`]), or else in base
+/// units of the scalar type, or as [`f64`]s if the exact values overflow.
+pub fn partial_cmp<L, R, V>(left: &L, right: &R) -> Option<Ordering>
+where
+    L: Quantity<V> + Conversion + ?Sized,
+    R: Quantity<V, Dimension = L::Dimension> + Conversion + ?Sized,
+    V: Scalar,
+{
+    if L::FACTOR == R::FACTOR && L::OFFSET == R::OFFSET {
+        return left.to_value().partial_cmp(&right.to_value());
+    }
+
+    match (
+        left.to_value().to_rational(),
+        right.to_value().to_rational(),
+    ) {
+        (Some(left), Some(right)) => {
+            let base = |value: Rational, factor, offset| {
+                value.checked_product(factor)?.checked_sum(offset)
+            };
+            let exact = base(left, L::FACTOR, L::OFFSET)
+                .zip(base(right, R::FACTOR, R::OFFSET))
+                .and_then(|(left, right)| left.checked_cmp(right));
+
+            exact.or_else(|| {
+                let left = affine(left.to_f64(), L::FACTOR, L::OFFSET);
+                left.partial_cmp(&affine(right.to_f64(), R::FACTOR, R::OFFSET))
+            })
+        }
+        _ => left.to_base().partial_cmp(&right.to_base()),
+    }
+}
+
+/// The lesser or greater of quantities in mixed units, in the unit of the
+/// receiver.
+///
+/// Implemented for all units.
+pub trait MinMax<V: Scalar = f64>: Quantity<V> + Conversion + Sized {
+    /// The lesser of `self` and `other`, or `self` if they are equal or
+    /// incomparable.
+    fn min<R>(self, other: &R) -> Self
+    where
+        R: Quantity<V, Dimension = Self::Dimension> + Conversion,
+    {
+        match partial_cmp(other, &self) {
+            Some(Ordering::Less) => convert(other),
+            _ => self,
+        }
+    }
+
+    /// The greater of `self` and `other`, or `self` if they are equal or
+    /// incomparable.
+    fn max<R>(self, other: &R) -> Self
+    where
+        R: Quantity<V, Dimension = Self::Dimension> + Conversion,
+    {
+        match partial_cmp(other, &self) {
+            Some(Ordering::Greater) => convert(other),
+            _ => self,
+        }
+    }
+
+    /// `self` restricted to the interval from `min` to `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, or they are incomparable (e.g.
+    /// NaN).
+    fn clamp<A, B>(self, min: &A, max: &B) -> Self
+    where
+        A: Quantity<V, Dimension = Self::Dimension> + Conversion,
+        B: Quantity<V, Dimension = Self::Dimension> + Conversion,
+    {
+        assert!(
+            matches!(
+                partial_cmp(min, max),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+            "min > max, or either was NaN"
+        );
+
+        self.max(min).min(max)
+    }
+}
+
+impl<V: Scalar, Q: Quantity<V> + Conversion> MinMax<V> for Q {}
+
+/// `unit` converted to the unit `U`.
+fn convert<U, R, V>(unit: &R) -> U
+where
+    U: Quantity<V> + Conversion,
+    R: Quantity<V> + Conversion,
+    V: Scalar,
+{
+    U::from_value(U::convert_from::<R, V>(unit.to_value()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Unit;
+    use crate::assert_quantity_eq;
+    use crate::length::imperial::{Foot, Inch};
+    use crate::length::metric::{KiloMeter, Meter, MilliMeter};
+    use crate::temperature::{Celsius, Fahrenheit, Kelvin};
+
+    #[test]
+    fn compare_across_units() {
+        assert!(Meter(1.0) < Foot(4.0));
+        assert!(KiloMeter(1.0) > Foot(3000.0));
+        assert!(Foot(1.0) == Inch(12.0));
+        assert!(Foot(1.0) != Inch(12.5));
+        assert!(Celsius(0.0) > Fahrenheit(0.0));
+        assert!(Kelvin(0.0) <= Celsius(-273.15));
+
+        assert_eq!(partial_cmp(&Meter(f64::NAN), &Foot(1.0)), None::<Ordering>);
+        assert!(Meter(f64::NAN) != Meter(f64::NAN));
+    }
+
+    #[test]
+    fn compare_integers_of_same_unit_exactly() {
+        // Both are 256 K when rounded in base units.
+        assert_eq!(Fahrenheit(1).to_base(), Fahrenheit(2).to_base());
+        assert!(Fahrenheit(1) < Fahrenheit(2));
+        assert!(Fahrenheit(1) != Fahrenheit(2));
+    }
+
+    #[test]
+    fn compare_integers_across_units_exactly() {
+        // Both are 1 m when rounded in base units.
+        assert!(MilliMeter(1_499_i32) != Meter(1_i32));
+        assert!(MilliMeter(1_499_i32) > Meter(1_i32));
+        assert!(MilliMeter(1_000_i32) == Meter(1_i32));
+        assert!(Fahrenheit(1_i32) < Kelvin(256_i32));
+        assert!(Foot(1_i64) == Inch(12_i64));
+        assert!(Inch(-13_i16) < Foot(-1_i16));
+
+        assert_eq!(MilliMeter(900_i32).max(&Meter(1_i32)), MilliMeter(1_000));
+        assert_eq!(Meter(1_i32).min(&MilliMeter(900_i32)), Meter(1));
+        assert_eq!(
+            MilliMeter(1_200_u32).clamp(&Meter(0_u32), &Meter(1_u32)),
+            MilliMeter(1_000)
+        );
+
+        // The exact values overflow, and compare as floats.
+        assert!(MilliMeter(u128::MAX) > Meter(1_u128));
+        assert!(KiloMeter(i128::MAX) > Meter(i128::MAX));
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        assert_eq!(Meter(1.0).min(&Foot(3.0)), Meter(0.9144));
+        let feet: Foot = Foot(3.0).max(&Meter(1.0));
+        assert_quantity_eq!(feet, Meter(1.0));
+        assert_eq!(Celsius(20.0).max(&Fahrenheit(50.0)), Celsius(20.0));
+
+        assert_eq!(Inch(6.0).clamp(&Foot(1.0), &Meter(1.0)), Inch(12.0));
+        assert_eq!(Inch(12.0).clamp(&Inch(1.0), &Inch(2.0)), Inch(2.0));
+        assert_eq!(Inch(1.5).clamp(&Inch(1.0), &Inch(2.0)), Inch(1.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "min > max")]
+    fn clamp_to_empty_interval() {
+        Inch(1.0).clamp(&Meter(1.0), &Foot(1.0));
+    }
+}
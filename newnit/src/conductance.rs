@@ -0,0 +1,285 @@
+//! Units of electrical conductance.
+//!
+//! This module contains predefined newtypes for units of electrical conductance
+//! as defined in the International System of Units (SI). The base unit is the
+//! [`Siemens`].
+//!
+//! [`Siemens`]: metric::Siemens
+
+use crate::current::metric::Ampere;
+use crate::rational::Rational;
+use crate::resistance::metric::Ohm;
+use crate::scalar::Scalar;
+use crate::voltage::Voltage;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of electrical conductance.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication, and
+/// the reciprocal resistance.
+pub trait Conductance<V: Scalar = f64>: Quantity<V, Dimension = dimension::Conductance> {
+    /// Multiply a unit of conductance with a unit of voltage.
+    fn multiply_voltage(&self, rhs: &dyn Voltage<V>) -> Ampere<V> {
+        Ampere(self.to_base() * rhs.to_base())
+    }
+
+    /// The resistance, the reciprocal of the conductance.
+    fn resistance(&self) -> Ohm<V> {
+        Ohm(V::from_rational(Rational::ONE) / self.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Conductance>> Conductance<V> for T {}
+
+pub mod metric {
+    use super::Conductance;
+    use crate::make_unit;
+    use newnit_derive::{Conductance, Unit};
+
+    make_unit!(
+        QuettaSiemens,
+        "QS",
+        "quettasiemens",
+        "quettasiemens",
+        Metric,
+        1E+30,
+        Conductance
+    );
+    make_unit!(
+        RonnaSiemens,
+        "RS",
+        "ronnasiemens",
+        "ronnasiemens",
+        Metric,
+        1E+27,
+        Conductance
+    );
+    make_unit!(
+        YottaSiemens,
+        "YS",
+        "yottasiemens",
+        "yottasiemens",
+        Metric,
+        1E+24,
+        Conductance
+    );
+    make_unit!(
+        ZettaSiemens,
+        "ZS",
+        "zettasiemens",
+        "zettasiemens",
+        Metric,
+        1E+21,
+        Conductance
+    );
+    make_unit!(
+        ExaSiemens,
+        "ES",
+        "exasiemens",
+        "exasiemens",
+        Metric,
+        1E+18,
+        Conductance
+    );
+    make_unit!(
+        PetaSiemens,
+        "PS",
+        "petasiemens",
+        "petasiemens",
+        Metric,
+        1E+15,
+        Conductance
+    );
+    make_unit!(
+        TeraSiemens,
+        "TS",
+        "terasiemens",
+        "terasiemens",
+        Metric,
+        1E+12,
+        Conductance
+    );
+    make_unit!(
+        GigaSiemens,
+        "GS",
+        "gigasiemens",
+        "gigasiemens",
+        Metric,
+        1E+9,
+        Conductance
+    );
+    make_unit!(
+        MegaSiemens,
+        "MS",
+        "megasiemens",
+        "megasiemens",
+        Metric,
+        1E+6,
+        Conductance
+    );
+    make_unit!(
+        KiloSiemens,
+        "kS",
+        "kilosiemens",
+        "kilosiemens",
+        Metric,
+        1E+3,
+        Conductance
+    );
+    make_unit!(Siemens, "S", "siemens", "siemens", Metric, 1.0, Conductance);
+    make_unit!(
+        DeciSiemens,
+        "dS",
+        "decisiemens",
+        "decisiemens",
+        Metric,
+        1E-1,
+        Conductance
+    );
+    make_unit!(
+        CentiSiemens,
+        "cS",
+        "centisiemens",
+        "centisiemens",
+        Metric,
+        1E-2,
+        Conductance
+    );
+    make_unit!(
+        MilliSiemens,
+        "mS",
+        "millisiemens",
+        "millisiemens",
+        Metric,
+        1E-3,
+        Conductance
+    );
+    make_unit!(
+        MicroSiemens,
+        "µS",
+        "microsiemens",
+        "microsiemens",
+        Metric,
+        1E-6,
+        Conductance
+    );
+    make_unit!(
+        NanoSiemens,
+        "nS",
+        "nanosiemens",
+        "nanosiemens",
+        Metric,
+        1E-9,
+        Conductance
+    );
+    make_unit!(
+        PicoSiemens,
+        "pS",
+        "picosiemens",
+        "picosiemens",
+        Metric,
+        1E-12,
+        Conductance
+    );
+    make_unit!(
+        FemtoSiemens,
+        "fS",
+        "femtosiemens",
+        "femtosiemens",
+        Metric,
+        1E-15,
+        Conductance
+    );
+    make_unit!(
+        AttoSiemens,
+        "aS",
+        "attosiemens",
+        "attosiemens",
+        Metric,
+        1E-18,
+        Conductance
+    );
+    make_unit!(
+        ZeptoSiemens,
+        "zS",
+        "zeptosiemens",
+        "zeptosiemens",
+        Metric,
+        1E-21,
+        Conductance
+    );
+    make_unit!(
+        YoctoSiemens,
+        "yS",
+        "yoctosiemens",
+        "yoctosiemens",
+        Metric,
+        1E-24,
+        Conductance
+    );
+    make_unit!(
+        RontoSiemens,
+        "rS",
+        "rontosiemens",
+        "rontosiemens",
+        Metric,
+        1E-27,
+        Conductance
+    );
+    make_unit!(
+        QuectoSiemens,
+        "qS",
+        "quectosiemens",
+        "quectosiemens",
+        Metric,
+        1E-30,
+        Conductance
+    );
+}
+
+parse::units! {
+    /// Parse an electrical conductance in the unit named by its symbol (e.g.
+    /// `"20 mS"`).
+    parse, UNITS: Conductance, ConductanceUnit;
+    metric::{
+        QuettaSiemens,
+        RonnaSiemens,
+        YottaSiemens,
+        ZettaSiemens,
+        ExaSiemens,
+        PetaSiemens,
+        TeraSiemens,
+        GigaSiemens,
+        MegaSiemens,
+        KiloSiemens,
+        Siemens,
+        DeciSiemens,
+        CentiSiemens,
+        MilliSiemens,
+        MicroSiemens,
+        NanoSiemens,
+        PicoSiemens,
+        FemtoSiemens,
+        AttoSiemens,
+        ZeptoSiemens,
+        YoctoSiemens,
+        RontoSiemens,
+        QuectoSiemens,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::voltage::metric::Volt;
+    use crate::{Unit, assert_quantity_eq};
+
+    #[test]
+    fn multiply_and_invert() {
+        let conductance = metric::MilliSiemens(20.0_f64);
+        assert_quantity_eq!(conductance.multiply_voltage(&Volt(5.0)), Ampere(0.1));
+        assert_quantity_eq!(conductance.resistance(), Ohm(50.0));
+        assert_eq!(metric::Siemens(1.0).plural(), "siemens");
+    }
+}
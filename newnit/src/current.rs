@@ -5,36 +5,277 @@
 //!
 //! [`Ampere`]: metric::Ampere
 
-use crate::Unit;
+use crate::charge::metric::Coulomb;
+use crate::inductance::Inductance;
+use crate::magnetic_flux::metric::Weber;
+use crate::power::metric::Watt;
+use crate::resistance::Resistance;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::voltage::Voltage;
+use crate::voltage::metric::Volt;
+use crate::{Quantity, dimension, parse};
 
-pub trait Current: Unit {}
+/// Types that are units of current.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+pub trait Current<V: Scalar = f64>: Quantity<V, Dimension = dimension::Current> {
+    /// Multiply a unit of current with a unit of time.
+    fn multiply_time(&self, rhs: &dyn Time<V>) -> Coulomb<V> {
+        Coulomb(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of current with a unit of resistance.
+    fn multiply_resistance(&self, rhs: &dyn Resistance<V>) -> Volt<V> {
+        Volt(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of current with a unit of voltage.
+    fn multiply_voltage(&self, rhs: &dyn Voltage<V>) -> Watt<V> {
+        Watt(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of current with a unit of inductance.
+    fn multiply_inductance(&self, rhs: &dyn Inductance<V>) -> Weber<V> {
+        Weber(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Current>> Current<V> for T {}
 
 pub mod metric {
     use super::Current;
-    use crate::{Unit, make_unit};
+    use crate::make_unit;
     use newnit_derive::{Current, Unit};
 
-    make_unit!(QuettaAmpere, 1E+30, Current);
-    make_unit!(RonnaAmpere, 1E+27, Current);
-    make_unit!(YottaAmpere, 1E+24, Current);
-    make_unit!(ZettaAmpere, 1E+21, Current);
-    make_unit!(ExaAmpere, 1E+18, Current);
-    make_unit!(PetaAmpere, 1E+15, Current);
-    make_unit!(TeraAmpere, 1E+12, Current);
-    make_unit!(GigaAmpere, 1E+9, Current);
-    make_unit!(MegaAmpere, 1E+6, Current);
-    make_unit!(KiloAmpere, 1E+3, Current);
-    make_unit!(Ampere, 1.0, Current);
-    make_unit!(DeciAmpere, 1E-1, Current);
-    make_unit!(CentiAmpere, 1E-2, Current);
-    make_unit!(MilliAmpere, 1E-3, Current);
-    make_unit!(MicroAmpere, 1E-6, Current);
-    make_unit!(NanoAmpere, 1E-9, Current);
-    make_unit!(PicoAmpere, 1E-12, Current);
-    make_unit!(FemtoAmpere, 1E-15, Current);
-    make_unit!(AttoAmpere, 1E-18, Current);
-    make_unit!(ZeptoAmpere, 1E-21, Current);
-    make_unit!(YoctoAmpere, 1E-24, Current);
-    make_unit!(RontoAmpere, 1E-27, Current);
-    make_unit!(QuectoAmpere, 1E-30, Current);
+    make_unit!(
+        QuettaAmpere,
+        "QA",
+        "quettaampere",
+        "quettaamperes",
+        Metric,
+        1E+30,
+        Current
+    );
+    make_unit!(
+        RonnaAmpere,
+        "RA",
+        "ronnaampere",
+        "ronnaamperes",
+        Metric,
+        1E+27,
+        Current
+    );
+    make_unit!(
+        YottaAmpere,
+        "YA",
+        "yottaampere",
+        "yottaamperes",
+        Metric,
+        1E+24,
+        Current
+    );
+    make_unit!(
+        ZettaAmpere,
+        "ZA",
+        "zettaampere",
+        "zettaamperes",
+        Metric,
+        1E+21,
+        Current
+    );
+    make_unit!(
+        ExaAmpere,
+        "EA",
+        "exaampere",
+        "exaamperes",
+        Metric,
+        1E+18,
+        Current
+    );
+    make_unit!(
+        PetaAmpere,
+        "PA",
+        "petaampere",
+        "petaamperes",
+        Metric,
+        1E+15,
+        Current
+    );
+    make_unit!(
+        TeraAmpere,
+        "TA",
+        "teraampere",
+        "teraamperes",
+        Metric,
+        1E+12,
+        Current
+    );
+    make_unit!(
+        GigaAmpere,
+        "GA",
+        "gigaampere",
+        "gigaamperes",
+        Metric,
+        1E+9,
+        Current
+    );
+    make_unit!(
+        MegaAmpere,
+        "MA",
+        "megaampere",
+        "megaamperes",
+        Metric,
+        1E+6,
+        Current
+    );
+    make_unit!(
+        KiloAmpere,
+        "kA",
+        "kiloampere",
+        "kiloamperes",
+        Metric,
+        1E+3,
+        Current
+    );
+    make_unit!(Ampere, "A", "ampere", "amperes", Metric, 1.0, Current);
+    make_unit!(
+        DeciAmpere,
+        "dA",
+        "deciampere",
+        "deciamperes",
+        Metric,
+        1E-1,
+        Current
+    );
+    make_unit!(
+        CentiAmpere,
+        "cA",
+        "centiampere",
+        "centiamperes",
+        Metric,
+        1E-2,
+        Current
+    );
+    make_unit!(
+        MilliAmpere,
+        "mA",
+        "milliampere",
+        "milliamperes",
+        Metric,
+        1E-3,
+        Current
+    );
+    make_unit!(
+        MicroAmpere,
+        "µA",
+        "microampere",
+        "microamperes",
+        Metric,
+        1E-6,
+        Current
+    );
+    make_unit!(
+        NanoAmpere,
+        "nA",
+        "nanoampere",
+        "nanoamperes",
+        Metric,
+        1E-9,
+        Current
+    );
+    make_unit!(
+        PicoAmpere,
+        "pA",
+        "picoampere",
+        "picoamperes",
+        Metric,
+        1E-12,
+        Current
+    );
+    make_unit!(
+        FemtoAmpere,
+        "fA",
+        "femtoampere",
+        "femtoamperes",
+        Metric,
+        1E-15,
+        Current
+    );
+    make_unit!(
+        AttoAmpere,
+        "aA",
+        "attoampere",
+        "attoamperes",
+        Metric,
+        1E-18,
+        Current
+    );
+    make_unit!(
+        ZeptoAmpere,
+        "zA",
+        "zeptoampere",
+        "zeptoamperes",
+        Metric,
+        1E-21,
+        Current
+    );
+    make_unit!(
+        YoctoAmpere,
+        "yA",
+        "yoctoampere",
+        "yoctoamperes",
+        Metric,
+        1E-24,
+        Current
+    );
+    make_unit!(
+        RontoAmpere,
+        "rA",
+        "rontoampere",
+        "rontoamperes",
+        Metric,
+        1E-27,
+        Current
+    );
+    make_unit!(
+        QuectoAmpere,
+        "qA",
+        "quectoampere",
+        "quectoamperes",
+        Metric,
+        1E-30,
+        Current
+    );
+}
+
+parse::units! {
+    /// Parse a current in the unit named by its symbol (e.g. `"20 mA"`).
+    parse, UNITS: Current, CurrentUnit;
+    metric::{
+        QuettaAmpere,
+        RonnaAmpere,
+        YottaAmpere,
+        ZettaAmpere,
+        ExaAmpere,
+        PetaAmpere,
+        TeraAmpere,
+        GigaAmpere,
+        MegaAmpere,
+        KiloAmpere,
+        Ampere,
+        DeciAmpere,
+        CentiAmpere,
+        MilliAmpere,
+        MicroAmpere,
+        NanoAmpere,
+        PicoAmpere,
+        FemtoAmpere,
+        AttoAmpere,
+        ZeptoAmpere,
+        YoctoAmpere,
+        RontoAmpere,
+        QuectoAmpere,
+    },
 }
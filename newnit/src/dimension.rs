@@ -0,0 +1,800 @@
+//! Type level dimensions of quantities.
+//!
+//! A dimension is represented by the type [`Dim`], parametrized by the
+//! exponents of the seven SI base dimensions (in the order length, mass, time,
+//! electric current, thermodynamic temperature, amount of substance and
+//! luminous intensity). The exponents are [`typenum`] integers, so dimensions
+//! can be multiplied and divided at compile time, which is what allows
+//! operations between units of different quantities to yield correctly
+//! dimensioned results.
+//!
+//! Named dimensions of the quantities provided by this library are available
+//! as type aliases (e.g. [`Length`], [`Velocity`]).
+//!
+//! Plane and solid angles are dimensionless in the SI, which leaves e.g. the
+//! lumen (cd·sr) of the same dimension as the candela, and the radian of the
+//! same dimension as ratios. Dimensions multiplied by an angle are therefore
+//! wrapped in [`WithAngle`] or [`WithSolidAngle`], so that such quantities are
+//! told apart:
+//!
+//! ```compile_fail
+//! use newnit::dimension::{LuminousFlux, LuminousIntensity};
+//!
+//! let flux: LuminousFlux = LuminousIntensity::new();
+//! ```
+//!
+//! Differences of quantities that can't be negative (e.g. absolute pressures)
+//! are wrapped in [`Difference`] in the same way.
+//!
+//! # Examples
+//! ```
+//! use newnit::dimension::{Length, Product, Quotient, Time, Velocity};
+//!
+//! // length / time is a velocity
+//! let velocity: Velocity = Quotient::<Length, Time>::new();
+//!
+//! // velocity * time is a length
+//! let length: Length = Product::<Velocity, Time>::new();
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use typenum::{
+    Diff, Integer, N1, N2, N3, NInt, Negate, NonZero, P1, P2, P3, P4, PInt, Sum, Unsigned, Z0,
+};
+
+use crate::rational::Rational;
+
+/// A dimension with the given exponents of base dimensions.
+///
+/// The type parameters are exponents of length (`L`), mass (`M`), time (`T`),
+/// electric current (`I`), thermodynamic temperature (`Th`), amount of
+/// substance (`N`) and luminous intensity (`J`) respectively.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dim<L, M, T, I, Th, N, J>(PhantomData<(L, M, T, I, Th, N, J)>);
+
+impl<L, M, T, I, Th, N, J> Dim<L, M, T, I, Th, N, J> {
+    /// Create a value of this dimension type.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Types that are dimensions.
+///
+/// Provides the exponents of the dimension as runtime values.
+pub trait Dimension {
+    /// Exponents of the seven SI base dimensions, in the order length, mass,
+    /// time, electric current, thermodynamic temperature, amount of substance
+    /// and luminous intensity.
+    const EXPONENTS: [i32; 7];
+
+    /// Whether the dimension is of points on an affine scale (see
+    /// [`Affine`]).
+    const AFFINE: bool = false;
+
+    /// Exponent of plane angle, which the SI counts as dimensionless (see
+    /// [`WithAngle`]).
+    const ANGLE: i32 = 0;
+
+    /// Exponent of solid angle, which the SI counts as dimensionless (see
+    /// [`WithSolidAngle`]).
+    const SOLID_ANGLE: i32 = 0;
+
+    /// Whether the dimension is of differences of quantities that can't be
+    /// negative themselves (see [`Difference`]).
+    const DIFFERENCE: bool = false;
+
+    /// The least physically possible value of quantities of the dimension in
+    /// base units, if any (e.g. zero for masses, absolute zero for
+    /// temperatures).
+    const MINIMUM: Option<Rational> = minimum(Self::EXPONENTS, Self::AFFINE);
+}
+
+impl<L, M, T, I, Th, N, J> Dimension for Dim<L, M, T, I, Th, N, J>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    N: Integer,
+    J: Integer,
+{
+    const EXPONENTS: [i32; 7] = [L::I32, M::I32, T::I32, I::I32, Th::I32, N::I32, J::I32];
+}
+
+impl<L1, M1, T1, I1, Th1, N1, J1, L2, M2, T2, I2, Th2, N2, J2> Mul<Dim<L2, M2, T2, I2, Th2, N2, J2>>
+    for Dim<L1, M1, T1, I1, Th1, N1, J1>
+where
+    L1: Add<L2>,
+    M1: Add<M2>,
+    T1: Add<T2>,
+    I1: Add<I2>,
+    Th1: Add<Th2>,
+    N1: Add<N2>,
+    J1: Add<J2>,
+{
+    type Output = Dim<
+        Sum<L1, L2>,
+        Sum<M1, M2>,
+        Sum<T1, T2>,
+        Sum<I1, I2>,
+        Sum<Th1, Th2>,
+        Sum<N1, N2>,
+        Sum<J1, J2>,
+    >;
+
+    fn mul(self, _rhs: Dim<L2, M2, T2, I2, Th2, N2, J2>) -> Self::Output {
+        Dim::new()
+    }
+}
+
+impl<L1, M1, T1, I1, Th1, N1, J1, L2, M2, T2, I2, Th2, N2, J2> Div<Dim<L2, M2, T2, I2, Th2, N2, J2>>
+    for Dim<L1, M1, T1, I1, Th1, N1, J1>
+where
+    L1: Sub<L2>,
+    M1: Sub<M2>,
+    T1: Sub<T2>,
+    I1: Sub<I2>,
+    Th1: Sub<Th2>,
+    N1: Sub<N2>,
+    J1: Sub<J2>,
+{
+    type Output = Dim<
+        Diff<L1, L2>,
+        Diff<M1, M2>,
+        Diff<T1, T2>,
+        Diff<I1, I2>,
+        Diff<Th1, Th2>,
+        Diff<N1, N2>,
+        Diff<J1, J2>,
+    >;
+
+    fn div(self, _rhs: Dim<L2, M2, T2, I2, Th2, N2, J2>) -> Self::Output {
+        Dim::new()
+    }
+}
+
+/// Dimension of points on an affine scale of dimension `D`.
+///
+/// Units with an offset from their base unit (e.g. the degree Celsius) measure
+/// points on a scale rather than amounts of `D`. Differences of such points
+/// are of dimension `D`, but the points themselves can't be added together,
+/// multiplied or divided.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Affine<D>(PhantomData<D>);
+
+impl<D: Dimension> Dimension for Affine<D> {
+    const AFFINE: bool = true;
+    const ANGLE: i32 = D::ANGLE;
+    const DIFFERENCE: bool = D::DIFFERENCE;
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const SOLID_ANGLE: i32 = D::SOLID_ANGLE;
+}
+
+/// Dimension `D` multiplied by a plane angle to the power `A` (e.g. angular
+/// velocity, rad/s).
+///
+/// Quantities of this dimension have the exponents of `D`, but are distinct
+/// from quantities of `D`. They can be multiplied and divided by quantities of
+/// any other dimension, the exponents of angle add up, and the angle cancels
+/// when they do (e.g. rad / rad is dimensionless). Dimensions multiplied by
+/// both angles wrap the solid angle in the plane angle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WithAngle<D, A = P1>(PhantomData<(D, A)>);
+
+impl<D, A> WithAngle<D, A> {
+    /// Create a value of this dimension type.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<D: Dimension, A: Integer> Dimension for WithAngle<D, A> {
+    const ANGLE: i32 = D::ANGLE + A::I32;
+    const DIFFERENCE: bool = D::DIFFERENCE;
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const SOLID_ANGLE: i32 = D::SOLID_ANGLE;
+}
+
+/// Exponents of plane angle, mapping a dimension `D` to `D` multiplied by a
+/// plane angle to the power of the exponent (see [`WithAngle`]).
+pub trait AngleExponent<D> {
+    /// `D` multiplied by the plane angle, which is `D` itself for the exponent
+    /// zero.
+    type Output;
+}
+
+impl<D> AngleExponent<D> for Z0 {
+    type Output = D;
+}
+
+impl<D, U: Unsigned + NonZero> AngleExponent<D> for PInt<U> {
+    type Output = WithAngle<D, Self>;
+}
+
+impl<D, U: Unsigned + NonZero> AngleExponent<D> for NInt<U> {
+    type Output = WithAngle<D, Self>;
+}
+
+impl<D, A, R> Mul<R> for WithAngle<D, A>
+where
+    D: Mul<R>,
+    R: PlainOrSolidAngle,
+{
+    type Output = WithAngle<Product<D, R>, A>;
+
+    fn mul(self, _rhs: R) -> Self::Output {
+        WithAngle::new()
+    }
+}
+
+impl<D, A, R> Div<R> for WithAngle<D, A>
+where
+    D: Div<R>,
+    R: PlainOrSolidAngle,
+{
+    type Output = WithAngle<Quotient<D, R>, A>;
+
+    fn div(self, _rhs: R) -> Self::Output {
+        WithAngle::new()
+    }
+}
+
+impl<D1, A1, D2, A2> Mul<WithAngle<D2, A2>> for WithAngle<D1, A1>
+where
+    D1: Mul<D2>,
+    A1: Add<A2>,
+    Sum<A1, A2>: AngleExponent<Product<D1, D2>>,
+    <Sum<A1, A2> as AngleExponent<Product<D1, D2>>>::Output: Default,
+{
+    type Output = <Sum<A1, A2> as AngleExponent<Product<D1, D2>>>::Output;
+
+    fn mul(self, _rhs: WithAngle<D2, A2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+impl<D1, A1, D2, A2> Div<WithAngle<D2, A2>> for WithAngle<D1, A1>
+where
+    D1: Div<D2>,
+    A1: Sub<A2>,
+    Diff<A1, A2>: AngleExponent<Quotient<D1, D2>>,
+    <Diff<A1, A2> as AngleExponent<Quotient<D1, D2>>>::Output: Default,
+{
+    type Output = <Diff<A1, A2> as AngleExponent<Quotient<D1, D2>>>::Output;
+
+    fn div(self, _rhs: WithAngle<D2, A2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+impl<D, A, L, M, T, I, Th, N, J> Mul<WithAngle<D, A>> for Dim<L, M, T, I, Th, N, J>
+where
+    Self: Mul<D>,
+{
+    type Output = WithAngle<Product<Self, D>, A>;
+
+    fn mul(self, _rhs: WithAngle<D, A>) -> Self::Output {
+        WithAngle::new()
+    }
+}
+
+impl<D, A, L, M, T, I, Th, N, J> Div<WithAngle<D, A>> for Dim<L, M, T, I, Th, N, J>
+where
+    Self: Div<D>,
+    A: Neg,
+{
+    type Output = WithAngle<Quotient<Self, D>, Negate<A>>;
+
+    fn div(self, _rhs: WithAngle<D, A>) -> Self::Output {
+        WithAngle::new()
+    }
+}
+
+impl<D, S, D2, A> Mul<WithAngle<D2, A>> for WithSolidAngle<D, S>
+where
+    Self: Mul<D2>,
+{
+    type Output = WithAngle<Product<Self, D2>, A>;
+
+    fn mul(self, _rhs: WithAngle<D2, A>) -> Self::Output {
+        WithAngle::new()
+    }
+}
+
+impl<D, S, D2, A> Div<WithAngle<D2, A>> for WithSolidAngle<D, S>
+where
+    Self: Div<D2>,
+    A: Neg,
+{
+    type Output = WithAngle<Quotient<Self, D2>, Negate<A>>;
+
+    fn div(self, _rhs: WithAngle<D2, A>) -> Self::Output {
+        WithAngle::new()
+    }
+}
+
+/// Dimension `D` multiplied by a solid angle to the power `S` (e.g. luminous
+/// flux, cd·sr).
+///
+/// Quantities of this dimension have the exponents of `D`, but are distinct
+/// from quantities of `D`. They can be multiplied and divided by quantities of
+/// any other dimension, the exponents of solid angle add up, and the solid
+/// angle cancels when they do (e.g. lm / cd is a solid angle, sr / sr is
+/// dimensionless).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WithSolidAngle<D, S = P1>(PhantomData<(D, S)>);
+
+impl<D, S> WithSolidAngle<D, S> {
+    /// Create a value of this dimension type.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<D: Dimension, S: Integer> Dimension for WithSolidAngle<D, S> {
+    const ANGLE: i32 = D::ANGLE;
+    const DIFFERENCE: bool = D::DIFFERENCE;
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const SOLID_ANGLE: i32 = D::SOLID_ANGLE + S::I32;
+}
+
+/// Exponents of solid angle, mapping a dimension `D` to `D` multiplied by a
+/// solid angle to the power of the exponent (see [`WithSolidAngle`]).
+pub trait SolidAngleExponent<D> {
+    /// `D` multiplied by the solid angle, which is `D` itself for the exponent
+    /// zero.
+    type Output;
+}
+
+impl<D> SolidAngleExponent<D> for Z0 {
+    type Output = D;
+}
+
+impl<D, U: Unsigned + NonZero> SolidAngleExponent<D> for PInt<U> {
+    type Output = WithSolidAngle<D, Self>;
+}
+
+impl<D, U: Unsigned + NonZero> SolidAngleExponent<D> for NInt<U> {
+    type Output = WithSolidAngle<D, Self>;
+}
+
+impl<D, S, L, M, T, I, Th, N, J> Mul<Dim<L, M, T, I, Th, N, J>> for WithSolidAngle<D, S>
+where
+    D: Mul<Dim<L, M, T, I, Th, N, J>>,
+{
+    type Output = WithSolidAngle<Product<D, Dim<L, M, T, I, Th, N, J>>, S>;
+
+    fn mul(self, _rhs: Dim<L, M, T, I, Th, N, J>) -> Self::Output {
+        WithSolidAngle::new()
+    }
+}
+
+impl<D, S, L, M, T, I, Th, N, J> Div<Dim<L, M, T, I, Th, N, J>> for WithSolidAngle<D, S>
+where
+    D: Div<Dim<L, M, T, I, Th, N, J>>,
+{
+    type Output = WithSolidAngle<Quotient<D, Dim<L, M, T, I, Th, N, J>>, S>;
+
+    fn div(self, _rhs: Dim<L, M, T, I, Th, N, J>) -> Self::Output {
+        WithSolidAngle::new()
+    }
+}
+
+impl<D1, S1, D2, S2> Mul<WithSolidAngle<D2, S2>> for WithSolidAngle<D1, S1>
+where
+    D1: Mul<D2>,
+    S1: Add<S2>,
+    Sum<S1, S2>: SolidAngleExponent<Product<D1, D2>>,
+    <Sum<S1, S2> as SolidAngleExponent<Product<D1, D2>>>::Output: Default,
+{
+    type Output = <Sum<S1, S2> as SolidAngleExponent<Product<D1, D2>>>::Output;
+
+    fn mul(self, _rhs: WithSolidAngle<D2, S2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+impl<D1, S1, D2, S2> Div<WithSolidAngle<D2, S2>> for WithSolidAngle<D1, S1>
+where
+    D1: Div<D2>,
+    S1: Sub<S2>,
+    Diff<S1, S2>: SolidAngleExponent<Quotient<D1, D2>>,
+    <Diff<S1, S2> as SolidAngleExponent<Quotient<D1, D2>>>::Output: Default,
+{
+    type Output = <Diff<S1, S2> as SolidAngleExponent<Quotient<D1, D2>>>::Output;
+
+    fn div(self, _rhs: WithSolidAngle<D2, S2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+impl<D, S, L, M, T, I, Th, N, J> Mul<WithSolidAngle<D, S>> for Dim<L, M, T, I, Th, N, J>
+where
+    Self: Mul<D>,
+{
+    type Output = WithSolidAngle<Product<Self, D>, S>;
+
+    fn mul(self, _rhs: WithSolidAngle<D, S>) -> Self::Output {
+        WithSolidAngle::new()
+    }
+}
+
+impl<D, S, L, M, T, I, Th, N, J> Div<WithSolidAngle<D, S>> for Dim<L, M, T, I, Th, N, J>
+where
+    Self: Div<D>,
+    S: Neg,
+{
+    type Output = WithSolidAngle<Quotient<Self, D>, Negate<S>>;
+
+    fn div(self, _rhs: WithSolidAngle<D, S>) -> Self::Output {
+        WithSolidAngle::new()
+    }
+}
+
+/// Dimensions without a plane angle, which are the operands a [`WithAngle`]
+/// passes on to the dimension it wraps.
+pub trait PlainOrSolidAngle {}
+
+impl<L, M, T, I, Th, N, J> PlainOrSolidAngle for Dim<L, M, T, I, Th, N, J> {}
+
+impl<D, S> PlainOrSolidAngle for WithSolidAngle<D, S> {}
+
+/// Dimension of differences of quantities of dimension `D` that can't be
+/// negative themselves (e.g. absolute pressures).
+///
+/// Quantities of this dimension have the exponents of `D`, but are distinct
+/// from quantities of `D`, and unlike them can be negative. They can be
+/// multiplied and divided by quantities of any other dimension, which yields
+/// the dimension of the product or quotient of `D`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Difference<D>(PhantomData<D>);
+
+impl<D> Difference<D> {
+    /// Create a value of this dimension type.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<D: Dimension> Dimension for Difference<D> {
+    const ANGLE: i32 = D::ANGLE;
+    const DIFFERENCE: bool = true;
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const MINIMUM: Option<Rational> = None;
+    const SOLID_ANGLE: i32 = D::SOLID_ANGLE;
+}
+
+impl<D, R> Mul<R> for Difference<D>
+where
+    D: Mul<R> + Default,
+{
+    type Output = Product<D, R>;
+
+    fn mul(self, rhs: R) -> Self::Output {
+        D::default() * rhs
+    }
+}
+
+impl<D, R> Div<R> for Difference<D>
+where
+    D: Div<R> + Default,
+{
+    type Output = Quotient<D, R>;
+
+    fn div(self, rhs: R) -> Self::Output {
+        D::default() / rhs
+    }
+}
+
+// Differences on the right hand side are taken apart for each kind of left
+// hand side, as blanket impls for them would overlap with the impls above.
+macro_rules! impl_difference_operand {
+    ($([$($param:ident),*] $lhs:ty;)*) => {
+        $(
+            impl<D, $($param),*> Mul<Difference<D>> for $lhs
+            where
+                Self: Mul<D>,
+                D: Default,
+            {
+                type Output = Product<Self, D>;
+
+                fn mul(self, _rhs: Difference<D>) -> Self::Output {
+                    self * D::default()
+                }
+            }
+
+            impl<D, $($param),*> Div<Difference<D>> for $lhs
+            where
+                Self: Div<D>,
+                D: Default,
+            {
+                type Output = Quotient<Self, D>;
+
+                fn div(self, _rhs: Difference<D>) -> Self::Output {
+                    self / D::default()
+                }
+            }
+        )*
+    };
+}
+impl_difference_operand! {
+    [L, M, T, I, Th, N, J] Dim<L, M, T, I, Th, N, J>;
+    [D2, A] WithAngle<D2, A>;
+    [D2, S] WithSolidAngle<D2, S>;
+}
+
+/// The least physically possible value of quantities of a dimension with the
+/// given exponents.
+///
+/// Amounts of mass, substance and luminous intensity and points on the
+/// thermodynamic temperature scale can't be negative. Absolute pressures can't
+/// either, but share their dimension with quantities that can (e.g. stresses),
+/// so their minimum is the one of their quantity
+/// ([`Quantity::minimum`](crate::Quantity::minimum)).
+const fn minimum(exponents: [i32; 7], affine: bool) -> Option<Rational> {
+    match (exponents, affine) {
+        ([0, 1, 0, 0, 0, 0, 0], false)
+        | ([0, 0, 0, 0, 0, 1, 0], false)
+        | ([0, 0, 0, 0, 0, 0, 1], false)
+        | ([0, 0, 0, 0, 1, 0, 0], true) => Some(Rational::ZERO),
+        _ => None,
+    }
+}
+
+/// Symbols of the base units of dimensions with the given exponents and
+/// exponents of plane and solid angle (e.g. `m·kg·s^-2`, `cd·sr`), or `1` for
+/// dimensionless quantities.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct BaseUnits(pub(crate) [i32; 7], pub(crate) i32, pub(crate) i32);
+
+impl BaseUnits {
+    /// The base units of the dimension `D`.
+    pub(crate) const fn of<D: Dimension>() -> Self {
+        Self(D::EXPONENTS, D::ANGLE, D::SOLID_ANGLE)
+    }
+
+    /// Whether the dimension is dimensionless, without any angle.
+    pub(crate) fn is_one(&self) -> bool {
+        self.0 == [0; 7] && self.1 == 0 && self.2 == 0
+    }
+}
+
+impl fmt::Display for BaseUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SYMBOLS: [&str; 9] = ["m", "kg", "s", "A", "K", "mol", "cd", "rad", "sr"];
+
+        if self.is_one() {
+            return write!(f, "1");
+        }
+
+        let exponents = self.0.into_iter().chain([self.1, self.2]);
+        let mut separator = "";
+        for (symbol, exponent) in SYMBOLS.iter().zip(exponents) {
+            match exponent {
+                0 => continue,
+                1 => write!(f, "{separator}{symbol}")?,
+                _ => write!(f, "{separator}{symbol}^{exponent}")?,
+            }
+            separator = "·";
+        }
+
+        Ok(())
+    }
+}
+
+/// The dimension of a product of quantities of dimensions `A` and `B`.
+pub type Product<A, B> = <A as Mul<B>>::Output;
+
+/// The dimension of a quotient of quantities of dimensions `A` and `B`.
+pub type Quotient<A, B> = <A as Div<B>>::Output;
+
+/// Dimension of dimensionless quantities (e.g. ratios).
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// Dimension of length (L).
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// Dimension of mass (M).
+pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+
+/// Dimension of time (T).
+pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+
+/// Dimension of electric current (I).
+pub type Current = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+
+/// Dimension of thermodynamic temperature (Θ).
+pub type Temperature = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+
+/// Dimension of amount of substance (N).
+pub type SubstanceAmount = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+
+/// Dimension of luminous intensity (J).
+pub type LuminousIntensity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+/// Dimension of area (L²).
+pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// Dimension of volume (L³).
+pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// Dimension of velocity (L T⁻¹).
+pub type Velocity = Dim<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+
+/// Dimension of acceleration (L T⁻²).
+pub type Acceleration = Dim<P1, Z0, N2, Z0, Z0, Z0, Z0>;
+
+/// Dimension of force (M L T⁻²).
+pub type Force = Dim<P1, P1, N2, Z0, Z0, Z0, Z0>;
+
+/// Dimension of pressure (M L⁻¹ T⁻²).
+pub type Pressure = Dim<N1, P1, N2, Z0, Z0, Z0, Z0>;
+
+/// Dimension of differences of pressures (M L⁻¹ T⁻²).
+pub type PressureDifference = Difference<Pressure>;
+
+/// Dimension of energy (M L² T⁻²).
+pub type Energy = Dim<P2, P1, N2, Z0, Z0, Z0, Z0>;
+
+/// Dimension of power (M L² T⁻³).
+pub type Power = Dim<P2, P1, N3, Z0, Z0, Z0, Z0>;
+
+/// Dimension of electric charge (T I).
+pub type Charge = Dim<Z0, Z0, P1, P1, Z0, Z0, Z0>;
+
+/// Dimension of voltage (M L² T⁻³ I⁻¹).
+pub type Voltage = Dim<P2, P1, N3, N1, Z0, Z0, Z0>;
+
+/// Dimension of electrical resistance (M L² T⁻³ I⁻²).
+pub type Resistance = Dim<P2, P1, N3, N2, Z0, Z0, Z0>;
+
+/// Dimension of electrical conductance (M⁻¹ L⁻² T³ I²).
+pub type Conductance = Dim<N2, N1, P3, P2, Z0, Z0, Z0>;
+
+/// Dimension of capacitance (M⁻¹ L⁻² T⁴ I²).
+pub type Capacitance = Dim<N2, N1, P4, P2, Z0, Z0, Z0>;
+
+/// Dimension of inductance (M L² T⁻² I⁻²).
+pub type Inductance = Dim<P2, P1, N2, N2, Z0, Z0, Z0>;
+
+/// Dimension of magnetic flux (M L² T⁻² I⁻¹).
+pub type MagneticFlux = Dim<P2, P1, N2, N1, Z0, Z0, Z0>;
+
+/// Dimension of magnetic flux density (M T⁻² I⁻¹).
+pub type MagneticFluxDensity = Dim<Z0, P1, N2, N1, Z0, Z0, Z0>;
+
+/// Dimension of frequency (T⁻¹).
+pub type Frequency = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+
+/// Dimension of mass density (M L⁻³).
+pub type Density = Dim<N3, P1, Z0, Z0, Z0, Z0, Z0>;
+
+/// Dimension of plane angle (rad).
+pub type Angle = WithAngle<Dimensionless>;
+
+/// Dimension of solid angle (sr).
+pub type SolidAngle = WithSolidAngle<Dimensionless>;
+
+/// Dimension of luminous flux (J sr).
+pub type LuminousFlux = WithSolidAngle<LuminousIntensity>;
+
+/// Dimension of luminance (J L⁻²).
+pub type Luminance = Dim<N2, Z0, Z0, Z0, Z0, Z0, P1>;
+
+/// Dimension of illuminance (J L⁻² sr).
+pub type Illuminance = WithSolidAngle<Luminance>;
+
+/// Dimension of luminous energy (J T sr).
+pub type LuminousEnergy = WithSolidAngle<Dim<Z0, Z0, P1, Z0, Z0, Z0, P1>>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn exponents_of_named_dimensions() {
+        assert_eq!(Dimensionless::EXPONENTS, [0; 7]);
+        assert_eq!(Velocity::EXPONENTS, [1, 0, -1, 0, 0, 0, 0]);
+        assert_eq!(Force::EXPONENTS, [1, 1, -2, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn product_and_quotient() {
+        let _: Area = Product::<Length, Length>::new();
+        let _: Velocity = Quotient::<Length, Time>::new();
+        let _: Force = Product::<Mass, Acceleration>::new();
+        let _: Energy = Product::<Force, Length>::new();
+        let _: Pressure = Quotient::<Force, Area>::new();
+        let _: Charge = Product::<Current, Time>::new();
+        let _: Resistance = Quotient::<Voltage, Current>::new();
+        let _: Conductance = Quotient::<Current, Voltage>::new();
+        let _: Capacitance = Quotient::<Charge, Voltage>::new();
+        let _: Inductance = Quotient::<MagneticFlux, Current>::new();
+        let _: MagneticFluxDensity = Quotient::<MagneticFlux, Area>::new();
+        let _: Power = Product::<Voltage, Current>::new();
+        let _: Power = Quotient::<Energy, Time>::new();
+        let _: Dimensionless = Quotient::<Mass, Mass>::new();
+        let _: LuminousFlux = Product::<LuminousIntensity, SolidAngle>::new();
+        let _: LuminousFlux = Product::<Illuminance, Area>::new();
+        let _: Illuminance = Quotient::<LuminousFlux, Area>::new();
+        let _: LuminousEnergy = Product::<LuminousFlux, Time>::new();
+        let _: LuminousIntensity = Quotient::<LuminousFlux, SolidAngle>::new();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn solid_angles() {
+        assert_eq!(LuminousFlux::EXPONENTS, LuminousIntensity::EXPONENTS);
+        assert_eq!(LuminousFlux::SOLID_ANGLE, 1);
+        assert_eq!(LuminousIntensity::SOLID_ANGLE, 0);
+        assert_eq!(SolidAngle::EXPONENTS, [0; 7]);
+        assert_eq!(format!("{}", BaseUnits::of::<LuminousFlux>()), "cd·sr");
+        assert_eq!(format!("{}", BaseUnits::of::<SolidAngle>()), "sr");
+
+        let _: Dimensionless = Quotient::<SolidAngle, SolidAngle>::new();
+        let _: SolidAngle = Quotient::<LuminousFlux, LuminousIntensity>::new();
+        let _: LuminousIntensity =
+            Product::<Quotient<LuminousIntensity, SolidAngle>, SolidAngle>::new();
+        assert_eq!(Product::<SolidAngle, SolidAngle>::SOLID_ANGLE, 2);
+        assert_eq!(Quotient::<Area, SolidAngle>::SOLID_ANGLE, -1);
+
+        // Plane angles wrap solid angles in either order of the operands.
+        let _: WithAngle<SolidAngle> = Product::<Angle, SolidAngle>::new();
+        let _: WithAngle<SolidAngle> = Product::<SolidAngle, Angle>::new();
+        let _: WithAngle<LuminousIntensity> =
+            Quotient::<Product<Angle, LuminousFlux>, SolidAngle>::new();
+        let _: Dimensionless =
+            Quotient::<Product<Angle, SolidAngle>, Product<SolidAngle, Angle>>::new();
+    }
+
+    #[test]
+    fn differences() {
+        let _: Force = Product::<PressureDifference, Area>::new();
+        let _: Force = Product::<Area, PressureDifference>::new();
+        let _: Dimensionless = Quotient::<PressureDifference, PressureDifference>::new();
+        let _: Dimensionless = Quotient::<Pressure, PressureDifference>::new();
+        let _: Product<Pressure, Pressure> =
+            Product::<PressureDifference, PressureDifference>::new();
+        let _: WithAngle<Pressure> = Product::<Angle, PressureDifference>::new();
+        let _: WithSolidAngle<Pressure> = Product::<PressureDifference, SolidAngle>::new();
+        let _: Quotient<Dimensionless, Pressure> =
+            Quotient::<Dimensionless, PressureDifference>::new();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn plane_angles() {
+        assert_eq!(Angle::EXPONENTS, Dimensionless::EXPONENTS);
+        assert_eq!((Angle::ANGLE, Angle::SOLID_ANGLE), (1, 0));
+        let _: Dimensionless = Quotient::<Angle, Angle>::new();
+        let _: WithAngle<Frequency> = Quotient::<Angle, Time>::new();
+        let _: Length = Product::<Quotient<Length, Angle>, Angle>::new();
+        let _: Angle = Quotient::<Product<Angle, Angle>, Angle>::new();
+        assert_eq!(Product::<Angle, Angle>::ANGLE, 2);
+        assert_eq!(Quotient::<Length, Angle>::ANGLE, -1);
+        assert_eq!(
+            format!("{}", BaseUnits::of::<Quotient<Length, Angle>>()),
+            "m·rad^-1"
+        );
+        assert_eq!(
+            format!("{}", BaseUnits::of::<Quotient<Angle, Time>>()),
+            "s^-1·rad"
+        );
+    }
+
+    #[test]
+    fn physical_minimums() {
+        assert_eq!(Mass::MINIMUM, Some(Rational::ZERO));
+        assert_eq!(Pressure::MINIMUM, None);
+        assert_eq!(PressureDifference::MINIMUM, None);
+        assert_eq!(Affine::<PressureDifference>::MINIMUM, None);
+        assert_eq!(Affine::<Temperature>::MINIMUM, Some(Rational::ZERO));
+        assert_eq!(Temperature::MINIMUM, None);
+        assert_eq!(Length::MINIMUM, None);
+    }
+}
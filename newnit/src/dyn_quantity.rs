@@ -0,0 +1,646 @@
+//! Quantities with dimensions known only at runtime.
+//!
+//! A [`DynQuantity`] is a value in base units together with its dimension
+//! ([`DynDimension`]). Unlike operations between units, whose dimensions are
+//! checked at compile time, operations between dynamic quantities are checked
+//! at runtime. This is useful e.g. to evaluate formulas configured by the
+//! user.
+//!
+//! Any unit converts into a dynamic quantity, and dynamic quantities convert
+//! back into units of their dimension.
+//!
+//! # Examples
+//! ```
+//! use newnit::dyn_quantity::DynQuantity;
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::Meter;
+//! use newnit::time::metric::Second;
+//! use newnit::velocity::metric::MeterPerSecond;
+//!
+//! let distance = DynQuantity::from(&Meter(6.0_f64));
+//! let time = DynQuantity::from(&Second(2.0_f64));
+//!
+//! let speed = MeterPerSecond::try_from((distance / time).unwrap());
+//! assert_eq!(speed, Ok(MeterPerSecond(3.0)));
+//!
+//! let total = (distance + DynQuantity::from(&Foot(10.0_f64))).unwrap();
+//! assert!((total.value() - 9.048).abs() < 1e-9);
+//!
+//! assert!((distance + time).is_err());
+//! assert!(Meter::try_from(time).is_err());
+//! ```
+//!
+//! Dimensions are told apart as in types, e.g. gauge pressures don't convert
+//! into absolute pressures:
+//!
+//! ```
+//! use newnit::dyn_quantity::DynQuantity;
+//! use newnit::pressure::metric::{BarGauge, Pascal};
+//!
+//! let pressure = DynQuantity::from(&BarGauge(1.0_f64));
+//! assert!(Pascal::try_from(pressure).is_err());
+//! ```
+
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+use core::str::FromStr;
+
+use crate::Quantity;
+use crate::dimension::{self, BaseUnits, Dimension};
+use crate::parse::{self, ParseError};
+use crate::quantity::Derived;
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+
+/// Dimensions of quantities that can't be negative, whose differences are of a
+/// distinct dimension (see [`Difference`](crate::dimension::Difference)).
+const WITH_DIFFERENCES: [DynDimension; 1] = [DynDimension::of::<dimension::Pressure>()];
+
+/// A dimension known only at runtime.
+///
+/// Besides the exponents of the base dimensions, it tells apart what
+/// [`Dimension`]s tell apart in types: points on an affine scale, differences
+/// of quantities that can't be negative, and plane and solid angles.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DynDimension {
+    /// Exponents of the base dimensions, as in [`Dimension::EXPONENTS`].
+    pub exponents: [i32; 7],
+    /// Exponent of plane angle, as in [`Dimension::ANGLE`].
+    pub angle: i32,
+    /// Exponent of solid angle, as in [`Dimension::SOLID_ANGLE`].
+    pub solid_angle: i32,
+    /// Whether the dimension is of points on an affine scale, as in
+    /// [`Dimension::AFFINE`].
+    pub affine: bool,
+    /// Whether the dimension is of differences, as in
+    /// [`Dimension::DIFFERENCE`].
+    pub difference: bool,
+}
+
+impl DynDimension {
+    /// The dimension with the given exponents of base dimensions, without
+    /// angles.
+    pub const fn new(exponents: [i32; 7]) -> Self {
+        Self {
+            exponents,
+            angle: 0,
+            solid_angle: 0,
+            affine: false,
+            difference: false,
+        }
+    }
+
+    /// The dimension `D`.
+    pub const fn of<D: Dimension>() -> Self {
+        Self {
+            exponents: D::EXPONENTS,
+            angle: D::ANGLE,
+            solid_angle: D::SOLID_ANGLE,
+            affine: D::AFFINE,
+            difference: D::DIFFERENCE,
+        }
+    }
+
+    /// The dimension of the sum of quantities of dimensions `self` and `rhs`,
+    /// or of their difference if `subtract`.
+    ///
+    /// A point on an affine scale and an interval of the scale add up to a
+    /// point (in either order), the difference of two points is an interval.
+    /// Likewise, quantities that can't be negative (e.g. absolute pressures)
+    /// and their differences add up to such quantities, the difference of two
+    /// such quantities is a difference.
+    fn sum(self, rhs: Self, subtract: bool) -> Result<Self, DimensionError> {
+        let interval = |dimension: Self| Self {
+            affine: false,
+            ..dimension
+        };
+        let error = |expected| DimensionError {
+            expected,
+            found: rhs,
+        };
+
+        let difference = Self {
+            difference: true,
+            ..self
+        };
+        if !self.affine && !self.difference && rhs == difference {
+            return Ok(self);
+        }
+        let point = Self {
+            difference: false,
+            ..self
+        };
+        if !subtract && !self.affine && self.difference && rhs == point {
+            return Ok(rhs);
+        }
+        if subtract && rhs == self && WITH_DIFFERENCES.contains(&self) {
+            return Ok(difference);
+        }
+        if interval(self) != interval(rhs) {
+            return Err(error(self));
+        }
+        match (self.affine, rhs.affine, subtract) {
+            (true, true, false) => Err(error(interval(self))),
+            (false, true, true) => Err(error(self)),
+            (true, true, true) => Ok(interval(self)),
+            _ => Ok(Self {
+                affine: self.affine || rhs.affine,
+                ..self
+            }),
+        }
+    }
+
+    /// The dimension of the product of quantities of dimensions `self` and
+    /// `rhs`, or of their quotient if `divide`, or [`None`] if its exponents
+    /// overflow.
+    fn product(self, rhs: Self, divide: bool) -> Option<Self> {
+        let combine = |lhs: i32, rhs: i32| match divide {
+            true => lhs.checked_sub(rhs),
+            false => lhs.checked_add(rhs),
+        };
+        let mut exponents = self.exponents;
+        for (exponent, rhs) in exponents.iter_mut().zip(rhs.exponents) {
+            *exponent = combine(*exponent, rhs)?;
+        }
+
+        Some(Self {
+            exponents,
+            angle: combine(self.angle, rhs.angle)?,
+            solid_angle: combine(self.solid_angle, rhs.solid_angle)?,
+            affine: false,
+            difference: false,
+        })
+    }
+
+    /// The dimension of quantities of dimension `self` raised to the integer
+    /// power `n`, or [`None`] if its exponents overflow.
+    fn powi(self, n: i32) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for exponent in exponents.iter_mut() {
+            *exponent = exponent.checked_mul(n)?;
+        }
+
+        Some(Self {
+            exponents,
+            angle: self.angle.checked_mul(n)?,
+            solid_angle: self.solid_angle.checked_mul(n)?,
+            affine: false,
+            difference: false,
+        })
+    }
+}
+
+impl fmt::Display for DynDimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            BaseUnits(self.exponents, self.angle, self.solid_angle)
+        )?;
+        if self.affine {
+            write!(f, " (point)")?;
+        }
+        if self.difference {
+            write!(f, " (difference)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A value in base units of a dimension known only at runtime.
+///
+/// Addition and subtraction of quantities of different dimensions fail with a
+/// [`DimensionError`], multiplication, division and powers only if the
+/// exponents of the dimension overflow ([`ExponentOverflow`]).
+///
+/// Points on an affine scale (e.g. [`Celsius`]) are represented by their
+/// value on the base scale (e.g. [`Kelvin`]), and convert only into units of
+/// points. They can't be added together, their difference is an interval
+/// (e.g. [`DeltaCelsius`]). Products and quotients multiply points by their
+/// value on the base scale, and differences (e.g. of pressures) like the
+/// quantities they are differences of.
+///
+/// [`Celsius`]: crate::temperature::Celsius
+/// [`Kelvin`]: crate::temperature::Kelvin
+/// [`DeltaCelsius`]: crate::temperature::DeltaCelsius
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DynQuantity<V = f64> {
+    value: V,
+    dimension: DynDimension,
+}
+
+impl<V: Scalar> DynQuantity<V> {
+    /// Create a quantity from its value in base units and the exponents of
+    /// its dimension.
+    pub fn new(value: V, exponents: [i32; 7]) -> Self {
+        Self::with_dimension(value, DynDimension::new(exponents))
+    }
+
+    /// Create a quantity from its value in base units and its dimension.
+    pub fn with_dimension(value: V, dimension: DynDimension) -> Self {
+        Self { value, dimension }
+    }
+
+    /// Create a dimensionless quantity.
+    pub fn dimensionless(value: V) -> Self {
+        Self::new(value, [0; 7])
+    }
+
+    /// The value of the quantity in base units.
+    pub fn value(&self) -> V {
+        self.value
+    }
+
+    /// The dimension of the quantity.
+    pub fn dimension(&self) -> DynDimension {
+        self.dimension
+    }
+
+    /// Exponents of the dimension of the quantity, in the order of
+    /// [`Dimension::EXPONENTS`].
+    pub fn exponents(&self) -> [i32; 7] {
+        self.dimension.exponents
+    }
+
+    /// Whether the quantity is of the dimension `D`.
+    pub fn is<D: Dimension>(&self) -> bool {
+        self.dimension == DynDimension::of::<D>()
+    }
+
+    /// The quantity raised to the integer power `n`, or an error if the
+    /// exponents of its dimension overflow.
+    pub fn powi(self, n: i32) -> Result<Self, ExponentOverflow> {
+        let dimension = self.dimension.powi(n).ok_or(ExponentOverflow)?;
+        let one = V::from_rational(Rational::ONE);
+        let mut power = one;
+        let mut base = self.value;
+        let mut exponent = n.unsigned_abs();
+
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                power = power * base;
+            }
+            base = base * base;
+            exponent /= 2;
+        }
+
+        Ok(Self {
+            value: if n < 0 { one / power } else { power },
+            dimension,
+        })
+    }
+
+    /// Convert the quantity to the unit `U`, if it is of the same dimension.
+    pub fn to_unit<U: Quantity<V>>(&self) -> Result<U, DimensionError> {
+        match self.is::<U::Dimension>() {
+            true => Ok(U::from_base(self.value)),
+            false => Err(DimensionError {
+                expected: DynDimension::of::<U::Dimension>(),
+                found: self.dimension,
+            }),
+        }
+    }
+}
+
+impl<V: Scalar, U: Quantity<V> + ?Sized> From<&U> for DynQuantity<V> {
+    fn from(unit: &U) -> Self {
+        Self::with_dimension(unit.to_base(), DynDimension::of::<U::Dimension>())
+    }
+}
+
+impl<D: Dimension, V: Scalar> TryFrom<DynQuantity<V>> for Derived<D, V> {
+    type Error = DimensionError;
+
+    fn try_from(quantity: DynQuantity<V>) -> Result<Self, Self::Error> {
+        quantity.to_unit()
+    }
+}
+
+impl<V: Scalar> FromStr for DynQuantity<V> {
+    type Err = ParseError;
+
+    /// Parse a quantity given in a known unit or a compound unit expression
+    /// (e.g. `"9.81 kg*m/s^2"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, symbol) = parse::split(s)?;
+        if symbol.is_empty() {
+            return V::try_from_rational(number)
+                .map(Self::dimensionless)
+                .ok_or(ParseError::OutOfRange);
+        }
+
+        let unit = parse::resolve(symbol)?;
+        let value =
+            parse::convert(number, unit.factor, unit.offset).ok_or(ParseError::OutOfRange)?;
+
+        Ok(Self::with_dimension(value, unit.dimension()))
+    }
+}
+
+impl<V: Scalar> Add for DynQuantity<V> {
+    type Output = Result<Self, DimensionError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let dimension = self.dimension.sum(rhs.dimension, false)?;
+        Ok(Self::with_dimension(self.value + rhs.value, dimension))
+    }
+}
+
+impl<V: Scalar> Sub for DynQuantity<V> {
+    type Output = Result<Self, DimensionError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let dimension = self.dimension.sum(rhs.dimension, true)?;
+        Ok(Self::with_dimension(self.value - rhs.value, dimension))
+    }
+}
+
+impl<V: Scalar> Mul for DynQuantity<V> {
+    type Output = Result<Self, ExponentOverflow>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let dimension = self.dimension.product(rhs.dimension, false);
+        let dimension = dimension.ok_or(ExponentOverflow)?;
+        Ok(Self::with_dimension(self.value * rhs.value, dimension))
+    }
+}
+
+impl<V: Scalar> Div for DynQuantity<V> {
+    type Output = Result<Self, ExponentOverflow>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let dimension = self.dimension.product(rhs.dimension, true);
+        let dimension = dimension.ok_or(ExponentOverflow)?;
+        Ok(Self::with_dimension(self.value / rhs.value, dimension))
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for DynQuantity<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+
+        match self.dimension == DynDimension::default() {
+            true => Ok(()),
+            false => write!(f, " {}", self.dimension),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<V: defmt::Format> defmt::Format for DynQuantity<V> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{} {}", self.value, self.dimension);
+    }
+}
+
+/// Error of an operation between quantities of mismatched dimensions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DimensionError {
+    /// The expected dimension.
+    pub expected: DynDimension,
+    /// The dimension found instead.
+    pub found: DynDimension,
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Mismatched dimensions: expected {}, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for DimensionError {}
+
+/// Error of a product, quotient or power of quantities whose exponents of
+/// dimensions overflow.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExponentOverflow;
+
+impl fmt::Display for ExponentOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Overflow of the exponents of a dimension")
+    }
+}
+
+impl core::error::Error for ExponentOverflow {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Unit;
+    use crate::angle::metric::Radian;
+    use crate::area::metric::SquareMeter;
+    use crate::assert_quantity_eq;
+    use crate::dimension;
+    use crate::length::imperial::Foot;
+    use crate::length::metric::{KiloMeter, Meter};
+    use crate::luminous_flux::metric::Lumen;
+    use crate::luminous_intensity::metric::Candela;
+    use crate::pressure::metric::{BarGauge, Pascal, PascalDifferential, PascalGauge};
+    use crate::temperature::{Celsius, DeltaCelsius, Kelvin};
+    use crate::time::metric::Second;
+    use crate::velocity::metric::MeterPerSecond;
+
+    #[test]
+    fn add_same_dimensions() {
+        let sum = DynQuantity::from(&Meter(2.0_f64)) + DynQuantity::from(&KiloMeter(1.0));
+        assert_eq!(KiloMeter::try_from(sum.unwrap()), Ok(KiloMeter(1.002)));
+
+        let difference = DynQuantity::from(&Foot(1.0_f64)) - DynQuantity::from(&Meter(1.0));
+        assert_quantity_eq!(
+            Meter::try_from(difference.unwrap()).unwrap(),
+            Meter(0.3048 - 1.0)
+        );
+    }
+
+    #[test]
+    fn add_mismatched_dimensions() {
+        let sum = DynQuantity::from(&Meter(2.0_f64)) + DynQuantity::from(&Second(1.0));
+        assert_eq!(
+            sum,
+            Err(DimensionError {
+                expected: DynDimension::of::<dimension::Length>(),
+                found: DynDimension::of::<dimension::Time>(),
+            })
+        );
+    }
+
+    #[test]
+    fn multiply_and_divide() {
+        let length = DynQuantity::from(&Meter(6.0_f64));
+        let time = DynQuantity::from(&Second(2.0_f64));
+
+        let speed = (length / time).unwrap();
+        assert!(speed.is::<dimension::Velocity>());
+        assert_eq!(MeterPerSecond::try_from(speed), Ok(MeterPerSecond(3.0)));
+
+        let area = (length * length).unwrap();
+        assert_eq!(area.exponents(), dimension::Area::EXPONENTS);
+        assert_eq!(area.value(), 36.0);
+    }
+
+    #[test]
+    fn integer_powers() {
+        let length = DynQuantity::from(&Meter(2.0_f64));
+        assert_eq!(
+            length.powi(3),
+            Ok(DynQuantity::new(8.0, [3, 0, 0, 0, 0, 0, 0]))
+        );
+        assert_eq!(
+            length.powi(-2),
+            Ok(DynQuantity::new(0.25, [-2, 0, 0, 0, 0, 0, 0]))
+        );
+        assert_eq!(length.powi(0), Ok(DynQuantity::dimensionless(1.0)));
+    }
+
+    #[test]
+    fn overflowing_exponents() {
+        let area = DynQuantity::from(&SquareMeter(1.0_f64));
+        assert_eq!(area.powi(i32::MAX), Err(ExponentOverflow));
+        assert_eq!(area.powi(i32::MIN), Err(ExponentOverflow));
+
+        let length = DynQuantity::new(1.0, [i32::MAX, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(length * length, Err(ExponentOverflow));
+        assert_eq!(length / length, Ok(DynQuantity::dimensionless(1.0)));
+        let per_length = DynQuantity::new(1.0, [i32::MIN, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(length / per_length, Err(ExponentOverflow));
+    }
+
+    #[test]
+    fn convert_to_units() {
+        let force: Derived<dimension::Force> = Derived::from_base(3.0);
+        let quantity = DynQuantity::from(&force);
+        assert_eq!(Derived::<dimension::Force>::try_from(quantity), Ok(force));
+
+        let temperature = DynQuantity::from(&Celsius(25.0_f64));
+        assert_eq!(temperature.to_unit(), Ok(Kelvin(298.15)));
+        assert!(Meter::<f64>::try_from(temperature).is_err());
+
+        let flux = DynQuantity::from(&Lumen(800.0_f64));
+        assert_eq!(flux.to_unit(), Ok(Lumen(800.0)));
+        assert!(Candela::<f64>::try_from(flux).is_err());
+    }
+
+    #[test]
+    fn reject_mismatched_kinds() {
+        let gauge = DynQuantity::from(&BarGauge(1.0_f64));
+        assert_eq!(
+            Pascal::try_from(gauge),
+            Err(DimensionError {
+                expected: DynDimension::of::<dimension::Pressure>(),
+                found: DynDimension::of::<dimension::Affine<dimension::PressureDifference>>(),
+            })
+        );
+        assert_eq!(gauge.to_unit(), Ok(PascalGauge(100_000.0)));
+
+        let temperature = DynQuantity::from(&Celsius(25.0_f64));
+        assert!(DeltaCelsius::<f64>::try_from(temperature).is_err());
+        let ratio = DynQuantity::dimensionless(0.5_f64);
+        assert!(Radian::<f64>::try_from(ratio).is_err());
+        assert_eq!(
+            Radian::try_from(DynQuantity::from(&Radian(0.5_f64))),
+            Ok(Radian(0.5))
+        );
+    }
+
+    #[test]
+    fn add_points_and_intervals() {
+        let morning = DynQuantity::from(&Celsius(10.0_f64));
+        let afternoon = DynQuantity::from(&Celsius(15.0_f64));
+        let warming = DynQuantity::from(&DeltaCelsius(5.0_f64));
+
+        assert!((morning + afternoon).is_err());
+        assert!((warming - morning).is_err());
+        assert_quantity_eq!(
+            DeltaCelsius::try_from((afternoon - morning).unwrap()).unwrap(),
+            DeltaCelsius(5.0)
+        );
+        assert_quantity_eq!(
+            Celsius::try_from((morning + warming).unwrap()).unwrap(),
+            Celsius(15.0)
+        );
+        assert_quantity_eq!(
+            Celsius::try_from((warming + morning).unwrap()).unwrap(),
+            Celsius(15.0)
+        );
+
+        let pressure = DynQuantity::from(&Pascal(3.0_f64));
+        let drop = DynQuantity::from(&PascalDifferential(1.0_f64));
+        assert_eq!(
+            Pascal::try_from((pressure - drop).unwrap()),
+            Ok(Pascal(2.0))
+        );
+        assert_eq!(
+            Pascal::try_from((drop + pressure).unwrap()),
+            Ok(Pascal(4.0))
+        );
+        assert!((drop - pressure).is_err());
+        assert!((pressure + DynQuantity::from(&Meter(1.0_f64))).is_err());
+
+        let total = (pressure + DynQuantity::from(&Pascal(2.0_f64))).unwrap();
+        assert_eq!(Pascal::try_from(total), Ok(Pascal(5.0)));
+        let difference = (total - pressure).unwrap();
+        assert_eq!(
+            PascalDifferential::try_from(difference),
+            Ok(PascalDifferential(2.0))
+        );
+        assert!(Pascal::try_from(difference).is_err());
+    }
+
+    #[test]
+    fn multiply_angles() {
+        let angle = DynQuantity::from(&Radian(2.0_f64));
+        let time = DynQuantity::from(&Second(4.0_f64));
+        let frequency = (angle / time).unwrap();
+        assert!(frequency.is::<dimension::WithAngle<dimension::Frequency>>());
+        assert!((angle / angle).unwrap().is::<dimension::Dimensionless>());
+        assert_eq!((angle * angle).unwrap().dimension().angle, 2);
+
+        let temperature = DynQuantity::from(&Celsius(0.0_f64)) * DynQuantity::dimensionless(2.0);
+        assert!(temperature.unwrap().is::<dimension::Temperature>());
+    }
+
+    #[test]
+    fn parse_quantities() {
+        let force: DynQuantity = "9.81 kg*m/s^2".parse().unwrap();
+        assert_eq!(force, DynQuantity::new(9.81, dimension::Force::EXPONENTS));
+
+        let temperature: DynQuantity = "25 °C".parse().unwrap();
+        assert_eq!(temperature.to_unit(), Ok(Kelvin(298.15)));
+
+        let ratio: DynQuantity = "0.5".parse().unwrap();
+        assert_eq!(ratio, DynQuantity::dimensionless(0.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display() {
+        let force = DynQuantity::new(2.5, dimension::Force::EXPONENTS);
+        assert_eq!(force.to_string(), "2.5 m·kg·s^-2");
+
+        let error = DimensionError {
+            expected: DynDimension::of::<dimension::Length>(),
+            found: DynDimension::of::<dimension::Dimensionless>(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Mismatched dimensions: expected m, found 1"
+        );
+
+        let error = DimensionError {
+            expected: DynDimension::of::<dimension::Pressure>(),
+            found: DynDimension::of::<dimension::Affine<dimension::PressureDifference>>(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Mismatched dimensions: expected m^-1·kg·s^-2, found m^-1·kg·s^-2 (point) (difference)"
+        );
+    }
+}
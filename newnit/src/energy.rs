@@ -0,0 +1,141 @@
+//! Units of energy.
+//!
+//! This module contains predefined newtypes for units of energy as defined in
+//! the following systems:
+//! - [`imperial`] - British Imperial units
+//! - [`metric`] - International System of Units (SI)
+//!
+//! The base used for all systems is the (metric) joule.
+
+use crate::charge::Charge;
+use crate::charge::metric::Coulomb;
+use crate::force::Force;
+use crate::force::metric::Newton;
+use crate::length::Length;
+use crate::length::metric::Meter;
+use crate::power::Power;
+use crate::power::metric::Watt;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::time::metric::Second;
+use crate::voltage::Voltage;
+use crate::voltage::metric::Volt;
+use crate::{Quantity, dimension, parse};
+
+pub mod imperial;
+pub mod metric;
+
+/// Types that are units of energy.
+///
+/// Provides various division methods, resulting in other units of quantity,
+/// based on the right hand side (`rhs`) in the division.
+pub trait Energy<V: Scalar = f64>: Quantity<V, Dimension = dimension::Energy> {
+    /// Divide a unit of energy by a unit of time.
+    fn divide_time(&self, rhs: &dyn Time<V>) -> Watt<V> {
+        Watt(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of energy by a unit of power.
+    fn divide_power(&self, rhs: &dyn Power<V>) -> Second<V> {
+        Second(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of energy by a unit of voltage.
+    fn divide_voltage(&self, rhs: &dyn Voltage<V>) -> Coulomb<V> {
+        Coulomb(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of energy by a unit of electric charge.
+    fn divide_charge(&self, rhs: &dyn Charge<V>) -> Volt<V> {
+        Volt(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of energy by a unit of length.
+    fn divide_length(&self, rhs: &dyn Length<V>) -> Newton<V> {
+        Newton(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of energy by a unit of force.
+    fn divide_force(&self, rhs: &dyn Force<V>) -> Meter<V> {
+        Meter(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Energy>> Energy<V> for T {}
+
+parse::units! {
+    /// Parse an energy in the unit named by its symbol (e.g. `"3.5 kWh"`).
+    parse, UNITS: Energy, EnergyUnit;
+    metric::{
+        QuettaJoule,
+        RonnaJoule,
+        YottaJoule,
+        ZettaJoule,
+        ExaJoule,
+        PetaJoule,
+        TeraJoule,
+        GigaJoule,
+        MegaJoule,
+        KiloJoule,
+        Joule,
+        DeciJoule,
+        CentiJoule,
+        MilliJoule,
+        MicroJoule,
+        NanoJoule,
+        PicoJoule,
+        FemtoJoule,
+        AttoJoule,
+        ZeptoJoule,
+        YoctoJoule,
+        RontoJoule,
+        QuectoJoule,
+        WattHour,
+        KiloWattHour,
+        MegaWattHour,
+        GigaWattHour,
+        Calorie,
+        KiloCalorie,
+        TeraElectronVolt,
+        GigaElectronVolt,
+        MegaElectronVolt,
+        KiloElectronVolt,
+        ElectronVolt,
+        Erg,
+    },
+    imperial::{
+        BritishThermalUnit,
+        Therm,
+        FootPound,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::length::metric::{KiloMeter, Meter};
+    use crate::time::metric::{Hour, Second};
+
+    #[test]
+    fn divide_by_time_power_length_and_force() {
+        let energy = metric::KiloWattHour(3.0_f64);
+        assert_quantity_eq!(energy.divide_time(&Hour(2.0)), Watt(1500.0));
+        assert_quantity_eq!(energy.divide_power(&Watt(1000.0)), Second(10_800.0));
+        assert_quantity_eq!(energy.divide_length(&KiloMeter(1.0)), Newton(10_800.0));
+        assert_quantity_eq!(energy.divide_force(&Newton(10_800.0)), Meter(1000.0));
+        assert_eq!(Hour(2.0).multiply_power(&Watt(1.5)).to_value(), 10_800.0);
+    }
+
+    #[test]
+    fn convert_between_systems() {
+        assert_quantity_eq!(
+            imperial::BritishThermalUnit(1.0_f64),
+            metric::KiloCalorie(0.252_164_400_6)
+        );
+        assert_quantity_eq!(
+            metric::PicoJoule(1.0_f64),
+            metric::MegaElectronVolt(6.241_509_074)
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! British Imperial units of energy
+//!
+//! This module contains predefined newtypes for units of energy used with the
+//! British Imperial system. The base unit is the joule.
+//!
+//! The British thermal unit (BTU) is the International Table BTU, and the
+//! therm is 100,000 BTU.
+
+use super::Energy;
+use crate::make_unit;
+use newnit_derive::{Energy, Unit};
+
+make_unit!(
+    BritishThermalUnit,
+    "BTU",
+    "British thermal unit",
+    "British thermal units",
+    Imperial,
+    1055.05585262,
+    Energy
+);
+make_unit!(
+    Therm,
+    "thm",
+    "therm",
+    "therms",
+    Imperial,
+    105_505_585.262,
+    Energy
+);
+make_unit!(
+    FootPound,
+    "ft·lbf",
+    "foot-pound",
+    "foot-pounds",
+    Imperial,
+    0.3048 * 0.453_592_37 * 9.80665,
+    Energy
+);
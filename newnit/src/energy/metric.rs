@@ -0,0 +1,308 @@
+//! Metric units of energy
+//!
+//! This module contains predefined newtypes for units of energy as defined in
+//! the International System of Units (SI). The base unit is the joule.
+//!
+//! Additional definitions provided for the watt-hour (Wh) = 3600 J and its
+//! multiples, the (thermochemical) calorie (cal) = 4.184 J, the electronvolt
+//! (eV) = 1.602176634E-19 J and its multiples, and the erg = 1E-7 J.
+
+use super::Energy;
+use crate::make_unit;
+use newnit_derive::{Energy, Unit};
+
+make_unit!(
+    QuettaJoule,
+    "QJ",
+    "quettajoule",
+    "quettajoules",
+    Metric,
+    1E+30,
+    Energy
+);
+make_unit!(
+    RonnaJoule,
+    "RJ",
+    "ronnajoule",
+    "ronnajoules",
+    Metric,
+    1E+27,
+    Energy
+);
+make_unit!(
+    YottaJoule,
+    "YJ",
+    "yottajoule",
+    "yottajoules",
+    Metric,
+    1E+24,
+    Energy
+);
+make_unit!(
+    ZettaJoule,
+    "ZJ",
+    "zettajoule",
+    "zettajoules",
+    Metric,
+    1E+21,
+    Energy
+);
+make_unit!(
+    ExaJoule,
+    "EJ",
+    "exajoule",
+    "exajoules",
+    Metric,
+    1E+18,
+    Energy
+);
+make_unit!(
+    PetaJoule,
+    "PJ",
+    "petajoule",
+    "petajoules",
+    Metric,
+    1E+15,
+    Energy
+);
+make_unit!(
+    TeraJoule,
+    "TJ",
+    "terajoule",
+    "terajoules",
+    Metric,
+    1E+12,
+    Energy
+);
+make_unit!(
+    GigaJoule,
+    "GJ",
+    "gigajoule",
+    "gigajoules",
+    Metric,
+    1E+9,
+    Energy
+);
+make_unit!(
+    MegaJoule,
+    "MJ",
+    "megajoule",
+    "megajoules",
+    Metric,
+    1E+6,
+    Energy
+);
+make_unit!(
+    KiloJoule,
+    "kJ",
+    "kilojoule",
+    "kilojoules",
+    Metric,
+    1E+3,
+    Energy
+);
+make_unit!(Joule, "J", "joule", "joules", Metric, 1.0, Energy);
+make_unit!(
+    DeciJoule,
+    "dJ",
+    "decijoule",
+    "decijoules",
+    Metric,
+    1E-1,
+    Energy
+);
+make_unit!(
+    CentiJoule,
+    "cJ",
+    "centijoule",
+    "centijoules",
+    Metric,
+    1E-2,
+    Energy
+);
+make_unit!(
+    MilliJoule,
+    "mJ",
+    "millijoule",
+    "millijoules",
+    Metric,
+    1E-3,
+    Energy
+);
+make_unit!(
+    MicroJoule,
+    "µJ",
+    "microjoule",
+    "microjoules",
+    Metric,
+    1E-6,
+    Energy
+);
+make_unit!(
+    NanoJoule,
+    "nJ",
+    "nanojoule",
+    "nanojoules",
+    Metric,
+    1E-9,
+    Energy
+);
+make_unit!(
+    PicoJoule,
+    "pJ",
+    "picojoule",
+    "picojoules",
+    Metric,
+    1E-12,
+    Energy
+);
+make_unit!(
+    FemtoJoule,
+    "fJ",
+    "femtojoule",
+    "femtojoules",
+    Metric,
+    1E-15,
+    Energy
+);
+make_unit!(
+    AttoJoule,
+    "aJ",
+    "attojoule",
+    "attojoules",
+    Metric,
+    1E-18,
+    Energy
+);
+make_unit!(
+    ZeptoJoule,
+    "zJ",
+    "zeptojoule",
+    "zeptojoules",
+    Metric,
+    1E-21,
+    Energy
+);
+make_unit!(
+    YoctoJoule,
+    "yJ",
+    "yoctojoule",
+    "yoctojoules",
+    Metric,
+    1E-24,
+    Energy
+);
+make_unit!(
+    RontoJoule,
+    "rJ",
+    "rontojoule",
+    "rontojoules",
+    Metric,
+    1E-27,
+    Energy
+);
+make_unit!(
+    QuectoJoule,
+    "qJ",
+    "quectojoule",
+    "quectojoules",
+    Metric,
+    1E-30,
+    Energy
+);
+
+make_unit!(
+    WattHour,
+    "Wh",
+    "watt-hour",
+    "watt-hours",
+    Metric,
+    3600.0,
+    Energy
+);
+make_unit!(
+    KiloWattHour,
+    "kWh",
+    "kilowatt-hour",
+    "kilowatt-hours",
+    Metric,
+    3.6E+6,
+    Energy
+);
+make_unit!(
+    MegaWattHour,
+    "MWh",
+    "megawatt-hour",
+    "megawatt-hours",
+    Metric,
+    3.6E+9,
+    Energy
+);
+make_unit!(
+    GigaWattHour,
+    "GWh",
+    "gigawatt-hour",
+    "gigawatt-hours",
+    Metric,
+    3.6E+12,
+    Energy
+);
+
+make_unit!(Calorie, "cal", "calorie", "calories", Metric, 4.184, Energy);
+make_unit!(
+    KiloCalorie,
+    "kcal",
+    "kilocalorie",
+    "kilocalories",
+    Metric,
+    4184.0,
+    Energy
+);
+
+make_unit!(
+    TeraElectronVolt,
+    "TeV",
+    "teraelectronvolt",
+    "teraelectronvolts",
+    Metric,
+    1.602176634E-7,
+    Energy
+);
+make_unit!(
+    GigaElectronVolt,
+    "GeV",
+    "gigaelectronvolt",
+    "gigaelectronvolts",
+    Metric,
+    1.602176634E-10,
+    Energy
+);
+make_unit!(
+    MegaElectronVolt,
+    "MeV",
+    "megaelectronvolt",
+    "megaelectronvolts",
+    Metric,
+    1.602176634E-13,
+    Energy
+);
+make_unit!(
+    KiloElectronVolt,
+    "keV",
+    "kiloelectronvolt",
+    "kiloelectronvolts",
+    Metric,
+    1.602176634E-16,
+    Energy
+);
+make_unit!(
+    ElectronVolt,
+    "eV",
+    "electronvolt",
+    "electronvolts",
+    Metric,
+    1.602176634E-19,
+    Energy
+);
+
+make_unit!(Erg, "erg", "erg", "ergs", Metric, 1E-7, Energy);
@@ -0,0 +1,147 @@
+//! Units of force.
+//!
+//! This module contains predefined newtypes for units of force as defined in
+//! the following systems:
+//! - [`imperial`] - British Imperial units
+//! - [`metric`] - International System of Units (SI)
+//!
+//! The base used for all systems is the (metric) newton.
+
+use crate::area::Area;
+use crate::area::metric::SquareMeter;
+use crate::energy::metric::Joule;
+use crate::length::Length;
+use crate::mass::Mass;
+use crate::power::metric::Watt;
+use crate::pressure::Pressure;
+use crate::pressure::metric::Pascal;
+use crate::quantity::Derived;
+use crate::scalar::Scalar;
+use crate::velocity::Velocity;
+use crate::{Quantity, dimension, parse};
+
+pub mod imperial;
+pub mod metric;
+
+/// Types that are units of force.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+///
+/// # Examples
+/// ```
+/// use newnit::Unit;
+/// use newnit::force::Force;
+/// use newnit::force::imperial::PoundForce;
+/// use newnit::length::imperial::Foot;
+///
+/// let work = PoundForce(10.0_f64).multiply_length(&Foot(3.0));
+/// assert!((work.to_value() - 40.674_538_449_942).abs() < 1e-9);
+/// ```
+pub trait Force<V: Scalar = f64>: Quantity<V, Dimension = dimension::Force> {
+    /// Multiply a unit of force with a unit of length.
+    fn multiply_length(&self, rhs: &dyn Length<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of force with a unit of velocity.
+    fn multiply_velocity(&self, rhs: &dyn Velocity<V>) -> Watt<V> {
+        Watt(self.to_base() * rhs.to_base())
+    }
+
+    /// Divide a unit of force by a unit of area.
+    ///
+    /// The result is an absolute pressure, which can't be negative (see
+    /// [`Quantity::minimum`]). Dividing with `/` results in a stress of the
+    /// same dimension instead, which can (e.g. of a compressive force).
+    fn divide_area(&self, rhs: &dyn Area<V>) -> Pascal<V> {
+        Pascal(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of force by a unit of pressure.
+    fn divide_pressure(&self, rhs: &dyn Pressure<V>) -> SquareMeter<V> {
+        SquareMeter(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of force by a unit of mass.
+    fn divide_mass(&self, rhs: &dyn Mass<V>) -> Derived<dimension::Acceleration, V> {
+        Derived::new(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Force>> Force<V> for T {}
+
+parse::units! {
+    /// Parse a force in the unit named by its symbol (e.g. `"12 kN"`).
+    parse, UNITS: Force, ForceUnit;
+    metric::{
+        QuettaNewton,
+        RonnaNewton,
+        YottaNewton,
+        ZettaNewton,
+        ExaNewton,
+        PetaNewton,
+        TeraNewton,
+        GigaNewton,
+        MegaNewton,
+        KiloNewton,
+        Newton,
+        DeciNewton,
+        CentiNewton,
+        MilliNewton,
+        MicroNewton,
+        NanoNewton,
+        PicoNewton,
+        FemtoNewton,
+        AttoNewton,
+        ZeptoNewton,
+        YoctoNewton,
+        RontoNewton,
+        QuectoNewton,
+        Dyne,
+        KiloGramForce,
+    },
+    imperial::{
+        PoundForce,
+        Poundal,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::length::metric::{CentiMeter, Meter};
+    use crate::mass::imperial::Pound;
+    use crate::mass::metric::KiloGram;
+    use crate::velocity::metric::KiloMeterPerHour;
+
+    #[test]
+    fn multiply_and_divide() {
+        let force = metric::KiloNewton(2.0_f64);
+        assert_eq!(force.multiply_length(&CentiMeter(50.0)).to_value(), 1000.0);
+        assert_quantity_eq!(
+            force.multiply_velocity(&KiloMeterPerHour(36.0)),
+            Watt(20_000.0)
+        );
+
+        let acceleration = imperial::PoundForce(1.0_f64).divide_mass(&Pound(1.0));
+        assert_quantity_eq!(
+            acceleration,
+            metric::Newton(9.80665).divide_mass(&KiloGram(1.0))
+        );
+        let weight = Pound(1.0).multiply_acceleration(&acceleration);
+        assert_quantity_eq!(weight, imperial::PoundForce(1.0));
+        assert_eq!(
+            Meter(1.0).multiply_force(&force),
+            metric::KiloNewton(2.0).multiply_length(&Meter(1.0))
+        );
+    }
+
+    #[test]
+    fn convert_between_systems() {
+        assert_quantity_eq!(metric::KiloGramForce(1.0_f64), metric::Newton(9.80665));
+        assert_quantity_eq!(imperial::Poundal(1.0_f64), metric::Dyne(13_825.495_437_6));
+    }
+}
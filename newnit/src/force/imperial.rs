@@ -0,0 +1,28 @@
+//! British Imperial units of force
+//!
+//! This module contains predefined newtypes for units of force based on units
+//! of mass and length defined in the British Imperial system. The base unit is
+//! the newton.
+
+use super::Force;
+use crate::make_unit;
+use newnit_derive::{Force, Unit};
+
+make_unit!(
+    PoundForce,
+    "lbf",
+    "pound-force",
+    "pounds-force",
+    Imperial,
+    0.453_592_37 * 9.80665,
+    Force
+);
+make_unit!(
+    Poundal,
+    "pdl",
+    "poundal",
+    "poundals",
+    Imperial,
+    0.453_592_37 * 0.3048,
+    Force
+);
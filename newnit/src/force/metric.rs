@@ -0,0 +1,222 @@
+//! Metric units of force
+//!
+//! This module contains predefined newtypes for units of force as defined in
+//! the International System of Units (SI). The base unit is the newton.
+//!
+//! Additional definitions provided for the dyne (dyn) = 1E-5 N and the
+//! kilogram-force (kgf) = 9.80665 N.
+
+use super::Force;
+use crate::make_unit;
+use newnit_derive::{Force, Unit};
+
+make_unit!(
+    QuettaNewton,
+    "QN",
+    "quettanewton",
+    "quettanewtons",
+    Metric,
+    1E+30,
+    Force
+);
+make_unit!(
+    RonnaNewton,
+    "RN",
+    "ronnanewton",
+    "ronnanewtons",
+    Metric,
+    1E+27,
+    Force
+);
+make_unit!(
+    YottaNewton,
+    "YN",
+    "yottanewton",
+    "yottanewtons",
+    Metric,
+    1E+24,
+    Force
+);
+make_unit!(
+    ZettaNewton,
+    "ZN",
+    "zettanewton",
+    "zettanewtons",
+    Metric,
+    1E+21,
+    Force
+);
+make_unit!(
+    ExaNewton,
+    "EN",
+    "exanewton",
+    "exanewtons",
+    Metric,
+    1E+18,
+    Force
+);
+make_unit!(
+    PetaNewton,
+    "PN",
+    "petanewton",
+    "petanewtons",
+    Metric,
+    1E+15,
+    Force
+);
+make_unit!(
+    TeraNewton,
+    "TN",
+    "teranewton",
+    "teranewtons",
+    Metric,
+    1E+12,
+    Force
+);
+make_unit!(
+    GigaNewton,
+    "GN",
+    "giganewton",
+    "giganewtons",
+    Metric,
+    1E+9,
+    Force
+);
+make_unit!(
+    MegaNewton,
+    "MN",
+    "meganewton",
+    "meganewtons",
+    Metric,
+    1E+6,
+    Force
+);
+make_unit!(
+    KiloNewton,
+    "kN",
+    "kilonewton",
+    "kilonewtons",
+    Metric,
+    1E+3,
+    Force
+);
+make_unit!(Newton, "N", "newton", "newtons", Metric, 1.0, Force);
+make_unit!(
+    DeciNewton,
+    "dN",
+    "decinewton",
+    "decinewtons",
+    Metric,
+    1E-1,
+    Force
+);
+make_unit!(
+    CentiNewton,
+    "cN",
+    "centinewton",
+    "centinewtons",
+    Metric,
+    1E-2,
+    Force
+);
+make_unit!(
+    MilliNewton,
+    "mN",
+    "millinewton",
+    "millinewtons",
+    Metric,
+    1E-3,
+    Force
+);
+make_unit!(
+    MicroNewton,
+    "µN",
+    "micronewton",
+    "micronewtons",
+    Metric,
+    1E-6,
+    Force
+);
+make_unit!(
+    NanoNewton,
+    "nN",
+    "nanonewton",
+    "nanonewtons",
+    Metric,
+    1E-9,
+    Force
+);
+make_unit!(
+    PicoNewton,
+    "pN",
+    "piconewton",
+    "piconewtons",
+    Metric,
+    1E-12,
+    Force
+);
+make_unit!(
+    FemtoNewton,
+    "fN",
+    "femtonewton",
+    "femtonewtons",
+    Metric,
+    1E-15,
+    Force
+);
+make_unit!(
+    AttoNewton,
+    "aN",
+    "attonewton",
+    "attonewtons",
+    Metric,
+    1E-18,
+    Force
+);
+make_unit!(
+    ZeptoNewton,
+    "zN",
+    "zeptonewton",
+    "zeptonewtons",
+    Metric,
+    1E-21,
+    Force
+);
+make_unit!(
+    YoctoNewton,
+    "yN",
+    "yoctonewton",
+    "yoctonewtons",
+    Metric,
+    1E-24,
+    Force
+);
+make_unit!(
+    RontoNewton,
+    "rN",
+    "rontonewton",
+    "rontonewtons",
+    Metric,
+    1E-27,
+    Force
+);
+make_unit!(
+    QuectoNewton,
+    "qN",
+    "quectonewton",
+    "quectonewtons",
+    Metric,
+    1E-30,
+    Force
+);
+
+make_unit!(Dyne, "dyn", "dyne", "dynes", Metric, 1E-5, Force);
+make_unit!(
+    KiloGramForce,
+    "kgf",
+    "kilogram-force",
+    "kilograms-force",
+    Metric,
+    9.80665,
+    Force
+);
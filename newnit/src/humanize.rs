@@ -0,0 +1,448 @@
+//! Formatting of values in the best scaled unit.
+//!
+//! Any unit can be [humanized](Humanize::humanize) for display: its value is
+//! expressed in the predefined unit of the same quantity (e.g. the metric
+//! multiples of the meter for a length) in which it reads best, i.e. the
+//! largest unit in which the value is at least one.
+//!
+//! By default, the units are chosen from the system of measurement of the
+//! humanized unit (metric for units of no particular system), leaving out the
+//! units with the deci, centi, deca and hecto prefixes and the multiples of
+//! the second, which read best as minutes, hours, days or weeks. [`Options`]
+//! restrict the units to another system, or to engineering multiples
+//! (10³ⁿ) of the base unit.
+//!
+//! Zero and non-finite values are displayed in the base unit.
+//!
+//! # Examples
+//! ```
+//! use newnit::humanize::{Humanize, Options};
+//! use newnit::length::metric::Meter;
+//! use newnit::time::metric::Second;
+//! use newnit::unit::System;
+//!
+//! assert_eq!(Meter(0.000_042).humanize().to_string(), "42 µm");
+//! assert_eq!(Second(93_784.0).humanize().to_string(), "1.085 d");
+//! assert_eq!(format!("{:#}", Meter(1500.0).humanize()), "1.5 kilometres");
+//!
+//! let options = Options::new().engineering();
+//! assert_eq!(
+//!     Second(93_784.0).humanize_with(options).to_string(),
+//!     "93.78 ks"
+//! );
+//!
+//! let options = Options::new().system(System::Imperial);
+//! assert_eq!(Meter(0.5).humanize_with(options).to_string(), "1.64 ft");
+//! ```
+
+use core::fmt;
+
+use crate::Quantity;
+use crate::dimension::{BaseUnits, Dimension};
+use crate::parse::UnitInfo;
+use crate::rational::Rational;
+use crate::registry::{self, Entry};
+use crate::scalar::Scalar;
+use crate::unit::{System, affine};
+
+/// Options of the choice of the unit of a humanized value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Options {
+    system: Option<System>,
+    engineering: bool,
+    digits: usize,
+}
+
+impl Options {
+    /// Units of the system of the humanized unit, displayed with four
+    /// significant digits.
+    pub const fn new() -> Self {
+        Self {
+            system: None,
+            engineering: false,
+            digits: 4,
+        }
+    }
+
+    /// Restrict the units to the given system of measurement.
+    pub const fn system(self, system: System) -> Self {
+        Self {
+            system: Some(system),
+            ..self
+        }
+    }
+
+    /// Restrict the units to engineering multiples (10³ⁿ) of the base unit.
+    pub const fn engineering(self) -> Self {
+        Self {
+            engineering: true,
+            ..self
+        }
+    }
+
+    /// Display the value with the given number of significant digits, unless
+    /// the precision is given in the format string (e.g. `{:.2}`).
+    pub const fn significant_digits(self, digits: usize) -> Self {
+        Self { digits, ..self }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Units that can be humanized for display.
+///
+/// Implemented for all quantities, including trait objects of quantity traits
+/// (e.g. `dyn Length`).
+pub trait Humanize<V: Scalar = f64> {
+    /// The value in the best scaled unit with the default [`Options`].
+    fn humanize(&self) -> Humanized {
+        self.humanize_with(Options::new())
+    }
+
+    /// The value in the best scaled unit allowed by the `options`.
+    fn humanize_with(&self, options: Options) -> Humanized;
+}
+
+impl<V: Scalar, U: Quantity<V> + ?Sized> Humanize<V> for U {
+    fn humanize_with(&self, options: Options) -> Humanized {
+        let base_units = BaseUnits::of::<U::Dimension>();
+
+        // Points on an affine scale (e.g. temperatures) are kept in their unit.
+        if <U::Dimension as Dimension>::AFFINE {
+            return Humanized {
+                value: self.to_value().to_f64(),
+                unit: Label::Unit {
+                    symbol: self.symbol(),
+                    name: self.name(),
+                    plural: self.plural(),
+                },
+                digits: options.digits,
+            };
+        }
+
+        let system = match options.system.unwrap_or(self.system()) {
+            System::Other => System::Metric,
+            system => system,
+        };
+        // The value is displayed as a float, so that integers aren't rounded
+        // to whole numbers of the chosen unit.
+        let base = self.to_base().to_f64();
+        let magnitude = if base < 0.0 { -base } else { base };
+
+        let candidates = || {
+            registry::entries().map(Entry::info).filter(|unit| {
+                BaseUnits(unit.exponents, unit.angle, unit.solid_angle) == base_units
+                    && !unit.affine
+                    && unit.difference == <U::Dimension as Dimension>::DIFFERENCE
+                    && unit.system == system
+                    && match options.engineering {
+                        true => is_engineering(unit.factor),
+                        false => reads_well(unit),
+                    }
+            })
+        };
+
+        let best = match magnitude == 0.0 || !magnitude.is_finite() {
+            true => candidates().find(|unit| unit.factor == Rational::ONE),
+            false => best_unit(candidates(), magnitude).map(|unit| {
+                // The value rounded for display may reach a larger unit (e.g.
+                // 999.96 m displayed as 1 km).
+                let factor = unit.factor.to_f64();
+                let rounded = round(magnitude / factor, options.digits) * factor;
+                best_unit(candidates(), rounded).unwrap_or(unit)
+            }),
+        };
+
+        match best {
+            Some(unit) => Humanized {
+                value: affine(base, unit.factor.reciprocal(), Rational::ZERO),
+                unit: Label::Unit {
+                    symbol: unit.symbol,
+                    name: unit.name,
+                    plural: unit.plural,
+                },
+                digits: options.digits,
+            },
+            None => Humanized {
+                value: base,
+                unit: Label::Base(base_units),
+                digits: options.digits,
+            },
+        }
+    }
+}
+
+/// A value in the unit chosen by [`Humanize`], for display.
+///
+/// Displays the value with the symbol of the unit (e.g. `42 µm`), or with its
+/// name in the alternate form (e.g. `42 micrometres`). Quantities without
+/// predefined units are displayed in base units (e.g. `3 m·kg·s^-2`).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Humanized {
+    value: f64,
+    unit: Label,
+    digits: usize,
+}
+
+/// The unit of a humanized value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Label {
+    Unit {
+        symbol: &'static str,
+        name: &'static str,
+        plural: &'static str,
+    },
+    Base(BaseUnits),
+}
+
+impl Humanized {
+    /// The value in the chosen unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The symbol of the chosen unit, if it is a predefined unit.
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self.unit {
+            Label::Unit { symbol, .. } => Some(symbol),
+            Label::Base(..) => None,
+        }
+    }
+}
+
+impl fmt::Display for Humanized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.value;
+        let decimals = match f.precision() {
+            Some(precision) => {
+                fmt::Display::fmt(&self.value, f)?;
+                precision
+            }
+            None => {
+                let decimals = decimals(value, self.digits);
+                write!(f, "{value:.decimals$}")?;
+                decimals
+            }
+        };
+
+        // The name is singular if the displayed value is one.
+        let one = (value - 1.0).abs() < 0.5 / power_of_ten(decimals.min(17) as u32);
+        match self.unit {
+            Label::Unit { symbol, .. } if !f.alternate() => write!(f, " {symbol}"),
+            Label::Unit { name, .. } if one => write!(f, " {name}"),
+            Label::Unit { plural, .. } => write!(f, " {plural}"),
+            Label::Base(units) if units.is_one() => Ok(()),
+            Label::Base(units) => write!(f, " {units}"),
+        }
+    }
+}
+
+/// The largest of the `candidates` in which the `magnitude` is at least one,
+/// or the smallest one if there is none.
+fn best_unit<'a>(
+    candidates: impl Iterator<Item = &'a UnitInfo>,
+    magnitude: f64,
+) -> Option<&'a UnitInfo> {
+    let mut best: Option<&UnitInfo> = None;
+    for unit in candidates {
+        let factor = unit.factor.to_f64();
+        best = match best {
+            None => Some(unit),
+            Some(best) => {
+                let best_factor = best.factor.to_f64();
+                let better = match (factor <= magnitude, best_factor <= magnitude) {
+                    (true, true) => factor > best_factor,
+                    (true, false) => true,
+                    (false, true) => false,
+                    (false, false) => factor < best_factor,
+                };
+                Some(if better { unit } else { best })
+            }
+        };
+    }
+
+    best
+}
+
+/// Whether the factor is an engineering multiple (10³ⁿ) of the base unit.
+fn is_engineering(factor: Rational) -> bool {
+    factor.numer() == 1 && factor.denom() == 1 && factor.exponent() % 3 == 0
+}
+
+/// Whether the unit reads well by default, unlike the units with the deci,
+/// centi, deca and hecto prefixes (e.g. `dm`, but not `dm³`) and the
+/// multiples of the second (e.g. `Ms`).
+fn reads_well(unit: &UnitInfo) -> bool {
+    let prefixed = ["deci", "centi", "deca", "hecto"]
+        .iter()
+        .any(|prefix| unit.name.starts_with(prefix));
+    let time = unit.exponents == [0, 0, 1, 0, 0, 0, 0];
+    let second_multiple = time && is_engineering(unit.factor) && unit.factor.exponent() > 0;
+
+    !prefixed && !second_multiple
+}
+
+/// The digits before the decimal point of the positive finite `magnitude`, or
+/// minus the zeros after it, with the magnitude scaled into [0.1, 1).
+fn integer_digits(mut magnitude: f64) -> (i32, f64) {
+    let mut integer_digits: i32 = 0;
+    while magnitude >= 1.0 {
+        magnitude /= 10.0;
+        integer_digits += 1;
+    }
+    while magnitude < 0.1 {
+        magnitude *= 10.0;
+        integer_digits -= 1;
+    }
+
+    (integer_digits, magnitude)
+}
+
+/// 10 to the power of `exponent`.
+fn power_of_ten(exponent: u32) -> f64 {
+    (0..exponent).fold(1.0, |power, _| power * 10.0)
+}
+
+/// The positive `magnitude` rounded to `digits` significant digits.
+fn round(magnitude: f64, digits: usize) -> f64 {
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return magnitude;
+    }
+
+    let (integer_digits, _) = integer_digits(magnitude);
+    let decimals = digits.min(17) as i32 - integer_digits;
+    let scale = power_of_ten(decimals.unsigned_abs());
+    match decimals >= 0 {
+        true => ((magnitude * scale + 0.5) as u64) as f64 / scale,
+        false => ((magnitude / scale + 0.5) as u64) as f64 * scale,
+    }
+}
+
+/// Number of decimals showing `value` with `digits` significant digits,
+/// without trailing zeros.
+fn decimals(value: f64, digits: usize) -> usize {
+    let magnitude = if value < 0.0 { -value } else { value };
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return 0;
+    }
+
+    // Count the digits before the decimal point, or the zeros after it.
+    let (integer_digits, magnitude) = integer_digits(magnitude);
+
+    let digits = digits.min(17) as i32;
+    let mut decimals = (digits - integer_digits).max(0);
+
+    // `magnitude` is in [0.1, 1), so its digits fit the significant digits.
+    let mut significant = magnitude;
+    for _ in 0..digits {
+        significant *= 10.0;
+    }
+    let mut significant = (significant + 0.5) as u64;
+    while decimals > 0 && significant.is_multiple_of(10) {
+        significant /= 10;
+        decimals -= 1;
+    }
+
+    decimals as usize
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use crate::Unit;
+    use crate::dimension;
+    use crate::length::Length;
+    use crate::length::imperial::Mile;
+    use crate::length::metric::Meter;
+    use crate::mass::metric::KiloGram;
+    use crate::quantity::Derived;
+    use crate::temperature::Celsius;
+    use crate::time::metric::Second;
+
+    #[test]
+    fn choose_best_scaled_unit() {
+        assert_eq!(Meter(0.000_042).humanize().to_string(), "42 µm");
+        assert_eq!(Meter(-2500.0).humanize().to_string(), "-2.5 km");
+        assert_eq!(Second(93_784.0).humanize().to_string(), "1.085 d");
+        assert_eq!(KiloGram(0.25).humanize().to_string(), "250 g");
+        assert_eq!(Meter(1E-33).humanize().to_string(), "0.001 qm");
+        assert_eq!(Meter(999.96).humanize().to_string(), "1 km");
+        assert_eq!(Meter(0.009_999_9).humanize().to_string(), "10 mm");
+        assert_eq!(Meter(1500_i32).humanize().to_string(), "1.5 km");
+        assert_eq!(Meter(1499_i64).humanize().to_string(), "1.499 km");
+
+        let force: Derived<dimension::Force> = Derived::from_base(2500.0);
+        assert_eq!(force.humanize().to_string(), "2.5 kN");
+        let flux: Derived<dimension::LuminousFlux> = Derived::from_base(1500.0);
+        assert_eq!(flux.humanize().to_string(), "1.5 klm");
+        let angle: Derived<dimension::Angle> = Derived::from_base(0.002);
+        assert_eq!(angle.humanize().to_string(), "2 mrad");
+    }
+
+    #[test]
+    fn display_zero_and_non_finite_in_base_unit() {
+        assert_eq!(Meter(0.0).humanize().to_string(), "0 m");
+        assert_eq!(Meter(f64::NAN).humanize().to_string(), "NaN m");
+        assert_eq!(Second(f64::NEG_INFINITY).humanize().to_string(), "-inf s");
+        assert_eq!(KiloGram(0.0).humanize().to_string(), "0 kg");
+    }
+
+    #[test]
+    fn leave_out_unusual_units() {
+        assert_eq!(Second(0.5).humanize().to_string(), "500 ms");
+        assert_eq!(Meter(0.5).humanize().to_string(), "500 mm");
+        assert_eq!(Meter(0.05).humanize().to_string(), "50 mm");
+        assert_eq!(Second(400.0 * 86_400.0).humanize().to_string(), "57.14 wk");
+        assert_eq!(Second(2_000.0).humanize().to_string(), "33.33 min");
+    }
+
+    #[test]
+    fn restrict_units() {
+        let engineering = Options::new().engineering();
+        assert_eq!(Meter(0.05).humanize_with(engineering).to_string(), "50 mm");
+
+        let imperial = Options::new().system(System::Imperial);
+        assert_eq!(Meter(3.0).humanize_with(imperial).to_string(), "3.281 yd");
+        assert_eq!(Mile(0.01).humanize().to_string(), "17.6 yd");
+    }
+
+    #[test]
+    fn display_names_and_precision() {
+        let length: &dyn Length = &Meter(1000.0);
+        assert_eq!(format!("{:#}", length.humanize()), "1 kilometre");
+        assert_eq!(format!("{:.2}", Meter(0.5).humanize()), "500.00 mm");
+        assert_eq!(format!("{:#}", Meter(1000.4).humanize()), "1 kilometre");
+        assert_eq!(format!("{:#}", Meter(999.96).humanize()), "1 kilometre");
+        assert_eq!(
+            format!("{:#.2}", Meter(1004.0).humanize()),
+            "1.00 kilometre"
+        );
+        assert_eq!(
+            format!("{:#.3}", Meter(1004.0).humanize()),
+            "1.004 kilometres"
+        );
+
+        let options = Options::new().significant_digits(2);
+        assert_eq!(Second(93_784.0).humanize_with(options).to_string(), "1.1 d");
+    }
+
+    #[test]
+    fn keep_units_without_candidates() {
+        assert_eq!(Celsius(21.5).humanize().to_string(), "21.5 °C");
+
+        let acceleration: Derived<dimension::Acceleration> = Derived::from_base(3.0);
+        assert_eq!(acceleration.humanize().to_string(), "3 m·s^-2");
+        assert_eq!(acceleration.humanize().symbol(), None);
+        let exposure: Derived<dimension::Illuminance> = Derived::from_base(2.0);
+        let exposure = exposure * &Second(3.0);
+        assert_eq!(exposure.humanize().to_string(), "6 m^-2·s·cd·sr");
+        let angular_velocity: Derived<dimension::Angle> = Derived::from_base(4.0);
+        let angular_velocity = angular_velocity / &Second(2.0);
+        assert_eq!(angular_velocity.humanize().to_string(), "2 s^-1·rad");
+    }
+}
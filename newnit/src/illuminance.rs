@@ -0,0 +1,104 @@
+//! Units of illuminance.
+//!
+//! This module contains predefined newtypes for units of illuminance as
+//! defined in the following systems:
+//! - [`imperial`] - British Imperial units
+//! - [`metric`] - International System of Units (SI)
+//!
+//! The base used for all systems is the (metric) lux, one lumen per square
+//! metre.
+
+use crate::area::Area;
+use crate::luminous_flux::metric::Lumen;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
+
+pub mod imperial;
+pub mod metric;
+
+/// Types that are units of illuminance.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+///
+/// # Examples
+/// ```
+/// use newnit::area::metric::SquareMeter;
+/// use newnit::illuminance::Illuminance;
+/// use newnit::illuminance::metric::Lux;
+/// use newnit::luminous_flux::metric::Lumen;
+///
+/// let flux = Lux(500.0).multiply_area(&SquareMeter(2.0));
+/// assert_eq!(flux, Lumen(1_000.0));
+/// ```
+pub trait Illuminance<V: Scalar = f64>: Quantity<V, Dimension = dimension::Illuminance> {
+    /// Multiply a unit of illuminance with a unit of area.
+    fn multiply_area(&self, rhs: &dyn Area<V>) -> Lumen<V> {
+        Lumen(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Illuminance>> Illuminance<V> for T {}
+
+parse::units! {
+    /// Parse an illuminance in the unit named by its symbol (e.g. `"500
+    /// lx"`).
+    parse, UNITS: Illuminance, IlluminanceUnit;
+    metric::{
+        QuettaLux,
+        RonnaLux,
+        YottaLux,
+        ZettaLux,
+        ExaLux,
+        PetaLux,
+        TeraLux,
+        GigaLux,
+        MegaLux,
+        KiloLux,
+        Lux,
+        DeciLux,
+        CentiLux,
+        MilliLux,
+        MicroLux,
+        NanoLux,
+        PicoLux,
+        FemtoLux,
+        AttoLux,
+        ZeptoLux,
+        YoctoLux,
+        RontoLux,
+        QuectoLux,
+        Phot,
+    },
+    imperial::{
+        FootCandle,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::imperial::SquareFoot;
+    use crate::area::metric::SquareMeter;
+    use crate::{Unit, assert_quantity_eq};
+
+    #[test]
+    fn convert_between_systems() {
+        assert_quantity_eq!(
+            imperial::FootCandle(1.0_f64),
+            metric::Lux(10.763_910_416_709_722)
+        );
+        assert_quantity_eq!(metric::Phot(1.0_f64), metric::KiloLux(10.0));
+        assert_eq!(metric::MilliLux(2.0).plural(), "millilux");
+    }
+
+    #[test]
+    fn multiply() {
+        let flux = imperial::FootCandle(30.0_f64).multiply_area(&SquareFoot(10.0));
+        assert_quantity_eq!(flux, Lumen(300.0));
+        assert_eq!(
+            SquareMeter(2.0).multiply_illuminance(&metric::Lux(50.0)).0,
+            100.0
+        );
+    }
+}
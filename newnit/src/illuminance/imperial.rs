@@ -0,0 +1,19 @@
+//! British Imperial units of illuminance
+//!
+//! This module contains predefined newtypes for units of illuminance based on
+//! units of length defined in the British Imperial system. The base unit is
+//! the lux.
+
+use super::Illuminance;
+use crate::make_unit;
+use newnit_derive::{Illuminance, Unit};
+
+make_unit!(
+    FootCandle,
+    "fc",
+    "foot-candle",
+    "foot-candles",
+    Imperial,
+    1 / (0.3048 * 0.3048),
+    Illuminance
+);
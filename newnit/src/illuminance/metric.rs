@@ -0,0 +1,212 @@
+//! Metric units of illuminance
+//!
+//! This module contains predefined newtypes for units of illuminance as
+//! defined in the International System of Units (SI). The base unit is the lux.
+//!
+//! Additional definition provided for the phot (ph) = 1E+4 lx, the CGS unit of
+//! one lumen per square centimetre.
+
+use super::Illuminance;
+use crate::make_unit;
+use newnit_derive::{Illuminance, Unit};
+
+make_unit!(
+    QuettaLux,
+    "Qlx",
+    "quettalux",
+    "quettalux",
+    Metric,
+    1E+30,
+    Illuminance
+);
+make_unit!(
+    RonnaLux,
+    "Rlx",
+    "ronnalux",
+    "ronnalux",
+    Metric,
+    1E+27,
+    Illuminance
+);
+make_unit!(
+    YottaLux,
+    "Ylx",
+    "yottalux",
+    "yottalux",
+    Metric,
+    1E+24,
+    Illuminance
+);
+make_unit!(
+    ZettaLux,
+    "Zlx",
+    "zettalux",
+    "zettalux",
+    Metric,
+    1E+21,
+    Illuminance
+);
+make_unit!(
+    ExaLux,
+    "Elx",
+    "exalux",
+    "exalux",
+    Metric,
+    1E+18,
+    Illuminance
+);
+make_unit!(
+    PetaLux,
+    "Plx",
+    "petalux",
+    "petalux",
+    Metric,
+    1E+15,
+    Illuminance
+);
+make_unit!(
+    TeraLux,
+    "Tlx",
+    "teralux",
+    "teralux",
+    Metric,
+    1E+12,
+    Illuminance
+);
+make_unit!(
+    GigaLux,
+    "Glx",
+    "gigalux",
+    "gigalux",
+    Metric,
+    1E+9,
+    Illuminance
+);
+make_unit!(
+    MegaLux,
+    "Mlx",
+    "megalux",
+    "megalux",
+    Metric,
+    1E+6,
+    Illuminance
+);
+make_unit!(
+    KiloLux,
+    "klx",
+    "kilolux",
+    "kilolux",
+    Metric,
+    1E+3,
+    Illuminance
+);
+make_unit!(Lux, "lx", "lux", "lux", Metric, 1.0, Illuminance);
+make_unit!(
+    DeciLux,
+    "dlx",
+    "decilux",
+    "decilux",
+    Metric,
+    1E-1,
+    Illuminance
+);
+make_unit!(
+    CentiLux,
+    "clx",
+    "centilux",
+    "centilux",
+    Metric,
+    1E-2,
+    Illuminance
+);
+make_unit!(
+    MilliLux,
+    "mlx",
+    "millilux",
+    "millilux",
+    Metric,
+    1E-3,
+    Illuminance
+);
+make_unit!(
+    MicroLux,
+    "µlx",
+    "microlux",
+    "microlux",
+    Metric,
+    1E-6,
+    Illuminance
+);
+make_unit!(
+    NanoLux,
+    "nlx",
+    "nanolux",
+    "nanolux",
+    Metric,
+    1E-9,
+    Illuminance
+);
+make_unit!(
+    PicoLux,
+    "plx",
+    "picolux",
+    "picolux",
+    Metric,
+    1E-12,
+    Illuminance
+);
+make_unit!(
+    FemtoLux,
+    "flx",
+    "femtolux",
+    "femtolux",
+    Metric,
+    1E-15,
+    Illuminance
+);
+make_unit!(
+    AttoLux,
+    "alx",
+    "attolux",
+    "attolux",
+    Metric,
+    1E-18,
+    Illuminance
+);
+make_unit!(
+    ZeptoLux,
+    "zlx",
+    "zeptolux",
+    "zeptolux",
+    Metric,
+    1E-21,
+    Illuminance
+);
+make_unit!(
+    YoctoLux,
+    "ylx",
+    "yoctolux",
+    "yoctolux",
+    Metric,
+    1E-24,
+    Illuminance
+);
+make_unit!(
+    RontoLux,
+    "rlx",
+    "rontolux",
+    "rontolux",
+    Metric,
+    1E-27,
+    Illuminance
+);
+make_unit!(
+    QuectoLux,
+    "qlx",
+    "quectolux",
+    "quectolux",
+    Metric,
+    1E-30,
+    Illuminance
+);
+make_unit!(Phot, "ph", "phot", "phots", Metric, 1E+4, Illuminance);
@@ -0,0 +1,284 @@
+//! Units of inductance.
+//!
+//! This module contains predefined newtypes for units of inductance as defined
+//! in the International System of Units (SI). The base unit is the [`Henry`].
+//!
+//! [`Henry`]: metric::Henry
+
+use crate::current::Current;
+use crate::magnetic_flux::metric::Weber;
+use crate::resistance::Resistance;
+use crate::scalar::Scalar;
+use crate::time::metric::Second;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of inductance.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+pub trait Inductance<V: Scalar = f64>: Quantity<V, Dimension = dimension::Inductance> {
+    /// Multiply a unit of inductance with a unit of current.
+    fn multiply_current(&self, rhs: &dyn Current<V>) -> Weber<V> {
+        Weber(self.to_base() * rhs.to_base())
+    }
+
+    /// Divide a unit of inductance by a unit of resistance.
+    fn divide_resistance(&self, rhs: &dyn Resistance<V>) -> Second<V> {
+        Second(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Inductance>> Inductance<V> for T {}
+
+pub mod metric {
+    use super::Inductance;
+    use crate::make_unit;
+    use newnit_derive::{Inductance, Unit};
+
+    make_unit!(
+        QuettaHenry,
+        "QH",
+        "quettahenry",
+        "quettahenries",
+        Metric,
+        1E+30,
+        Inductance
+    );
+    make_unit!(
+        RonnaHenry,
+        "RH",
+        "ronnahenry",
+        "ronnahenries",
+        Metric,
+        1E+27,
+        Inductance
+    );
+    make_unit!(
+        YottaHenry,
+        "YH",
+        "yottahenry",
+        "yottahenries",
+        Metric,
+        1E+24,
+        Inductance
+    );
+    make_unit!(
+        ZettaHenry,
+        "ZH",
+        "zettahenry",
+        "zettahenries",
+        Metric,
+        1E+21,
+        Inductance
+    );
+    make_unit!(
+        ExaHenry,
+        "EH",
+        "exahenry",
+        "exahenries",
+        Metric,
+        1E+18,
+        Inductance
+    );
+    make_unit!(
+        PetaHenry,
+        "PH",
+        "petahenry",
+        "petahenries",
+        Metric,
+        1E+15,
+        Inductance
+    );
+    make_unit!(
+        TeraHenry,
+        "TH",
+        "terahenry",
+        "terahenries",
+        Metric,
+        1E+12,
+        Inductance
+    );
+    make_unit!(
+        GigaHenry,
+        "GH",
+        "gigahenry",
+        "gigahenries",
+        Metric,
+        1E+9,
+        Inductance
+    );
+    make_unit!(
+        MegaHenry,
+        "MH",
+        "megahenry",
+        "megahenries",
+        Metric,
+        1E+6,
+        Inductance
+    );
+    make_unit!(
+        KiloHenry,
+        "kH",
+        "kilohenry",
+        "kilohenries",
+        Metric,
+        1E+3,
+        Inductance
+    );
+    make_unit!(Henry, "H", "henry", "henries", Metric, 1.0, Inductance);
+    make_unit!(
+        DeciHenry,
+        "dH",
+        "decihenry",
+        "decihenries",
+        Metric,
+        1E-1,
+        Inductance
+    );
+    make_unit!(
+        CentiHenry,
+        "cH",
+        "centihenry",
+        "centihenries",
+        Metric,
+        1E-2,
+        Inductance
+    );
+    make_unit!(
+        MilliHenry,
+        "mH",
+        "millihenry",
+        "millihenries",
+        Metric,
+        1E-3,
+        Inductance
+    );
+    make_unit!(
+        MicroHenry,
+        "µH",
+        "microhenry",
+        "microhenries",
+        Metric,
+        1E-6,
+        Inductance
+    );
+    make_unit!(
+        NanoHenry,
+        "nH",
+        "nanohenry",
+        "nanohenries",
+        Metric,
+        1E-9,
+        Inductance
+    );
+    make_unit!(
+        PicoHenry,
+        "pH",
+        "picohenry",
+        "picohenries",
+        Metric,
+        1E-12,
+        Inductance
+    );
+    make_unit!(
+        FemtoHenry,
+        "fH",
+        "femtohenry",
+        "femtohenries",
+        Metric,
+        1E-15,
+        Inductance
+    );
+    make_unit!(
+        AttoHenry,
+        "aH",
+        "attohenry",
+        "attohenries",
+        Metric,
+        1E-18,
+        Inductance
+    );
+    make_unit!(
+        ZeptoHenry,
+        "zH",
+        "zeptohenry",
+        "zeptohenries",
+        Metric,
+        1E-21,
+        Inductance
+    );
+    make_unit!(
+        YoctoHenry,
+        "yH",
+        "yoctohenry",
+        "yoctohenries",
+        Metric,
+        1E-24,
+        Inductance
+    );
+    make_unit!(
+        RontoHenry,
+        "rH",
+        "rontohenry",
+        "rontohenries",
+        Metric,
+        1E-27,
+        Inductance
+    );
+    make_unit!(
+        QuectoHenry,
+        "qH",
+        "quectohenry",
+        "quectohenries",
+        Metric,
+        1E-30,
+        Inductance
+    );
+}
+
+parse::units! {
+    /// Parse an inductance in the unit named by its symbol (e.g. `"10 mH"`).
+    parse, UNITS: Inductance, InductanceUnit;
+    metric::{
+        QuettaHenry,
+        RonnaHenry,
+        YottaHenry,
+        ZettaHenry,
+        ExaHenry,
+        PetaHenry,
+        TeraHenry,
+        GigaHenry,
+        MegaHenry,
+        KiloHenry,
+        Henry,
+        DeciHenry,
+        CentiHenry,
+        MilliHenry,
+        MicroHenry,
+        NanoHenry,
+        PicoHenry,
+        FemtoHenry,
+        AttoHenry,
+        ZeptoHenry,
+        YoctoHenry,
+        RontoHenry,
+        QuectoHenry,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::current::metric::Ampere;
+    use crate::resistance::metric::Ohm;
+    use crate::{Unit, assert_quantity_eq};
+
+    #[test]
+    fn multiply_and_divide() {
+        let inductance = metric::MilliHenry(10.0_f64);
+        assert_quantity_eq!(inductance.multiply_current(&Ampere(2.0)), Weber(0.02));
+        assert_quantity_eq!(inductance.divide_resistance(&Ohm(5.0)), Second(0.002));
+        assert_eq!(metric::Henry(2.0).plural(), "henries");
+    }
+}
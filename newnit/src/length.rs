@@ -7,14 +7,17 @@
 //! - [`metric`] - International System of Units (SI)
 //! - [`nautical`] - International nautical units
 
-use crate::Unit;
 use crate::area::Area;
 use crate::area::metric::SquareMeter;
+use crate::energy::metric::Joule;
+use crate::force::Force;
+use crate::scalar::Scalar;
 use crate::time::Time;
 use crate::time::metric::Second;
 use crate::velocity::Velocity;
 use crate::velocity::metric::MeterPerSecond;
 use crate::volume::metric::CubicMeter;
+use crate::{Quantity, dimension, parse};
 
 pub mod astronomical;
 pub mod imperial;
@@ -41,31 +44,106 @@ pub mod nautical;
 /// let area = length1.multiply(&length2);
 /// assert!((area.to_value() - 2.4384).abs() < 1e-5);
 /// ```
-pub trait Length: Unit {
+pub trait Length<V: Scalar = f64>: Quantity<V, Dimension = dimension::Length> {
     /// Multiply two units of length.
-    fn multiply(&self, rhs: &dyn Length) -> SquareMeter {
+    fn multiply(&self, rhs: &dyn Length<V>) -> SquareMeter<V> {
         SquareMeter(self.to_base() * rhs.to_base())
     }
 
     /// Multiply a unit of length with a unit of area.
-    fn multiply_area(&self, rhs: &dyn Area) -> CubicMeter {
+    fn multiply_area(&self, rhs: &dyn Area<V>) -> CubicMeter<V> {
         CubicMeter(self.to_base() * rhs.to_base())
     }
 
     /// Divide a unit of length by a unit of time.
-    fn divide_time(&self, rhs: &dyn Time) -> MeterPerSecond {
+    fn divide_time(&self, rhs: &dyn Time<V>) -> MeterPerSecond<V> {
         MeterPerSecond(self.to_base() / rhs.to_base())
     }
 
     /// Divide a unit of length by a unit of velocity.
-    fn divide_velocity(&self, rhs: &dyn Velocity) -> Second {
+    fn divide_velocity(&self, rhs: &dyn Velocity<V>) -> Second<V> {
         Second(self.to_base() / rhs.to_base())
     }
+
+    /// Multiply a unit of length with a unit of force.
+    fn multiply_force(&self, rhs: &dyn Force<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Length>> Length<V> for T {}
+
+parse::units! {
+    /// Parse a length in the unit named by its symbol (e.g. `"3.5 km"`).
+    parse, UNITS: Length, LengthUnit;
+    metric::{
+        QuettaMeter,
+        RonnaMeter,
+        YottaMeter,
+        ZettaMeter,
+        ExaMeter,
+        PetaMeter,
+        TeraMeter,
+        GigaMeter,
+        MegaMeter,
+        KiloMeter,
+        Meter,
+        DeciMeter,
+        CentiMeter,
+        MilliMeter,
+        MicroMeter,
+        NanoMeter,
+        PicoMeter,
+        FemtoMeter,
+        AttoMeter,
+        ZeptoMeter,
+        YoctoMeter,
+        RontoMeter,
+        QuectoMeter,
+        Angstrom,
+    },
+    imperial::{
+        Inch,
+        Foot,
+        Yard,
+        Chain,
+        Furlong,
+        Mile,
+        League,
+    },
+    nautical::{
+        Fathom,
+        Cable,
+        Mile => NauticalMile,
+    },
+    astronomical::{
+        MegaParsec,
+        KiloParsec,
+        Parsec,
+        LightYear,
+        AstronomicalUnit,
+    },
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Unit;
+
+    #[test]
+    fn prefixes_convert_exactly() {
+        assert_eq!(metric::QuectoMeter(1.0).to_base(), 1E-30);
+        assert_eq!(metric::YoctoMeter(1.0).to_base(), 1E-24);
+        assert_eq!(metric::QuettaMeter(1.0).to_base(), 1E+30);
+        assert_eq!(
+            metric::QuectoMeter::from_base(1E-30),
+            metric::QuectoMeter(1.0)
+        );
+        assert_eq!(
+            metric::NanoMeter::from(&metric::KiloMeter(3.0)),
+            metric::NanoMeter(3E+12)
+        );
+    }
 
     #[test]
     fn multiply_with_length() {
@@ -76,6 +154,18 @@ mod test {
         assert!((area.to_value() - 1.8288).abs() < 1e-5);
     }
 
+    #[test]
+    fn convert_exactly() {
+        assert_eq!(
+            imperial::Foot::from(&imperial::Inch(12.0)),
+            imperial::Foot(1.0)
+        );
+        assert_eq!(
+            imperial::Mile::from(&imperial::Yard(1_760.0)),
+            imperial::Mile(1.0)
+        );
+    }
+
     #[test]
     fn multiply_with_area() {
         let length = metric::Meter(2.0);
@@ -6,31 +6,215 @@
 //! Additional definition provided for the Ångström (Å) = 1E-10 m.
 
 use super::Length;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Length, Unit};
 
-make_unit!(QuettaMeter, 1E+30, Length);
-make_unit!(RonnaMeter, 1E+27, Length);
-make_unit!(YottaMeter, 1E+24, Length);
-make_unit!(ZettaMeter, 1E+21, Length);
-make_unit!(ExaMeter, 1E+18, Length);
-make_unit!(PetaMeter, 1E+15, Length);
-make_unit!(TeraMeter, 1E+12, Length);
-make_unit!(GigaMeter, 1E+9, Length);
-make_unit!(MegaMeter, 1E+6, Length);
-make_unit!(KiloMeter, 1E+3, Length);
-make_unit!(Meter, 1.0, Length);
-make_unit!(DeciMeter, 1E-1, Length);
-make_unit!(CentiMeter, 1E-2, Length);
-make_unit!(MilliMeter, 1E-3, Length);
-make_unit!(MicroMeter, 1E-6, Length);
-make_unit!(NanoMeter, 1E-9, Length);
-make_unit!(PicoMeter, 1E-12, Length);
-make_unit!(FemtoMeter, 1E-15, Length);
-make_unit!(AttoMeter, 1E-18, Length);
-make_unit!(ZeptoMeter, 1E-21, Length);
-make_unit!(YoctoMeter, 1E-24, Length);
-make_unit!(RontoMeter, 1E-27, Length);
-make_unit!(QuectoMeter, 1E-30, Length);
+make_unit!(
+    QuettaMeter,
+    "Qm",
+    "quettametre",
+    "quettametres",
+    Metric,
+    1E+30,
+    Length
+);
+make_unit!(
+    RonnaMeter,
+    "Rm",
+    "ronnametre",
+    "ronnametres",
+    Metric,
+    1E+27,
+    Length
+);
+make_unit!(
+    YottaMeter,
+    "Ym",
+    "yottametre",
+    "yottametres",
+    Metric,
+    1E+24,
+    Length
+);
+make_unit!(
+    ZettaMeter,
+    "Zm",
+    "zettametre",
+    "zettametres",
+    Metric,
+    1E+21,
+    Length
+);
+make_unit!(
+    ExaMeter,
+    "Em",
+    "exametre",
+    "exametres",
+    Metric,
+    1E+18,
+    Length
+);
+make_unit!(
+    PetaMeter,
+    "Pm",
+    "petametre",
+    "petametres",
+    Metric,
+    1E+15,
+    Length
+);
+make_unit!(
+    TeraMeter,
+    "Tm",
+    "terametre",
+    "terametres",
+    Metric,
+    1E+12,
+    Length
+);
+make_unit!(
+    GigaMeter,
+    "Gm",
+    "gigametre",
+    "gigametres",
+    Metric,
+    1E+9,
+    Length
+);
+make_unit!(
+    MegaMeter,
+    "Mm",
+    "megametre",
+    "megametres",
+    Metric,
+    1E+6,
+    Length
+);
+make_unit!(
+    KiloMeter,
+    "km",
+    "kilometre",
+    "kilometres",
+    Metric,
+    1E+3,
+    Length
+);
+make_unit!(Meter, "m", "metre", "metres", Metric, 1.0, Length);
+make_unit!(
+    DeciMeter,
+    "dm",
+    "decimetre",
+    "decimetres",
+    Metric,
+    1E-1,
+    Length
+);
+make_unit!(
+    CentiMeter,
+    "cm",
+    "centimetre",
+    "centimetres",
+    Metric,
+    1E-2,
+    Length
+);
+make_unit!(
+    MilliMeter,
+    "mm",
+    "millimetre",
+    "millimetres",
+    Metric,
+    1E-3,
+    Length
+);
+make_unit!(
+    MicroMeter,
+    "µm",
+    "micrometre",
+    "micrometres",
+    Metric,
+    1E-6,
+    Length
+);
+make_unit!(
+    NanoMeter,
+    "nm",
+    "nanometre",
+    "nanometres",
+    Metric,
+    1E-9,
+    Length
+);
+make_unit!(
+    PicoMeter,
+    "pm",
+    "picometre",
+    "picometres",
+    Metric,
+    1E-12,
+    Length
+);
+make_unit!(
+    FemtoMeter,
+    "fm",
+    "femtometre",
+    "femtometres",
+    Metric,
+    1E-15,
+    Length
+);
+make_unit!(
+    AttoMeter,
+    "am",
+    "attometre",
+    "attometres",
+    Metric,
+    1E-18,
+    Length
+);
+make_unit!(
+    ZeptoMeter,
+    "zm",
+    "zeptometre",
+    "zeptometres",
+    Metric,
+    1E-21,
+    Length
+);
+make_unit!(
+    YoctoMeter,
+    "ym",
+    "yoctometre",
+    "yoctometres",
+    Metric,
+    1E-24,
+    Length
+);
+make_unit!(
+    RontoMeter,
+    "rm",
+    "rontometre",
+    "rontometres",
+    Metric,
+    1E-27,
+    Length
+);
+make_unit!(
+    QuectoMeter,
+    "qm",
+    "quectometre",
+    "quectometres",
+    Metric,
+    1E-30,
+    Length
+);
 
-make_unit!(Angstrom, 1E-10, Length);
+make_unit!(
+    Angstrom,
+    "Å",
+    "ångström",
+    "ångströms",
+    Metric,
+    1E-10,
+    Length
+);
@@ -4,9 +4,19 @@
 //! marine, air and space navigation. The base unit is the meter.
 
 use super::Length;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Length, Unit};
 
-make_unit!(Fathom, 1.828_8, Length);
-make_unit!(Cable, 219.456, Length);
-make_unit!(Mile, 1852.0, Length);
+make_unit!(
+    Fathom, "ftm", "fathom", "fathoms", Nautical, 1.828_8, Length
+);
+make_unit!(Cable, "cb", "cable", "cables", Nautical, 219.456, Length);
+make_unit!(
+    Mile,
+    "nmi",
+    "nautical mile",
+    "nautical miles",
+    Nautical,
+    1852.0,
+    Length
+);
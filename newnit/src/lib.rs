@@ -13,14 +13,19 @@
 //! addition, these quantity traits provide useful and valid mathematical
 //! operations between the units.
 //!
+//! Each unit has a [`dimension`] assigned through the [`Quantity`] trait. The
+//! quantity traits are implemented for all units of their dimension, and
+//! multiplying or dividing any two units yields a unit of the correct
+//! dimension, checked at compile time.
+//!
 //! For each provided quantity trait the library provides their common units
 //! (e.g. [`mass::metric::Gram`], [`mass::imperial::Pound`]) and, for the metric
 //! units, their multiples (e.g. [`length::metric::KiloMeter`],
 //! [`length::metric::MilliMeter`]).
 //!
 //! In case you didn't find a unit that you need, you can implement the [`Unit`]
-//! and the desired quantity trait yourself, or you can use the derive macros
-//! from the [`derive`] module (see examples below).
+//! and the [`Quantity`] traits yourself, or you can use the derive macros from
+//! the [`derive`] module (see examples below).
 //!
 //! If you believe a unit or a quantity is missing, feel free to open an issue
 //! or a PR!
@@ -113,6 +118,19 @@
 //! let speed: MeterPerSecond = meters.divide_time(&seconds);
 //! ```
 //!
+//! ```
+//! use newnit::Unit;
+//! use newnit::length::metric::Meter;
+//! use newnit::time::metric::Second;
+//! use newnit::velocity::metric::MeterPerSecond;
+//!
+//! let meters = Meter(4.0);
+//! let seconds = Second(2.0);
+//!
+//! // any two units can be multiplied or divided
+//! let speed = MeterPerSecond::from(&(meters / &seconds));
+//! ```
+//!
 //! Define your own unit with a derive macro:
 //!
 //! ```
@@ -126,21 +144,74 @@
 //! struct Elephants(f64);
 //! ```
 //!
+//! # Features
+//!
+//! - `std` (default): use the standard library; without it, the library is
+//!   `#![no_std]`
+//! - `defmt`: implement `defmt::Format` for units
+//! - `serde`: serialize and deserialize units as their value with the symbol of
+//!   their unit (see `serde`)
+//! - `approx`: implement the traits of the approx crate for units (see
+//!   [`approx`])
+//! - `rust_decimal`, `num-rational`: use `Decimal` and `Ratio` to store values
+//!   of units (see [`scalar`])
+//!
 //!  [newtype]: https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod angle;
+pub mod approx;
 pub mod area;
+pub mod capacitance;
+pub mod charge;
+pub mod checked;
+pub mod cmp;
+pub mod conductance;
 pub mod current;
+pub mod dimension;
+pub mod dyn_quantity;
+pub mod energy;
+pub mod force;
+pub mod humanize;
+pub mod illuminance;
+pub mod inductance;
 pub mod length;
+pub mod luminance;
+pub mod luminous_energy;
+pub mod luminous_flux;
 pub mod luminous_intensity;
+pub mod magnetic_flux;
+pub mod magnetic_flux_density;
 pub mod mass;
+pub mod mixed;
+pub mod parse;
+pub mod power;
+pub mod pressure;
+pub mod quantity;
+pub mod rational;
+pub mod registry;
+pub mod resistance;
+pub mod runtime;
+pub mod scalar;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod solid_angle;
+pub mod stats;
 pub mod substance_amount;
 pub mod temperature;
 pub mod time;
 pub mod unit;
 pub mod velocity;
+pub mod voltage;
 pub mod volume;
 
 pub use newnit_derive as derive;
+pub use quantity::Quantity;
 pub use unit::Unit;
 
+// Allows the code generated by the derive macros to refer to this crate as
+// `::newnit` from within the crate itself.
+extern crate self as newnit;
+
 mod make_unit;
@@ -0,0 +1,62 @@
+//! Units of luminance.
+//!
+//! This module contains predefined newtypes for units of luminance as defined
+//! in the following systems:
+//! - [`imperial`] - British Imperial units
+//! - [`metric`] - International System of Units (SI)
+//!
+//! The base used for all systems is the (metric) nit, one candela per square
+//! metre.
+
+use crate::area::Area;
+use crate::luminous_intensity::metric::Candela;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
+
+pub mod imperial;
+pub mod metric;
+
+/// Types that are units of luminance.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+pub trait Luminance<V: Scalar = f64>: Quantity<V, Dimension = dimension::Luminance> {
+    /// Multiply a unit of luminance with a unit of area.
+    fn multiply_area(&self, rhs: &dyn Area<V>) -> Candela<V> {
+        Candela(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Luminance>> Luminance<V> for T {}
+
+parse::units! {
+    /// Parse a luminance in the unit named by its symbol (e.g. `"300 nt"`).
+    parse, UNITS: Luminance, LuminanceUnit;
+    metric::{
+        KiloNit,
+        Nit,
+        Stilb,
+    },
+    imperial::{
+        FootLambert,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::metric::SquareCentiMeter;
+    use crate::assert_quantity_eq;
+
+    #[test]
+    fn convert_and_multiply() {
+        assert_quantity_eq!(
+            imperial::FootLambert(1.0_f64),
+            metric::Nit(3.426_259_099_635_39)
+        );
+        assert_quantity_eq!(metric::Stilb(1.0_f64), metric::KiloNit(10.0));
+
+        let intensity = metric::Stilb(2.0_f64).multiply_area(&SquareCentiMeter(3.0));
+        assert_quantity_eq!(intensity, Candela(6.0));
+    }
+}
@@ -0,0 +1,20 @@
+//! British Imperial units of luminance
+//!
+//! This module contains predefined newtypes for units of luminance based on
+//! units of length defined in the British Imperial system. The base unit is
+//! the nit.
+
+use super::Luminance;
+use crate::make_unit;
+use newnit_derive::{Luminance, Unit};
+
+// 1/π candela per square foot.
+make_unit!(
+    FootLambert,
+    "fL",
+    "foot-lambert",
+    "foot-lamberts",
+    Imperial,
+    3.426_259_099_635_39,
+    Luminance
+);
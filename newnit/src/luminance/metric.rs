@@ -0,0 +1,27 @@
+//! Metric units of luminance
+//!
+//! This module contains predefined newtypes for units of luminance as defined
+//! in the International System of Units (SI). The base unit is the nit, or
+//! candela per square metre (cd/m²).
+//!
+//! Additional definition provided for the stilb (sb) = 1E+4 nt, the CGS unit
+//! of one candela per square centimetre.
+
+use super::Luminance;
+use crate::make_unit;
+use newnit_derive::{Luminance, Unit};
+
+make_unit!(
+    KiloNit, "knt", "kilonit", "kilonits", Metric, 1E+3, Luminance
+);
+make_unit!(
+    #[unit(alias = "cd/m²")]
+    Nit,
+    "nt",
+    "nit",
+    "nits",
+    Metric,
+    1.0,
+    Luminance
+);
+make_unit!(Stilb, "sb", "stilb", "stilbs", Metric, 1E+4, Luminance);
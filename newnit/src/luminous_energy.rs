@@ -0,0 +1,86 @@
+//! Units of luminous energy.
+//!
+//! This module contains predefined newtypes for units of luminous energy as
+//! defined in the International System of Units (SI). The base unit is the
+//! [`LumenSecond`].
+//!
+//! Additional definition provided for the lumen-hour (lm·h) = 3600 lm·s.
+//!
+//! [`LumenSecond`]: metric::LumenSecond
+
+use crate::luminous_flux::LuminousFlux;
+use crate::luminous_flux::metric::Lumen;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::time::metric::Second;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of luminous energy.
+///
+/// Provides various division methods, resulting in other units of quantity,
+/// based on the right hand side (`rhs`) in the division.
+pub trait LuminousEnergy<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::LuminousEnergy>
+{
+    /// Divide a unit of luminous energy by a unit of time.
+    fn divide_time(&self, rhs: &dyn Time<V>) -> Lumen<V> {
+        Lumen(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of luminous energy by a unit of luminous flux.
+    fn divide_luminous_flux(&self, rhs: &dyn LuminousFlux<V>) -> Second<V> {
+        Second(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::LuminousEnergy>> LuminousEnergy<V> for T {}
+
+pub mod metric {
+    use super::LuminousEnergy;
+    use crate::make_unit;
+    use newnit_derive::{LuminousEnergy, Unit};
+
+    make_unit!(
+        LumenSecond,
+        "lm·s",
+        "lumen-second",
+        "lumen-seconds",
+        Metric,
+        1.0,
+        LuminousEnergy
+    );
+    make_unit!(
+        LumenHour,
+        "lm·h",
+        "lumen-hour",
+        "lumen-hours",
+        Metric,
+        3600.0,
+        LuminousEnergy
+    );
+}
+
+parse::units! {
+    /// Parse a luminous energy in the unit named by its symbol (e.g. `"20
+    /// lm·h"`).
+    parse, UNITS: LuminousEnergy, LuminousEnergyUnit;
+    metric::{
+        LumenSecond,
+        LumenHour,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::luminous_flux::metric::KiloLumen;
+    use crate::time::metric::Hour;
+
+    #[test]
+    fn divide() {
+        let energy = metric::LumenHour(1_600.0_f64);
+        assert_quantity_eq!(energy.divide_time(&Hour(2.0)), KiloLumen(0.8));
+        assert_quantity_eq!(energy.divide_luminous_flux(&Lumen(400.0)), Hour(4.0));
+    }
+}
@@ -0,0 +1,343 @@
+//! Units of luminous flux.
+//!
+//! This module contains predefined newtypes for units of luminous flux as
+//! defined in the International System of Units (SI). The base unit is the
+//! [`Lumen`], the flux of one candela into a solid angle of one steradian.
+//!
+//! [`Lumen`]: metric::Lumen
+
+use crate::area::Area;
+use crate::area::metric::SquareMeter;
+use crate::illuminance::Illuminance;
+use crate::illuminance::metric::Lux;
+use crate::luminous_energy::metric::LumenSecond;
+use crate::luminous_intensity::LuminousIntensity;
+use crate::luminous_intensity::metric::Candela;
+use crate::scalar::Scalar;
+use crate::solid_angle::SolidAngle;
+use crate::solid_angle::metric::Steradian;
+use crate::time::Time;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of luminous flux.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+///
+/// # Examples
+/// ```
+/// use newnit::area::metric::SquareMeter;
+/// use newnit::illuminance::metric::Lux;
+/// use newnit::luminous_flux::LuminousFlux;
+/// use newnit::luminous_flux::metric::Lumen;
+/// use newnit::solid_angle::metric::Steradian;
+///
+/// let flux = Lumen(1_200.0);
+/// assert_eq!(flux.divide_area(&SquareMeter(4.0)), Lux(300.0));
+/// assert_eq!(flux.divide_solid_angle(&Steradian(4.0)).0, 300.0);
+/// ```
+pub trait LuminousFlux<V: Scalar = f64>: Quantity<V, Dimension = dimension::LuminousFlux> {
+    /// Divide a unit of luminous flux by a unit of area.
+    fn divide_area(&self, rhs: &dyn Area<V>) -> Lux<V> {
+        Lux(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of luminous flux by a unit of illuminance.
+    fn divide_illuminance(&self, rhs: &dyn Illuminance<V>) -> SquareMeter<V> {
+        SquareMeter(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of luminous flux by a unit of solid angle.
+    fn divide_solid_angle(&self, rhs: &dyn SolidAngle<V>) -> Candela<V> {
+        Candela(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of luminous flux by a unit of luminous intensity.
+    fn divide_luminous_intensity(&self, rhs: &dyn LuminousIntensity<V>) -> Steradian<V> {
+        Steradian(self.to_base() / rhs.to_base())
+    }
+
+    /// Multiply a unit of luminous flux with a unit of time.
+    fn multiply_time(&self, rhs: &dyn Time<V>) -> LumenSecond<V> {
+        LumenSecond(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::LuminousFlux>> LuminousFlux<V> for T {}
+
+pub mod metric {
+    use super::LuminousFlux;
+    use crate::make_unit;
+    use newnit_derive::{LuminousFlux, Unit};
+
+    make_unit!(
+        QuettaLumen,
+        "Qlm",
+        "quettalumen",
+        "quettalumens",
+        Metric,
+        1E+30,
+        LuminousFlux
+    );
+    make_unit!(
+        RonnaLumen,
+        "Rlm",
+        "ronnalumen",
+        "ronnalumens",
+        Metric,
+        1E+27,
+        LuminousFlux
+    );
+    make_unit!(
+        YottaLumen,
+        "Ylm",
+        "yottalumen",
+        "yottalumens",
+        Metric,
+        1E+24,
+        LuminousFlux
+    );
+    make_unit!(
+        ZettaLumen,
+        "Zlm",
+        "zettalumen",
+        "zettalumens",
+        Metric,
+        1E+21,
+        LuminousFlux
+    );
+    make_unit!(
+        ExaLumen,
+        "Elm",
+        "exalumen",
+        "exalumens",
+        Metric,
+        1E+18,
+        LuminousFlux
+    );
+    make_unit!(
+        PetaLumen,
+        "Plm",
+        "petalumen",
+        "petalumens",
+        Metric,
+        1E+15,
+        LuminousFlux
+    );
+    make_unit!(
+        TeraLumen,
+        "Tlm",
+        "teralumen",
+        "teralumens",
+        Metric,
+        1E+12,
+        LuminousFlux
+    );
+    make_unit!(
+        GigaLumen,
+        "Glm",
+        "gigalumen",
+        "gigalumens",
+        Metric,
+        1E+9,
+        LuminousFlux
+    );
+    make_unit!(
+        MegaLumen,
+        "Mlm",
+        "megalumen",
+        "megalumens",
+        Metric,
+        1E+6,
+        LuminousFlux
+    );
+    make_unit!(
+        KiloLumen,
+        "klm",
+        "kilolumen",
+        "kilolumens",
+        Metric,
+        1E+3,
+        LuminousFlux
+    );
+    make_unit!(Lumen, "lm", "lumen", "lumens", Metric, 1.0, LuminousFlux);
+    make_unit!(
+        DeciLumen,
+        "dlm",
+        "decilumen",
+        "decilumens",
+        Metric,
+        1E-1,
+        LuminousFlux
+    );
+    make_unit!(
+        CentiLumen,
+        "clm",
+        "centilumen",
+        "centilumens",
+        Metric,
+        1E-2,
+        LuminousFlux
+    );
+    make_unit!(
+        MilliLumen,
+        "mlm",
+        "millilumen",
+        "millilumens",
+        Metric,
+        1E-3,
+        LuminousFlux
+    );
+    make_unit!(
+        MicroLumen,
+        "µlm",
+        "microlumen",
+        "microlumens",
+        Metric,
+        1E-6,
+        LuminousFlux
+    );
+    make_unit!(
+        NanoLumen,
+        "nlm",
+        "nanolumen",
+        "nanolumens",
+        Metric,
+        1E-9,
+        LuminousFlux
+    );
+    make_unit!(
+        PicoLumen,
+        "plm",
+        "picolumen",
+        "picolumens",
+        Metric,
+        1E-12,
+        LuminousFlux
+    );
+    make_unit!(
+        FemtoLumen,
+        "flm",
+        "femtolumen",
+        "femtolumens",
+        Metric,
+        1E-15,
+        LuminousFlux
+    );
+    make_unit!(
+        AttoLumen,
+        "alm",
+        "attolumen",
+        "attolumens",
+        Metric,
+        1E-18,
+        LuminousFlux
+    );
+    make_unit!(
+        ZeptoLumen,
+        "zlm",
+        "zeptolumen",
+        "zeptolumens",
+        Metric,
+        1E-21,
+        LuminousFlux
+    );
+    make_unit!(
+        YoctoLumen,
+        "ylm",
+        "yoctolumen",
+        "yoctolumens",
+        Metric,
+        1E-24,
+        LuminousFlux
+    );
+    make_unit!(
+        RontoLumen,
+        "rlm",
+        "rontolumen",
+        "rontolumens",
+        Metric,
+        1E-27,
+        LuminousFlux
+    );
+    make_unit!(
+        QuectoLumen,
+        "qlm",
+        "quectolumen",
+        "quectolumens",
+        Metric,
+        1E-30,
+        LuminousFlux
+    );
+}
+
+parse::units! {
+    /// Parse a luminous flux in the unit named by its symbol (e.g. `"800
+    /// lm"`).
+    parse, UNITS: LuminousFlux, LuminousFluxUnit;
+    metric::{
+        QuettaLumen,
+        RonnaLumen,
+        YottaLumen,
+        ZettaLumen,
+        ExaLumen,
+        PetaLumen,
+        TeraLumen,
+        GigaLumen,
+        MegaLumen,
+        KiloLumen,
+        Lumen,
+        DeciLumen,
+        CentiLumen,
+        MilliLumen,
+        MicroLumen,
+        NanoLumen,
+        PicoLumen,
+        FemtoLumen,
+        AttoLumen,
+        ZeptoLumen,
+        YoctoLumen,
+        RontoLumen,
+        QuectoLumen,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::imperial::SquareFoot;
+    use crate::assert_quantity_eq;
+    use crate::illuminance::imperial::FootCandle;
+    use crate::luminous_intensity::metric::MilliCandela;
+    use crate::time::metric::Hour;
+
+    #[test]
+    fn divide_and_multiply() {
+        let flux = metric::KiloLumen(1.0_f64);
+        assert_quantity_eq!(flux.divide_area(&SquareFoot(100.0)), FootCandle(10.0));
+        assert_quantity_eq!(flux.divide_illuminance(&FootCandle(20.0)), SquareFoot(50.0));
+
+        let solid_angle = metric::Lumen(2.0_f64).divide_luminous_intensity(&MilliCandela(500.0));
+        assert_quantity_eq!(solid_angle, Steradian(4.0));
+        assert_quantity_eq!(flux.multiply_time(&Hour(2.0)), LumenSecond(7.2E+6));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn derive_from_operators() {
+        let flux = metric::Lumen::from(&(Lux(500.0) * &SquareMeter(2.0)));
+        assert_eq!(flux, metric::Lumen(1_000.0));
+        let flux = metric::Lumen::from(&(Candela(40.0) * &Steradian(0.5)));
+        assert_eq!(flux, metric::Lumen(20.0));
+        let intensity = Candela::from(&(flux / &Steradian(2.0)));
+        assert_eq!(intensity, Candela(10.0));
+
+        let solid_angle = Steradian::from(&(flux / &Candela(10.0)));
+        assert_eq!(solid_angle, Steradian(2.0));
+        let per_steradian = Candela(10.0) / &flux;
+        assert_eq!(per_steradian.to_string(), "0.5 sr^-1");
+        let square = Steradian(2.0) * &Steradian(3.0);
+        assert_eq!(square.to_string(), "6 sr^2");
+    }
+}
@@ -6,36 +6,288 @@
 //!
 //! [`Candela`]: metric::Candela
 
-use crate::Unit;
+use crate::area::Area;
+use crate::luminance::metric::Nit;
+use crate::luminous_flux::metric::Lumen;
+use crate::scalar::Scalar;
+use crate::solid_angle::SolidAngle;
+use crate::{Quantity, dimension, parse};
 
-pub trait LuminousIntensity: Unit {}
+/// Types that are units of luminous intensity.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+///
+/// # Examples
+/// ```
+/// use newnit::luminous_flux::metric::Lumen;
+/// use newnit::luminous_intensity::LuminousIntensity;
+/// use newnit::luminous_intensity::metric::Candela;
+/// use newnit::solid_angle::metric::Steradian;
+///
+/// let flux = Candela(100.0).multiply_solid_angle(&Steradian(2.0));
+/// assert_eq!(flux, Lumen(200.0));
+/// ```
+pub trait LuminousIntensity<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::LuminousIntensity>
+{
+    /// Multiply a unit of luminous intensity with a unit of solid angle.
+    fn multiply_solid_angle(&self, rhs: &dyn SolidAngle<V>) -> Lumen<V> {
+        Lumen(self.to_base() * rhs.to_base())
+    }
+
+    /// Divide a unit of luminous intensity by a unit of area.
+    fn divide_area(&self, rhs: &dyn Area<V>) -> Nit<V> {
+        Nit(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::LuminousIntensity>> LuminousIntensity<V>
+    for T
+{
+}
 
 pub mod metric {
     use super::LuminousIntensity;
-    use crate::{Unit, make_unit};
+    use crate::make_unit;
     use newnit_derive::{LuminousIntensity, Unit};
 
-    make_unit!(QuettaCandela, 1E+30, LuminousIntensity);
-    make_unit!(RonnaCandela, 1E+27, LuminousIntensity);
-    make_unit!(YottaCandela, 1E+24, LuminousIntensity);
-    make_unit!(ZettaCandela, 1E+21, LuminousIntensity);
-    make_unit!(ExaCandela, 1E+18, LuminousIntensity);
-    make_unit!(PetaCandela, 1E+15, LuminousIntensity);
-    make_unit!(TeraCandela, 1E+12, LuminousIntensity);
-    make_unit!(GigaCandela, 1E+9, LuminousIntensity);
-    make_unit!(MegaCandela, 1E+6, LuminousIntensity);
-    make_unit!(KiloCandela, 1E+3, LuminousIntensity);
-    make_unit!(Candela, 1.0, LuminousIntensity);
-    make_unit!(DeciCandela, 1E-1, LuminousIntensity);
-    make_unit!(CentiCandela, 1E-2, LuminousIntensity);
-    make_unit!(MilliCandela, 1E-3, LuminousIntensity);
-    make_unit!(MicroCandela, 1E-6, LuminousIntensity);
-    make_unit!(NanoCandela, 1E-9, LuminousIntensity);
-    make_unit!(PicoCandela, 1E-12, LuminousIntensity);
-    make_unit!(FemtoCandela, 1E-15, LuminousIntensity);
-    make_unit!(AttoCandela, 1E-18, LuminousIntensity);
-    make_unit!(ZeptoCandela, 1E-21, LuminousIntensity);
-    make_unit!(YoctoCandela, 1E-24, LuminousIntensity);
-    make_unit!(RontoCandela, 1E-27, LuminousIntensity);
-    make_unit!(QuectoCandela, 1E-30, LuminousIntensity);
+    make_unit!(
+        QuettaCandela,
+        "Qcd",
+        "quettacandela",
+        "quettacandelas",
+        Metric,
+        1E+30,
+        LuminousIntensity
+    );
+    make_unit!(
+        RonnaCandela,
+        "Rcd",
+        "ronnacandela",
+        "ronnacandelas",
+        Metric,
+        1E+27,
+        LuminousIntensity
+    );
+    make_unit!(
+        YottaCandela,
+        "Ycd",
+        "yottacandela",
+        "yottacandelas",
+        Metric,
+        1E+24,
+        LuminousIntensity
+    );
+    make_unit!(
+        ZettaCandela,
+        "Zcd",
+        "zettacandela",
+        "zettacandelas",
+        Metric,
+        1E+21,
+        LuminousIntensity
+    );
+    make_unit!(
+        ExaCandela,
+        "Ecd",
+        "exacandela",
+        "exacandelas",
+        Metric,
+        1E+18,
+        LuminousIntensity
+    );
+    make_unit!(
+        PetaCandela,
+        "Pcd",
+        "petacandela",
+        "petacandelas",
+        Metric,
+        1E+15,
+        LuminousIntensity
+    );
+    make_unit!(
+        TeraCandela,
+        "Tcd",
+        "teracandela",
+        "teracandelas",
+        Metric,
+        1E+12,
+        LuminousIntensity
+    );
+    make_unit!(
+        GigaCandela,
+        "Gcd",
+        "gigacandela",
+        "gigacandelas",
+        Metric,
+        1E+9,
+        LuminousIntensity
+    );
+    make_unit!(
+        MegaCandela,
+        "Mcd",
+        "megacandela",
+        "megacandelas",
+        Metric,
+        1E+6,
+        LuminousIntensity
+    );
+    make_unit!(
+        KiloCandela,
+        "kcd",
+        "kilocandela",
+        "kilocandelas",
+        Metric,
+        1E+3,
+        LuminousIntensity
+    );
+    make_unit!(
+        Candela,
+        "cd",
+        "candela",
+        "candelas",
+        Metric,
+        1.0,
+        LuminousIntensity
+    );
+    make_unit!(
+        DeciCandela,
+        "dcd",
+        "decicandela",
+        "decicandelas",
+        Metric,
+        1E-1,
+        LuminousIntensity
+    );
+    make_unit!(
+        CentiCandela,
+        "ccd",
+        "centicandela",
+        "centicandelas",
+        Metric,
+        1E-2,
+        LuminousIntensity
+    );
+    make_unit!(
+        MilliCandela,
+        "mcd",
+        "millicandela",
+        "millicandelas",
+        Metric,
+        1E-3,
+        LuminousIntensity
+    );
+    make_unit!(
+        MicroCandela,
+        "µcd",
+        "microcandela",
+        "microcandelas",
+        Metric,
+        1E-6,
+        LuminousIntensity
+    );
+    make_unit!(
+        NanoCandela,
+        "ncd",
+        "nanocandela",
+        "nanocandelas",
+        Metric,
+        1E-9,
+        LuminousIntensity
+    );
+    make_unit!(
+        PicoCandela,
+        "pcd",
+        "picocandela",
+        "picocandelas",
+        Metric,
+        1E-12,
+        LuminousIntensity
+    );
+    make_unit!(
+        FemtoCandela,
+        "fcd",
+        "femtocandela",
+        "femtocandelas",
+        Metric,
+        1E-15,
+        LuminousIntensity
+    );
+    make_unit!(
+        AttoCandela,
+        "acd",
+        "attocandela",
+        "attocandelas",
+        Metric,
+        1E-18,
+        LuminousIntensity
+    );
+    make_unit!(
+        ZeptoCandela,
+        "zcd",
+        "zeptocandela",
+        "zeptocandelas",
+        Metric,
+        1E-21,
+        LuminousIntensity
+    );
+    make_unit!(
+        YoctoCandela,
+        "ycd",
+        "yoctocandela",
+        "yoctocandelas",
+        Metric,
+        1E-24,
+        LuminousIntensity
+    );
+    make_unit!(
+        RontoCandela,
+        "rcd",
+        "rontocandela",
+        "rontocandelas",
+        Metric,
+        1E-27,
+        LuminousIntensity
+    );
+    make_unit!(
+        QuectoCandela,
+        "qcd",
+        "quectocandela",
+        "quectocandelas",
+        Metric,
+        1E-30,
+        LuminousIntensity
+    );
+}
+
+parse::units! {
+    /// Parse a luminous intensity in the unit named by its symbol (e.g. `"120 cd"`).
+    parse, UNITS: LuminousIntensity, LuminousIntensityUnit;
+    metric::{
+        QuettaCandela,
+        RonnaCandela,
+        YottaCandela,
+        ZettaCandela,
+        ExaCandela,
+        PetaCandela,
+        TeraCandela,
+        GigaCandela,
+        MegaCandela,
+        KiloCandela,
+        Candela,
+        DeciCandela,
+        CentiCandela,
+        MilliCandela,
+        MicroCandela,
+        NanoCandela,
+        PicoCandela,
+        FemtoCandela,
+        AttoCandela,
+        ZeptoCandela,
+        YoctoCandela,
+        RontoCandela,
+        QuectoCandela,
+    },
 }
@@ -0,0 +1,293 @@
+//! Units of magnetic flux.
+//!
+//! This module contains predefined newtypes for units of magnetic flux as
+//! defined in the International System of Units (SI). The base unit is the
+//! [`Weber`].
+//!
+//! [`Weber`]: metric::Weber
+
+use crate::area::Area;
+use crate::current::Current;
+use crate::inductance::metric::Henry;
+use crate::magnetic_flux_density::metric::Tesla;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::voltage::metric::Volt;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of magnetic flux.
+///
+/// Provides various division methods, resulting in other units of quantity,
+/// based on the right hand side (`rhs`) in the division.
+pub trait MagneticFlux<V: Scalar = f64>: Quantity<V, Dimension = dimension::MagneticFlux> {
+    /// Divide a unit of magnetic flux by a unit of area.
+    fn divide_area(&self, rhs: &dyn Area<V>) -> Tesla<V> {
+        Tesla(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of magnetic flux by a unit of current.
+    fn divide_current(&self, rhs: &dyn Current<V>) -> Henry<V> {
+        Henry(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of magnetic flux by a unit of time.
+    fn divide_time(&self, rhs: &dyn Time<V>) -> Volt<V> {
+        Volt(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::MagneticFlux>> MagneticFlux<V> for T {}
+
+pub mod metric {
+    use super::MagneticFlux;
+    use crate::make_unit;
+    use newnit_derive::{MagneticFlux, Unit};
+
+    make_unit!(
+        QuettaWeber,
+        "QWb",
+        "quettaweber",
+        "quettawebers",
+        Metric,
+        1E+30,
+        MagneticFlux
+    );
+    make_unit!(
+        RonnaWeber,
+        "RWb",
+        "ronnaweber",
+        "ronnawebers",
+        Metric,
+        1E+27,
+        MagneticFlux
+    );
+    make_unit!(
+        YottaWeber,
+        "YWb",
+        "yottaweber",
+        "yottawebers",
+        Metric,
+        1E+24,
+        MagneticFlux
+    );
+    make_unit!(
+        ZettaWeber,
+        "ZWb",
+        "zettaweber",
+        "zettawebers",
+        Metric,
+        1E+21,
+        MagneticFlux
+    );
+    make_unit!(
+        ExaWeber,
+        "EWb",
+        "exaweber",
+        "exawebers",
+        Metric,
+        1E+18,
+        MagneticFlux
+    );
+    make_unit!(
+        PetaWeber,
+        "PWb",
+        "petaweber",
+        "petawebers",
+        Metric,
+        1E+15,
+        MagneticFlux
+    );
+    make_unit!(
+        TeraWeber,
+        "TWb",
+        "teraweber",
+        "terawebers",
+        Metric,
+        1E+12,
+        MagneticFlux
+    );
+    make_unit!(
+        GigaWeber,
+        "GWb",
+        "gigaweber",
+        "gigawebers",
+        Metric,
+        1E+9,
+        MagneticFlux
+    );
+    make_unit!(
+        MegaWeber,
+        "MWb",
+        "megaweber",
+        "megawebers",
+        Metric,
+        1E+6,
+        MagneticFlux
+    );
+    make_unit!(
+        KiloWeber,
+        "kWb",
+        "kiloweber",
+        "kilowebers",
+        Metric,
+        1E+3,
+        MagneticFlux
+    );
+    make_unit!(Weber, "Wb", "weber", "webers", Metric, 1.0, MagneticFlux);
+    make_unit!(
+        DeciWeber,
+        "dWb",
+        "deciweber",
+        "deciwebers",
+        Metric,
+        1E-1,
+        MagneticFlux
+    );
+    make_unit!(
+        CentiWeber,
+        "cWb",
+        "centiweber",
+        "centiwebers",
+        Metric,
+        1E-2,
+        MagneticFlux
+    );
+    make_unit!(
+        MilliWeber,
+        "mWb",
+        "milliweber",
+        "milliwebers",
+        Metric,
+        1E-3,
+        MagneticFlux
+    );
+    make_unit!(
+        MicroWeber,
+        "µWb",
+        "microweber",
+        "microwebers",
+        Metric,
+        1E-6,
+        MagneticFlux
+    );
+    make_unit!(
+        NanoWeber,
+        "nWb",
+        "nanoweber",
+        "nanowebers",
+        Metric,
+        1E-9,
+        MagneticFlux
+    );
+    make_unit!(
+        PicoWeber,
+        "pWb",
+        "picoweber",
+        "picowebers",
+        Metric,
+        1E-12,
+        MagneticFlux
+    );
+    make_unit!(
+        FemtoWeber,
+        "fWb",
+        "femtoweber",
+        "femtowebers",
+        Metric,
+        1E-15,
+        MagneticFlux
+    );
+    make_unit!(
+        AttoWeber,
+        "aWb",
+        "attoweber",
+        "attowebers",
+        Metric,
+        1E-18,
+        MagneticFlux
+    );
+    make_unit!(
+        ZeptoWeber,
+        "zWb",
+        "zeptoweber",
+        "zeptowebers",
+        Metric,
+        1E-21,
+        MagneticFlux
+    );
+    make_unit!(
+        YoctoWeber,
+        "yWb",
+        "yoctoweber",
+        "yoctowebers",
+        Metric,
+        1E-24,
+        MagneticFlux
+    );
+    make_unit!(
+        RontoWeber,
+        "rWb",
+        "rontoweber",
+        "rontowebers",
+        Metric,
+        1E-27,
+        MagneticFlux
+    );
+    make_unit!(
+        QuectoWeber,
+        "qWb",
+        "quectoweber",
+        "quectowebers",
+        Metric,
+        1E-30,
+        MagneticFlux
+    );
+}
+
+parse::units! {
+    /// Parse a magnetic flux in the unit named by its symbol (e.g. `"2 mWb"`).
+    parse, UNITS: MagneticFlux, MagneticFluxUnit;
+    metric::{
+        QuettaWeber,
+        RonnaWeber,
+        YottaWeber,
+        ZettaWeber,
+        ExaWeber,
+        PetaWeber,
+        TeraWeber,
+        GigaWeber,
+        MegaWeber,
+        KiloWeber,
+        Weber,
+        DeciWeber,
+        CentiWeber,
+        MilliWeber,
+        MicroWeber,
+        NanoWeber,
+        PicoWeber,
+        FemtoWeber,
+        AttoWeber,
+        ZeptoWeber,
+        YoctoWeber,
+        RontoWeber,
+        QuectoWeber,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::metric::SquareCentiMeter;
+    use crate::assert_quantity_eq;
+    use crate::current::metric::Ampere;
+    use crate::magnetic_flux_density::metric::Gauss;
+    use crate::time::metric::MilliSecond;
+
+    #[test]
+    fn divide() {
+        let flux = metric::MicroWeber(2.0_f64);
+        assert_quantity_eq!(flux.divide_area(&SquareCentiMeter(1.0)), Gauss(200.0));
+        assert_quantity_eq!(flux.divide_current(&Ampere(2.0)), Henry(1E-6));
+        assert_quantity_eq!(flux.divide_time(&MilliSecond(1.0)), Volt(2E-3));
+    }
+}
@@ -0,0 +1,303 @@
+//! Units of magnetic flux density.
+//!
+//! This module contains predefined newtypes for units of magnetic flux density
+//! as defined in the International System of Units (SI). The base unit is the
+//! [`Tesla`].
+//!
+//! Additional definition provided for the gauss (G) = 1E-4 T.
+//!
+//! [`Tesla`]: metric::Tesla
+
+use crate::area::Area;
+use crate::magnetic_flux::metric::Weber;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of magnetic flux density.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+pub trait MagneticFluxDensity<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::MagneticFluxDensity>
+{
+    /// Multiply a unit of magnetic flux density with a unit of area.
+    fn multiply_area(&self, rhs: &dyn Area<V>) -> Weber<V> {
+        Weber(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::MagneticFluxDensity>> MagneticFluxDensity<V>
+    for T
+{
+}
+
+pub mod metric {
+    use super::MagneticFluxDensity;
+    use crate::make_unit;
+    use newnit_derive::{MagneticFluxDensity, Unit};
+
+    make_unit!(
+        QuettaTesla,
+        "QT",
+        "quettatesla",
+        "quettateslas",
+        Metric,
+        1E+30,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        RonnaTesla,
+        "RT",
+        "ronnatesla",
+        "ronnateslas",
+        Metric,
+        1E+27,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        YottaTesla,
+        "YT",
+        "yottatesla",
+        "yottateslas",
+        Metric,
+        1E+24,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        ZettaTesla,
+        "ZT",
+        "zettatesla",
+        "zettateslas",
+        Metric,
+        1E+21,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        ExaTesla,
+        "ET",
+        "exatesla",
+        "exateslas",
+        Metric,
+        1E+18,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        PetaTesla,
+        "PT",
+        "petatesla",
+        "petateslas",
+        Metric,
+        1E+15,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        TeraTesla,
+        "TT",
+        "teratesla",
+        "terateslas",
+        Metric,
+        1E+12,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        GigaTesla,
+        "GT",
+        "gigatesla",
+        "gigateslas",
+        Metric,
+        1E+9,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        MegaTesla,
+        "MT",
+        "megatesla",
+        "megateslas",
+        Metric,
+        1E+6,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        KiloTesla,
+        "kT",
+        "kilotesla",
+        "kiloteslas",
+        Metric,
+        1E+3,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        Tesla,
+        "T",
+        "tesla",
+        "teslas",
+        Metric,
+        1.0,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        DeciTesla,
+        "dT",
+        "decitesla",
+        "deciteslas",
+        Metric,
+        1E-1,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        CentiTesla,
+        "cT",
+        "centitesla",
+        "centiteslas",
+        Metric,
+        1E-2,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        MilliTesla,
+        "mT",
+        "millitesla",
+        "milliteslas",
+        Metric,
+        1E-3,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        MicroTesla,
+        "µT",
+        "microtesla",
+        "microteslas",
+        Metric,
+        1E-6,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        NanoTesla,
+        "nT",
+        "nanotesla",
+        "nanoteslas",
+        Metric,
+        1E-9,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        PicoTesla,
+        "pT",
+        "picotesla",
+        "picoteslas",
+        Metric,
+        1E-12,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        FemtoTesla,
+        "fT",
+        "femtotesla",
+        "femtoteslas",
+        Metric,
+        1E-15,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        AttoTesla,
+        "aT",
+        "attotesla",
+        "attoteslas",
+        Metric,
+        1E-18,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        ZeptoTesla,
+        "zT",
+        "zeptotesla",
+        "zeptoteslas",
+        Metric,
+        1E-21,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        YoctoTesla,
+        "yT",
+        "yoctotesla",
+        "yoctoteslas",
+        Metric,
+        1E-24,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        RontoTesla,
+        "rT",
+        "rontotesla",
+        "rontoteslas",
+        Metric,
+        1E-27,
+        MagneticFluxDensity
+    );
+    make_unit!(
+        QuectoTesla,
+        "qT",
+        "quectotesla",
+        "quectoteslas",
+        Metric,
+        1E-30,
+        MagneticFluxDensity
+    );
+
+    make_unit!(
+        Gauss,
+        "G",
+        "gauss",
+        "gauss",
+        Metric,
+        1E-4,
+        MagneticFluxDensity
+    );
+}
+
+parse::units! {
+    /// Parse a magnetic flux density in the unit named by its symbol (e.g.
+    /// `"1.5 T"`).
+    parse, UNITS: MagneticFluxDensity, MagneticFluxDensityUnit;
+    metric::{
+        QuettaTesla,
+        RonnaTesla,
+        YottaTesla,
+        ZettaTesla,
+        ExaTesla,
+        PetaTesla,
+        TeraTesla,
+        GigaTesla,
+        MegaTesla,
+        KiloTesla,
+        Tesla,
+        DeciTesla,
+        CentiTesla,
+        MilliTesla,
+        MicroTesla,
+        NanoTesla,
+        PicoTesla,
+        FemtoTesla,
+        AttoTesla,
+        ZeptoTesla,
+        YoctoTesla,
+        RontoTesla,
+        QuectoTesla,
+        Gauss,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::metric::SquareMeter;
+    use crate::{Unit, assert_quantity_eq};
+
+    #[test]
+    fn convert_and_multiply() {
+        let density = metric::Gauss::from(&metric::MilliTesla(1.0_f64));
+        assert_quantity_eq!(density, metric::Gauss(10.0));
+        assert_quantity_eq!(density.multiply_area(&SquareMeter(2.0)), Weber(2E-3));
+        assert_eq!(density.plural(), "gauss");
+    }
+}
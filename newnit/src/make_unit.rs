@@ -1,29 +1,125 @@
 /// Define a new unit of measurement.
 ///
-/// Defines a newtype struct with the given `name`, implementing the [`Unit`]
-/// trait and the `quantity_trait`, signifying the quantity measured by
-/// the unit.
+/// Defines a newtype struct with the given `name`, generic over the type of
+/// its value (defaulting to [`f64`]), implementing the [`Unit`] trait and the
+/// `quantity_trait`, signifying the quantity measured by the unit. The
+/// `$symbol` of the unit (e.g. `"km"`) is used to parse and display values of
+/// the unit, its singular `$singular` and `$plural` names (e.g. `"kilometre"`
+/// and `"kilometres"`, the plural defaulting to the singular) to display them
+/// in the long form. `$system` names the variant of [`System`] the unit
+/// belongs to.
 ///
-/// `$factor` and `$offset` are used for conversions between the new unit and
-/// the base unit of the same quantity, defined as follows:
+/// `$factor` and `$offset` (exact decimal literals, [`Rational`] constants or
+/// arithmetic expressions of them, e.g. `5 / 9`, `Rational::PI / 180`) are
+/// used for conversions between the new unit and the base unit of the same
+/// quantity, defined as follows:
 ///   value_in_base_unit = value_in_this_unit * `$factor` + `$offset`
 ///
+/// Units measuring points on an affine scale (e.g. temperatures) additionally
+/// take the unit of intervals between the points as `$interval`.
+///
+/// The unit is registered in the [`registry`] under its quantity. Attributes
+/// preceding the `name` are applied to the struct, e.g. its documentation or
+/// aliases of the unit (`#[unit(alias = "L")]`).
+///
+/// The deprecated forms `make_unit!(name, factor, quantity_trait)` and
+/// `make_unit!(name, factor, offset, quantity_trait)` of earlier versions
+/// still define units, whose symbol and names are the `name` and whose system
+/// is [`System::Other`]. They aren't registered.
+///
 /// [`Unit`]: crate::Unit
+/// [`registry`]: crate::registry
+/// [`System`]: crate::unit::System
+/// [`Rational`]: crate::rational::Rational
 #[macro_export]
 macro_rules! make_unit {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $symbol: literal,
+        $singular: literal,
+        $($plural: literal,)?
+        $system: ident,
+        $factor: expr,
+        $offset: expr,
+        $quantity_trait:ident,
+        $interval:ident
+    ) => {
+        #[derive(Unit, Copy, Clone, Debug, Default, $quantity_trait)]
+        #[cfg_attr(feature = "serde", quantity(serde))]
+        #[cfg_attr(feature = "approx", quantity(approx))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[unit(factor = $factor, offset = $offset, symbol = $symbol, display)]
+        #[unit(name = $singular, $(plural = $plural,)? system = $system)]
+        #[quantity(from, cmp, ops, register, interval = $interval)]
+        $(#[$attr])*
+        pub struct $name<V = f64>(pub V);
+    };
+
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $symbol: literal,
+        $singular: literal,
+        $($plural: literal,)?
+        $system: ident,
+        $factor: expr,
+        $offset: expr,
+        $quantity_trait:ident
+    ) => {
+        #[derive(Unit, Copy, Clone, Debug, Default, $quantity_trait)]
+        #[cfg_attr(feature = "serde", quantity(serde))]
+        #[cfg_attr(feature = "approx", quantity(approx))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[unit(factor = $factor, offset = $offset, symbol = $symbol, display)]
+        #[unit(name = $singular, $(plural = $plural,)? system = $system)]
+        #[quantity(from, cmp, ops, register)]
+        $(#[$attr])*
+        pub struct $name<V = f64>(pub V);
+    };
+
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $symbol: literal,
+        $singular: literal,
+        $($plural: literal,)?
+        $system: ident,
+        $factor: expr,
+        $quantity_trait:ident
+    ) => {
+        #[derive(Unit, Copy, Clone, Debug, Default, $quantity_trait)]
+        #[cfg_attr(feature = "serde", quantity(serde))]
+        #[cfg_attr(feature = "approx", quantity(approx))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[unit(factor = $factor, symbol = $symbol, display)]
+        #[unit(name = $singular, $(plural = $plural,)? system = $system)]
+        #[quantity(from, cmp, ops, register)]
+        $(#[$attr])*
+        pub struct $name<V = f64>(pub V);
+    };
+
     ($name:ident, $factor: expr, $offset: expr, $quantity_trait:ident) => {
-        #[derive(Unit, Copy, Clone, PartialEq, PartialOrd, Debug, Default, $quantity_trait)]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Unit, Copy, Clone, Debug, Default, $quantity_trait)]
+        #[cfg_attr(feature = "serde", quantity(serde))]
+        #[cfg_attr(feature = "approx", quantity(approx))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
         #[unit(factor = $factor, offset = $offset, display)]
-        #[quantity(from, ops)]
-        pub struct $name(pub f64);
+        #[quantity(from, cmp, ops)]
+        pub struct $name<V = f64>(pub V);
+
+        const _: () = $crate::unit::__make_unit_without_metadata();
     };
 
     ($name:ident, $factor: expr, $quantity_trait:ident) => {
-        #[derive(Unit, Copy, Clone, PartialEq, PartialOrd, Debug, Default, $quantity_trait)]
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Unit, Copy, Clone, Debug, Default, $quantity_trait)]
+        #[cfg_attr(feature = "serde", quantity(serde))]
+        #[cfg_attr(feature = "approx", quantity(approx))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
         #[unit(factor = $factor, display)]
-        #[quantity(from, ops)]
-        pub struct $name(pub f64);
+        #[quantity(from, cmp, ops)]
+        pub struct $name<V = f64>(pub V);
+
+        const _: () = $crate::unit::__make_unit_without_metadata();
     };
 }
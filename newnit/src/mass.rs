@@ -22,11 +22,73 @@
 //! assert!((grams.to_value() - 907.1847).abs() < 1e-4);
 //! ```
 
-use crate::Unit;
+use crate::force::metric::Newton;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
 
 pub mod customary;
 pub mod imperial;
 pub mod metric;
 
-/// Marker trait for mass units.
-pub trait Mass: Unit {}
+/// Types that are units of mass.
+///
+/// Provides a multiplication method, resulting in a unit of force, based on
+/// the right hand side (`rhs`) in the multiplication.
+pub trait Mass<V: Scalar = f64>: Quantity<V, Dimension = dimension::Mass> {
+    /// Multiply a unit of mass with any unit of acceleration (e.g. a
+    /// [`Derived`](crate::quantity::Derived) unit).
+    fn multiply_acceleration(
+        &self,
+        rhs: &dyn Quantity<V, Dimension = dimension::Acceleration>,
+    ) -> Newton<V> {
+        Newton(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Mass>> Mass<V> for T {}
+
+parse::units! {
+    /// Parse a mass in the unit named by its symbol (e.g. `"70 kg"`).
+    parse, UNITS: Mass, MassUnit;
+    metric::{
+        QuettaGram,
+        RonnaGram,
+        YottaGram,
+        ZettaGram,
+        ExaGram,
+        PetaGram,
+        TeraGram,
+        GigaGram,
+        MegaGram,
+        KiloGram,
+        Gram,
+        MilliGram,
+        MicroGram,
+        NanoGram,
+        PicoGram,
+        FemtoGram,
+        AttoGram,
+        ZeptoGram,
+        YoctoGram,
+        RontoGram,
+        QuectoGram,
+    },
+    imperial::{
+        TroyPound,
+        TroyOunce,
+        Pennyweight,
+        Grain,
+        LongTon,
+        Hundredweight,
+        Quarter,
+        Stone,
+        Pound,
+        Ounce,
+        Drachm,
+        Slug,
+    },
+    customary::{
+        ShortTon,
+        ShortHundredweight,
+    },
+}
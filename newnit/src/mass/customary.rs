@@ -8,7 +8,7 @@
 //! used, where possible.
 
 use super::Mass;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Mass, Unit};
 
 // Troy units (precious metals)
@@ -20,13 +20,29 @@ pub use super::imperial::{Grain, Pennyweight, TroyOunce, TroyPound};
 pub type LongTon = super::imperial::LongTon;
 
 // short ton
-make_unit!(ShortTon, 907.184_74, Mass);
+make_unit!(
+    ShortTon,
+    "sh tn",
+    "short ton",
+    "short tons",
+    UsCustomary,
+    907.184_74,
+    Mass
+);
 
 // long cwt
 pub type LongHundredweight = super::imperial::Hundredweight;
 
 // US cwt
-make_unit!(ShortHundredweight, 45.359_237, Mass);
+make_unit!(
+    ShortHundredweight,
+    "sh cwt",
+    "short hundredweight",
+    "short hundredweight",
+    UsCustomary,
+    45.359_237,
+    Mass
+);
 
 pub use super::imperial::{Ounce, Pound};
 
@@ -6,30 +6,127 @@
 //! Additional aliases provided for commonly used alternative unit names.
 
 use super::Mass;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Mass, Unit};
 
-make_unit!(QuettaGram, 1E+27, Mass);
-make_unit!(RonnaGram, 1E+24, Mass);
-make_unit!(YottaGram, 1E+21, Mass);
-make_unit!(ZettaGram, 1E+18, Mass);
-make_unit!(ExaGram, 1E+15, Mass);
-make_unit!(PetaGram, 1E+12, Mass);
-make_unit!(TeraGram, 1E+9, Mass);
-make_unit!(GigaGram, 1E+6, Mass);
-make_unit!(MegaGram, 1E+3, Mass);
-make_unit!(KiloGram, 1E+0, Mass);
-make_unit!(Gram, 1E-3, Mass);
-make_unit!(MilliGram, 1E-6, Mass);
-make_unit!(MicroGram, 1E-9, Mass);
-make_unit!(NanoGram, 1E-12, Mass);
-make_unit!(PicoGram, 1E-15, Mass);
-make_unit!(FemtoGram, 1E-18, Mass);
-make_unit!(AttoGram, 1E-21, Mass);
-make_unit!(ZeptoGram, 1E-24, Mass);
-make_unit!(YoctoGram, 1E-27, Mass);
-make_unit!(RontoGram, 1E-30, Mass);
-make_unit!(QuectoGram, 1E-33, Mass);
+make_unit!(
+    QuettaGram,
+    "Qg",
+    "quettagram",
+    "quettagrams",
+    Metric,
+    1E+27,
+    Mass
+);
+make_unit!(
+    RonnaGram,
+    "Rg",
+    "ronnagram",
+    "ronnagrams",
+    Metric,
+    1E+24,
+    Mass
+);
+make_unit!(
+    YottaGram,
+    "Yg",
+    "yottagram",
+    "yottagrams",
+    Metric,
+    1E+21,
+    Mass
+);
+make_unit!(
+    ZettaGram,
+    "Zg",
+    "zettagram",
+    "zettagrams",
+    Metric,
+    1E+18,
+    Mass
+);
+make_unit!(ExaGram, "Eg", "exagram", "exagrams", Metric, 1E+15, Mass);
+make_unit!(PetaGram, "Pg", "petagram", "petagrams", Metric, 1E+12, Mass);
+make_unit!(TeraGram, "Tg", "teragram", "teragrams", Metric, 1E+9, Mass);
+make_unit!(GigaGram, "Gg", "gigagram", "gigagrams", Metric, 1E+6, Mass);
+make_unit!(
+    #[unit(alias = "t", alias = "tonne", alias = "tonnes")]
+    MegaGram,
+    "Mg",
+    "megagram",
+    "megagrams",
+    Metric,
+    1E+3,
+    Mass
+);
+make_unit!(KiloGram, "kg", "kilogram", "kilograms", Metric, 1E+0, Mass);
+make_unit!(Gram, "g", "gram", "grams", Metric, 1E-3, Mass);
+make_unit!(
+    MilliGram,
+    "mg",
+    "milligram",
+    "milligrams",
+    Metric,
+    1E-6,
+    Mass
+);
+make_unit!(
+    MicroGram,
+    "µg",
+    "microgram",
+    "micrograms",
+    Metric,
+    1E-9,
+    Mass
+);
+make_unit!(NanoGram, "ng", "nanogram", "nanograms", Metric, 1E-12, Mass);
+make_unit!(PicoGram, "pg", "picogram", "picograms", Metric, 1E-15, Mass);
+make_unit!(
+    FemtoGram,
+    "fg",
+    "femtogram",
+    "femtograms",
+    Metric,
+    1E-18,
+    Mass
+);
+make_unit!(AttoGram, "ag", "attogram", "attograms", Metric, 1E-21, Mass);
+make_unit!(
+    ZeptoGram,
+    "zg",
+    "zeptogram",
+    "zeptograms",
+    Metric,
+    1E-24,
+    Mass
+);
+make_unit!(
+    YoctoGram,
+    "yg",
+    "yoctogram",
+    "yoctograms",
+    Metric,
+    1E-27,
+    Mass
+);
+make_unit!(
+    RontoGram,
+    "rg",
+    "rontogram",
+    "rontograms",
+    Metric,
+    1E-30,
+    Mass
+);
+make_unit!(
+    QuectoGram,
+    "qg",
+    "quectogram",
+    "quectograms",
+    Metric,
+    1E-33,
+    Mass
+);
 
 /// Metric Tonne
 pub type Tonne = MegaGram;
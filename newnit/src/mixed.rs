@@ -0,0 +1,328 @@
+//! Formatting of values split across several units.
+//!
+//! Heights, durations or recipe amounts read best in mixed units (e.g. `5 ft
+//! 11 in`, `1 h 23 min 4 s`). A quantity [formatted](Mix::mixed) in a tuple
+//! of units of the same quantity, ordered from the largest to the smallest
+//! unit, displays as whole numbers of all but the last unit, and the rest in
+//! the last unit.
+//!
+//! The last unit is rounded to the precision given in the format string (e.g.
+//! `{:.1}`), to whole numbers by default, carrying over into the larger units
+//! (e.g. `6 ft 0 in` instead of `5 ft 12 in`). Leading units of zero value are
+//! omitted. The alternate form `{:#}` displays the names of the units.
+//!
+//! # Examples
+//! ```
+//! use newnit::length::imperial::{Foot, Inch};
+//! use newnit::length::metric::Meter;
+//! use newnit::mass::imperial::{Pound, Stone};
+//! use newnit::mass::metric::KiloGram;
+//! use newnit::mixed::Mix;
+//! use newnit::time::metric::{Hour, Minute, Second};
+//!
+//! let height = Meter(1.8);
+//! assert_eq!(height.mixed::<(Foot, Inch)>().to_string(), "5 ft 11 in");
+//! assert_eq!(
+//!     format!("{:.1}", height.mixed::<(Foot, Inch)>()),
+//!     "5 ft 10.9 in"
+//! );
+//!
+//! let duration = Second(5_024.0).mixed::<(Hour, Minute, Second)>();
+//! assert_eq!(format!("{duration:#}"), "1 hour 23 minutes 44 seconds");
+//!
+//! assert_eq!(
+//!     KiloGram(70.0).mixed::<(Stone, Pound)>().to_string(),
+//!     "11 st 0 lb"
+//! );
+//! ```
+//!
+//! Units of different quantities can't be mixed:
+//!
+//! ```compile_fail
+//! use newnit::length::imperial::Foot;
+//! use newnit::mixed::Mix;
+//! use newnit::time::metric::Second;
+//!
+//! let bad_mix = Second(3.0).mixed::<(Foot, Second)>();
+//! ```
+//!
+//! Neither can points on an affine scale (e.g. temperatures):
+//!
+//! ```compile_fail
+//! use newnit::mixed::Mix;
+//! use newnit::temperature::{Celsius, Fahrenheit};
+//!
+//! let bad_mix = Celsius(3.5).mixed::<(Celsius, Fahrenheit)>().to_string();
+//! ```
+//!
+//! Values that can't be split exactly (NaN, infinities, or values too large for
+//! whole numbers of the last unit to be exact) are displayed in the last unit.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Quantity;
+use crate::dimension::Dimension;
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::unit::{Conversion, Metadata};
+
+/// The greatest number of units a quantity can be split across.
+const MAX_UNITS: usize = 6;
+
+/// 2^53, from which on all [`f64`]s are whole numbers, and not all whole
+/// numbers are [`f64`]s.
+const EXACT_INTEGERS: f64 = 9_007_199_254_740_992.0;
+
+/// A unit of mixed units.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Part {
+    symbol: &'static str,
+    name: &'static str,
+    plural: &'static str,
+    factor: Rational,
+}
+
+impl Part {
+    /// The unit `U`.
+    const fn of<U: Conversion + Metadata>() -> Self {
+        Self {
+            symbol: U::SYMBOL,
+            name: U::NAME,
+            plural: U::PLURAL,
+            factor: U::FACTOR,
+        }
+    }
+}
+
+/// An ordered list of units of the same quantity.
+///
+/// Implemented for tuples of one to six units, ordered from the largest to the
+/// smallest unit (e.g. `(Hour, Minute, Second)`). Points on an affine scale
+/// (e.g. temperatures) can't be split across units, as parts of them can't be
+/// summed, which fails to compile.
+pub trait Units {
+    /// The dimension of the quantity measured by the units.
+    type Dimension;
+
+    /// The units, in order.
+    const PARTS: &'static [Part];
+}
+
+macro_rules! units {
+    ($first:ident $(, $unit:ident)*) => {
+        impl<
+            $first: Quantity + Conversion + Metadata,
+            $(
+                $unit: Quantity<Dimension = <$first as Quantity>::Dimension>
+                    + Conversion
+                    + Metadata,
+            )*
+        > Units for ($first, $($unit,)*)
+        {
+            type Dimension = <$first as Quantity>::Dimension;
+
+            const PARTS: &'static [Part] = {
+                assert!(
+                    !<Self::Dimension as Dimension>::AFFINE,
+                    "Points on an affine scale can't be split across units."
+                );
+
+                &[Part::of::<$first>(), $(Part::of::<$unit>(),)*]
+            };
+        }
+    };
+}
+
+units!(A);
+units!(A, B);
+units!(A, B, C);
+units!(A, B, C, D);
+units!(A, B, C, D, E);
+units!(A, B, C, D, E, F);
+
+/// Quantities that can be split across several units for display.
+///
+/// Implemented for all quantities, including trait objects of quantity traits
+/// (e.g. `dyn Length`).
+pub trait Mix<V: Scalar = f64>: Quantity<V> {
+    /// The quantity split across the units `U` (e.g. `(Foot, Inch)`).
+    fn mixed<U: Units<Dimension = Self::Dimension>>(&self) -> Mixed<U, V> {
+        Mixed {
+            base: self.to_base(),
+            units: PhantomData,
+        }
+    }
+}
+
+impl<V: Scalar, Q: Quantity<V> + ?Sized> Mix<V> for Q {}
+
+/// A quantity split across the units `U`, for display.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mixed<U, V = f64> {
+    base: V,
+    units: PhantomData<U>,
+}
+
+impl<U: Units, V: Scalar> fmt::Display for Mixed<U, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = &U::PARTS[..U::PARTS.len().min(MAX_UNITS)];
+        let last = parts.len() - 1;
+        let decimals = f.precision().unwrap_or(0);
+
+        let base = self.base.to_f64();
+        let mut remaining = if base < 0.0 { -base } else { base };
+
+        // Values that can't be split exactly (e.g. NaN, or too large for whole
+        // numbers of the last unit to be exact) are displayed in the last unit.
+        let last_factor = parts[last].factor.to_f64();
+        let in_last = remaining / last_factor;
+        if in_last.is_nan() || in_last >= EXACT_INTEGERS {
+            let value = base / last_factor;
+            let unit = match f.alternate() {
+                false => parts[last].symbol,
+                true => parts[last].plural,
+            };
+            return write!(f, "{value:.decimals$} {unit}");
+        }
+
+        // Whole numbers of all but the last unit, the rest in the last unit.
+        let mut values = [0.0; MAX_UNITS];
+        for (value, part) in values.iter_mut().zip(&parts[..last]) {
+            let factor = part.factor.to_f64();
+            *value = (remaining / factor) as u64 as f64;
+            remaining = (remaining - *value * factor).max(0.0);
+        }
+        values[last] = round(remaining / last_factor, decimals);
+
+        // Rounding may fill a whole larger unit (e.g. `5 ft 12 in`).
+        for i in (1..=last).rev() {
+            let ratio = parts[i - 1].factor.quotient(parts[i].factor).to_f64();
+            if values[i] >= ratio {
+                values[i] = (values[i] - ratio).max(0.0);
+                values[i - 1] += 1.0;
+            }
+        }
+
+        if base < 0.0 {
+            write!(f, "-")?;
+        }
+
+        let first = values[..last].iter().position(|value| *value != 0.0);
+        let first = first.unwrap_or(last);
+        for (i, (value, part)) in values.iter().zip(parts).enumerate().skip(first) {
+            if i > first {
+                write!(f, " ")?;
+            }
+            match i == last {
+                true => write!(f, "{value:.decimals$}")?,
+                false => write!(f, "{value}")?,
+            }
+
+            let unit = match f.alternate() {
+                false => part.symbol,
+                true if *value == 1.0 => part.name,
+                true => part.plural,
+            };
+            write!(f, " {unit}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The non-negative `value` rounded to the given number of decimals.
+fn round(value: f64, decimals: usize) -> f64 {
+    let scale = (0..decimals).fold(1.0, |scale, _| scale * 10.0);
+    match value * scale < EXACT_INTEGERS {
+        true => ((value * scale + 0.5) as u64) as f64 / scale,
+        false => value,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use crate::length::Length;
+    use crate::length::imperial::{Foot, Inch, Mile, Yard};
+    use crate::length::metric::{CentiMeter, Meter};
+    use crate::time::metric::{Day, Hour, Minute, Second};
+    use crate::volume::customary::{Cup, TableSpoon, TeaSpoon};
+    use crate::volume::metric::CubicMeter;
+
+    #[test]
+    fn split_across_units() {
+        let height = Foot(5.0) + &Inch(11.0);
+        assert_eq!(height.mixed::<(Foot, Inch)>().to_string(), "5 ft 11 in");
+
+        let distance = Mile(1.5);
+        assert_eq!(
+            distance.mixed::<(Mile, Yard, Foot)>().to_string(),
+            "1 mi 880 yd 0 ft"
+        );
+
+        let amount = CubicMeter(0.000_3);
+        assert_eq!(
+            amount.mixed::<(Cup, TableSpoon, TeaSpoon)>().to_string(),
+            "1 cup 4 tbsp 1 tsp"
+        );
+    }
+
+    #[test]
+    fn carry_rounding_over() {
+        let height = Foot(5.0) + &Inch(11.8);
+        assert_eq!(height.mixed::<(Foot, Inch)>().to_string(), "6 ft 0 in");
+        assert_eq!(
+            format!("{:.1}", height.mixed::<(Foot, Inch)>()),
+            "5 ft 11.8 in"
+        );
+
+        let duration = Second(86_399.7).mixed::<(Day, Hour, Minute, Second)>();
+        assert_eq!(duration.to_string(), "1 d 0 h 0 min 0 s");
+        assert_eq!(format!("{duration:.1}"), "23 h 59 min 59.7 s");
+
+        assert_eq!(
+            Meter(6.0 * 0.3048).mixed::<(Foot, Inch)>().to_string(),
+            "6 ft 0 in"
+        );
+    }
+
+    #[test]
+    fn omit_leading_zeros_and_keep_sign() {
+        let duration = Second(65.0).mixed::<(Hour, Minute, Second)>();
+        assert_eq!(duration.to_string(), "1 min 5 s");
+        assert_eq!(Second(0.0).mixed::<(Hour, Minute)>().to_string(), "0 min");
+
+        let depth = Meter(-1.8).mixed::<(Meter, CentiMeter)>();
+        assert_eq!(depth.to_string(), "-1 m 80 cm");
+    }
+
+    #[test]
+    fn display_unsplittable_values_in_last_unit() {
+        let nan = Second(f64::NAN).mixed::<(Hour, Minute, Second)>();
+        assert_eq!(nan.to_string(), "NaN s");
+        assert_eq!(format!("{nan:#}"), "NaN seconds");
+
+        let infinity = Meter(f64::NEG_INFINITY).mixed::<(Foot, Inch)>();
+        assert_eq!(infinity.to_string(), "-inf in");
+
+        let huge = Meter(1e300).mixed::<(Meter, CentiMeter)>();
+        assert_eq!(huge.to_string(), format!("{:.0} cm", 1e302));
+        let huge = Second(1e17).mixed::<(Hour, Minute, Second)>();
+        assert_eq!(format!("{huge:.1}"), "100000000000000000.0 s");
+
+        let large = Second(1e15).mixed::<(Hour, Second)>();
+        assert_eq!(large.to_string(), "277777777777 h 2800 s");
+    }
+
+    #[test]
+    fn display_names() {
+        let length: &dyn Length = &Foot(1.0);
+        let length = length.mixed::<(Foot, Inch)>();
+        assert_eq!(format!("{length:#}"), "1 foot 0 inches");
+
+        let duration = Second(3_601.0).mixed::<(Hour, Minute, Second)>();
+        assert_eq!(format!("{duration:#}"), "1 hour 0 minutes 1 second");
+    }
+}
@@ -0,0 +1,903 @@
+//! Parsing units from text.
+//!
+//! Values are parsed from a decimal number, optionally followed by the symbol
+//! of a unit (e.g. `"3.5 km"`, `"-40 °F"`, `"12ft"`). Every unit implements
+//! [`FromStr`](core::str::FromStr), converting values given in other units of
+//! its quantity, and each quantity module provides a `parse` function, which
+//! returns the value in the unit named by its symbol.
+//!
+//! Symbols are matched exactly, except that `^2` and `^3` may be written for
+//! `²` and `³`, and the greek letter `μ` for the micro sign `µ`. Values may
+//! also be given in a [`CompoundUnit`] expression of known units (e.g.
+//! `"60 mi/h"`, `"9.81 kg*m/s^2"`).
+//!
+//! Units also parse values given in several parts of the same quantity, which
+//! are summed (e.g. `"5 ft 11 in"`, `"2 lb 3 oz"`). The parts may follow each
+//! other without spaces, and in such values the shorthands `'` and `"` (feet
+//! and inches) and `m` (minutes, in durations) are accepted (e.g. `5'11"`,
+//! `1h30m15s`). Only the first part may be signed (e.g. `-5 ft 6 in`). For
+//! angles, `'` and `"` are arcminutes and arcseconds (e.g. `12°34'56.7"`).
+//!
+//! # Examples
+//! ```
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::Meter;
+//! use newnit::parse::ParseError;
+//!
+//! let length: Meter = "3.5 km".parse().unwrap();
+//! assert_eq!(length, Meter(3500.0));
+//!
+//! assert_eq!("12 in".parse::<Foot>(), Ok(Foot(1.0)));
+//! assert_eq!("3.5".parse::<Meter>(), Ok(Meter(3.5)));
+//!
+//! assert_eq!(
+//!     "3.5 parsnips".parse::<Meter>(),
+//!     Err(ParseError::UnknownUnit)
+//! );
+//! assert_eq!("3.5 kg".parse::<Meter>(), Err(ParseError::WrongQuantity));
+//! assert_eq!("many km".parse::<Meter>(), Err(ParseError::InvalidNumber));
+//!
+//! assert_eq!("5'11\"".parse::<Meter>(), Ok(Meter(1.8034)));
+//! assert_eq!(
+//!     "3 ft 2 kg".parse::<Meter>(),
+//!     Err(ParseError::MixedQuantities)
+//! );
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use newnit::{Unit, length};
+//!
+//! let length = length::parse::<f64>("12 ft").unwrap();
+//! assert!((length.to_base() - 3.6576).abs() < 1e-9);
+//! assert_eq!(length.to_value(), 12.0);
+//! # }
+//! ```
+
+mod expression;
+
+pub use expression::CompoundUnit;
+
+use core::fmt;
+
+use crate::Quantity;
+use crate::dimension::Dimension;
+use crate::dyn_quantity::DynDimension;
+use crate::rational::Rational;
+use crate::registry::{self, Entry};
+use crate::scalar::Scalar;
+use crate::unit::{Conversion, Metadata, System};
+
+/// Error of parsing a unit from text.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseError {
+    /// The text doesn't start with a decimal number.
+    InvalidNumber,
+    /// The symbol isn't a symbol of any known unit.
+    UnknownUnit,
+    /// The symbol is of a unit of a different quantity.
+    WrongQuantity,
+    /// The unit expression is malformed, or its factor overflows.
+    InvalidExpression,
+    /// The parts of a value given in several parts are of different
+    /// quantities (e.g. `3 ft 2 kg`).
+    MixedQuantities,
+    /// The value is out of range of the numeric type storing it (e.g. `-3 m`
+    /// as a `Meter<u8>`).
+    OutOfRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber => write!(f, "Invalid number"),
+            Self::UnknownUnit => write!(f, "Unknown unit"),
+            Self::WrongQuantity => write!(f, "Unit of a different quantity"),
+            Self::InvalidExpression => write!(f, "Invalid unit expression"),
+            Self::MixedQuantities => write!(f, "Parts of different quantities"),
+            Self::OutOfRange => write!(f, "Value out of range"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Parse a value of the unit `U` from text.
+///
+/// A value given in another unit of the quantity is converted to `U`, a
+/// value without a unit is taken to be in `U`. Values given in several parts
+/// (e.g. `5 ft 11 in`, `5'11"`, `1h30m15s`) are summed, only the first part
+/// may be signed.
+///
+/// # Examples
+/// ```
+/// use newnit::length::imperial::Inch;
+/// use newnit::length::metric::MilliMeter;
+/// use newnit::parse::{ParseError, parse_unit};
+///
+/// let length: MilliMeter<i32> = parse_unit("1.5 m").unwrap();
+/// assert_eq!(length, MilliMeter(1_500));
+///
+/// assert_eq!(parse_unit("5'11\""), Ok(Inch(71.0)));
+/// assert_eq!(
+///     parse_unit::<Inch, f64>("3 ft 2 kg"),
+///     Err(ParseError::MixedQuantities)
+/// );
+/// ```
+pub fn parse_unit<U, V>(s: &str) -> Result<U, ParseError>
+where
+    U: Quantity<V> + Conversion,
+    V: Scalar,
+{
+    let exponents = <U::Dimension as Dimension>::EXPONENTS;
+    let (number, symbol, mut rest) = part(s)?;
+
+    if symbol.is_empty() {
+        return match rest.is_empty() {
+            true => V::try_from_rational(number)
+                .map(U::from_value)
+                .ok_or(ParseError::OutOfRange),
+            false => Err(ParseError::UnknownUnit),
+        };
+    }
+
+    // Shorthands only stand for units in values given in several parts (e.g.
+    // `5'11"`), a single `m` is always the metre.
+    let unit = match rest.is_empty() {
+        true => resolve(symbol)?,
+        false => resolve_as(symbol, exponents)?,
+    };
+    if unit.exponents != exponents
+        || unit.affine != <U::Dimension as Dimension>::AFFINE
+        || unit.difference != <U::Dimension as Dimension>::DIFFERENCE
+        || unit.angle != <U::Dimension as Dimension>::ANGLE
+        || unit.solid_angle != <U::Dimension as Dimension>::SOLID_ANGLE
+    {
+        return Err(ParseError::WrongQuantity);
+    }
+
+    let factor = unit
+        .factor
+        .checked_quotient(U::FACTOR)
+        .ok_or(ParseError::OutOfRange)?;
+    if rest.is_empty() {
+        let offset = unit
+            .offset
+            .checked_difference(U::OFFSET)
+            .and_then(|offset| offset.checked_quotient(U::FACTOR))
+            .ok_or(ParseError::OutOfRange)?;
+        return convert(number, factor, offset)
+            .map(U::from_value)
+            .ok_or(ParseError::OutOfRange);
+    }
+
+    // Points on an affine scale can't be summed.
+    if unit.affine {
+        return Err(ParseError::InvalidExpression);
+    }
+
+    // The sign of the first part is the sign of the whole value (e.g. `-5 ft 6
+    // in`), the other parts can't be signed (e.g. `5 ft -6 in`). The sign is
+    // taken from the text, as a first part of zero has none (e.g. `-0 ft 6
+    // in`). The sum is exact unless it overflows, approximated as a float
+    // otherwise.
+    let negative = s.trim_start().starts_with('-');
+    let mut exact = number.checked_product(factor);
+    let mut approx = number.to_f64() * factor.to_f64();
+    while !rest.is_empty() {
+        if rest.starts_with(['-', '+']) {
+            return Err(ParseError::InvalidNumber);
+        }
+        let (number, symbol, next) = part(rest)?;
+        if symbol.is_empty() {
+            return Err(ParseError::UnknownUnit);
+        }
+        let number = if negative { number.negated() } else { number };
+
+        let unit = resolve_as(symbol, exponents)?;
+        if unit.exponents != exponents
+            || unit.affine
+            || unit.difference != <U::Dimension as Dimension>::DIFFERENCE
+            || unit.angle != <U::Dimension as Dimension>::ANGLE
+            || unit.solid_angle != <U::Dimension as Dimension>::SOLID_ANGLE
+        {
+            return Err(ParseError::MixedQuantities);
+        }
+
+        let factor = unit
+            .factor
+            .checked_quotient(U::FACTOR)
+            .ok_or(ParseError::OutOfRange)?;
+        let value = number.checked_product(factor);
+        exact = exact
+            .zip(value)
+            .and_then(|(sum, value)| sum.checked_sum(value));
+        approx += number.to_f64() * factor.to_f64();
+        rest = next;
+    }
+
+    exact
+        .map_or_else(|| V::try_from_f64(approx), V::try_from_rational)
+        .map(U::from_value)
+        .ok_or(ParseError::OutOfRange)
+}
+
+/// Resolve the symbol of a known unit, or a compound unit expression.
+pub(crate) fn resolve(symbol: &str) -> Result<UnitInfo, ParseError> {
+    if let Some(unit) = find(symbol) {
+        return Ok(*unit);
+    }
+
+    let unit: CompoundUnit = symbol.parse()?;
+    Ok(UnitInfo {
+        symbol: "",
+        name: "",
+        plural: "",
+        system: System::Other,
+        exponents: unit.exponents(),
+        affine: false,
+        difference: false,
+        angle: unit.angle(),
+        solid_angle: unit.solid_angle(),
+        factor: unit.factor(),
+        offset: Rational::ZERO,
+    })
+}
+
+/// Resolve the symbol of a unit, preferring the unit of the dimension given by
+/// its `exponents` among the [`SHORTHANDS`].
+fn resolve_as(symbol: &str, exponents: [i32; 7]) -> Result<UnitInfo, ParseError> {
+    let unit = resolve(symbol);
+    if matches!(&unit, Ok(unit) if unit.exponents == exponents) {
+        return unit;
+    }
+
+    SHORTHANDS
+        .iter()
+        .filter(|(shorthand, _)| matches(symbol, shorthand))
+        .filter_map(|(_, symbol)| find(symbol))
+        .find(|unit| unit.exponents == exponents)
+        .map_or(unit, |unit| Ok(*unit))
+}
+
+/// Shorthand symbols used in compound notations (e.g. `5'11"`, `1h30m`), by
+/// the symbol of the unit they stand for.
+const SHORTHANDS: [(&str, &str); 5] = [
+    ("'", "ft"),
+    ("′", "ft"),
+    ("\"", "in"),
+    ("″", "in"),
+    ("m", "min"),
+];
+
+/// Maps `number` to `number * factor + offset`, exactly unless the
+/// computation overflows, or [`None`] if the result is out of range of `V`.
+pub(crate) fn convert<V: Scalar>(
+    number: Rational,
+    factor: Rational,
+    offset: Rational,
+) -> Option<V> {
+    match number.checked_product(factor) {
+        Some(value) => value.checked_sum(offset),
+        None => None,
+    }
+    .map_or_else(
+        || V::try_from_f64(number.to_f64() * factor.to_f64() + offset.to_f64()),
+        V::try_from_rational,
+    )
+}
+
+/// A unit known to the parsers.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct UnitInfo {
+    pub(crate) symbol: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) plural: &'static str,
+    pub(crate) system: System,
+    pub(crate) exponents: [i32; 7],
+    pub(crate) affine: bool,
+    pub(crate) difference: bool,
+    pub(crate) angle: i32,
+    pub(crate) solid_angle: i32,
+    pub(crate) factor: Rational,
+    pub(crate) offset: Rational,
+}
+
+impl UnitInfo {
+    /// Information about the unit `U` of values of type `V`.
+    pub(crate) const fn of<U: Quantity<V> + Conversion + Metadata, V>() -> Self {
+        Self {
+            symbol: U::SYMBOL,
+            name: U::NAME,
+            plural: U::PLURAL,
+            system: U::SYSTEM,
+            exponents: <U::Dimension as Dimension>::EXPONENTS,
+            affine: <U::Dimension as Dimension>::AFFINE,
+            difference: <U::Dimension as Dimension>::DIFFERENCE,
+            angle: <U::Dimension as Dimension>::ANGLE,
+            solid_angle: <U::Dimension as Dimension>::SOLID_ANGLE,
+            factor: U::FACTOR,
+            offset: U::OFFSET,
+        }
+    }
+
+    /// The dimension of the unit.
+    pub(crate) fn dimension(&self) -> DynDimension {
+        DynDimension {
+            exponents: self.exponents,
+            angle: self.angle,
+            solid_angle: self.solid_angle,
+            affine: self.affine,
+            difference: self.difference,
+        }
+    }
+}
+
+/// Find a known unit by its symbol or alias.
+pub(crate) fn find(symbol: &str) -> Option<&'static UnitInfo> {
+    registry::find_symbol(symbol).map(Entry::info)
+}
+
+/// The error of a symbol not matching any unit of a quantity.
+#[cfg(feature = "std")]
+pub(crate) fn mismatch(symbol: &str) -> ParseError {
+    match find(symbol) {
+        Some(_) => ParseError::WrongQuantity,
+        None => ParseError::UnknownUnit,
+    }
+}
+
+/// Split text into its leading number and the symbol following it.
+pub(crate) fn split(s: &str) -> Result<(Rational, &str), ParseError> {
+    let s = s.trim();
+    let (number, symbol) = s.split_at(number_length(s));
+    let number = parse_number(number).ok_or(ParseError::InvalidNumber)?;
+
+    Ok((number, symbol.trim_start()))
+}
+
+/// Split text into its leading number, the symbol following it and the rest
+/// of the text, which starts with the number of the next part (e.g. `"5 ft 11
+/// in"` into `5`, `"ft"` and `"11 in"`).
+fn part(s: &str) -> Result<(Rational, &str, &str), ParseError> {
+    let s = s.trim_start();
+    let (number, rest) = s.split_at(number_length(s));
+    let number = parse_number(number).ok_or(ParseError::InvalidNumber)?;
+
+    let rest = rest.trim_start();
+    let (symbol, rest) = rest.split_at(symbol_length(rest));
+
+    Ok((number, symbol.trim_end(), rest.trim_start()))
+}
+
+/// Length of the symbol at the start of `s`, which ends where a number starts,
+/// unless the number is an exponent or a factor of a unit expression (e.g.
+/// `m^2`, `1/s`). A number starts with its sign after whitespace (e.g. `ft -6
+/// in`).
+fn symbol_length(s: &str) -> usize {
+    let digit = |s: &str| s.starts_with(|c: char| c.is_ascii_digit());
+    let mut previous = None;
+    for (i, c) in s.char_indices() {
+        let number = c.is_ascii_digit() || (c == '.' && digit(&s[i + 1..]));
+        let signed = matches!(c, '-' | '+')
+            && s[..i].ends_with(char::is_whitespace)
+            && (digit(&s[i + 1..]) || s[i + 1..].starts_with('.'));
+        let operand = matches!(
+            previous,
+            Some('^' | '*' | '/' | '(' | '-' | '+' | '·' | '⋅')
+        );
+        if (number || signed) && !operand {
+            return i;
+        }
+        if !c.is_whitespace() {
+            previous = Some(c);
+        }
+    }
+
+    s.len()
+}
+
+/// Length of the decimal number at the start of `s`.
+fn number_length(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits = |from: usize| {
+        from + bytes
+            .iter()
+            .skip(from)
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let mut end = digits(usize::from(matches!(bytes.first(), Some(b'+' | b'-'))));
+    if bytes.get(end) == Some(&b'.') {
+        end = digits(end + 1);
+    }
+
+    // An exponent must have digits, otherwise the `e` starts the symbol.
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent = digits(end + 1 + sign);
+        if exponent > end + 1 + sign {
+            end = exponent;
+        }
+    }
+
+    end
+}
+
+/// Exact value of a decimal number (e.g. `-3.5`, `1e-3`).
+fn parse_number(s: &str) -> Option<Rational> {
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (s, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let mut numer: i128 = 0;
+    for digit in integer.bytes().chain(fraction.bytes()) {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        numer = numer
+            .checked_mul(10)?
+            .checked_add(i128::from(digit - b'0'))?;
+    }
+    let exponent = exponent.checked_sub(i32::try_from(fraction.len()).ok()?)?;
+
+    Rational::checked_new(if negative { -numer } else { numer }, 1, exponent)
+}
+
+/// Whether `input` names the unit with the given `symbol`.
+pub(crate) fn matches(input: &str, symbol: &str) -> bool {
+    normalized(input).eq(normalized(symbol))
+}
+
+/// Characters of a symbol with alternative spellings replaced.
+fn normalized(symbol: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = symbol.chars().peekable();
+    core::iter::from_fn(move || {
+        let next = chars.next()?;
+        Some(match (next, chars.peek()) {
+            ('^', Some('2')) => {
+                chars.next();
+                '²'
+            }
+            ('^', Some('3')) => {
+                chars.next();
+                '³'
+            }
+            ('μ', _) => 'µ',
+            _ => next,
+        })
+    })
+}
+
+/// Declare the units of a quantity known to the parsers.
+///
+/// Defines the constant `$units` listing the [`registry`] entries of the
+/// units, the function `$parse` parsing a value in any of them and the enum
+/// `$enum` of the units (see [`RuntimeUnit`](crate::runtime::RuntimeUnit)).
+///
+/// Units are listed by their module, optionally with the name of their variant
+/// (e.g. `Mile => NauticalMile`, defaulting to the name of the unit).
+macro_rules! units {
+    (
+        $(#[$attr:meta])*
+        $parse:ident, $units:ident: $quantity:ident, $enum:ident;
+        $($module:ident::{
+            $($unit:ident $(=> $variant:ident)?),* $(,)?
+        }),* $(,)?
+    ) => {
+        $crate::parse::units! {
+            @variants [$(#[$attr])* $parse, $units, $quantity, $enum] []
+            $($($module::$unit $(=> $variant)?;)*)*
+        }
+    };
+
+    // Normalize the units to `(module unit variant)`.
+    (
+        @variants $header:tt [$($units:tt)*]
+        $module:ident::$unit:ident => $variant:ident;
+        $($rest:tt)*
+    ) => {
+        $crate::parse::units! {
+            @variants $header [$($units)* ($module $unit $variant)]
+            $($rest)*
+        }
+    };
+
+    (
+        @variants $header:tt [$($units:tt)*]
+        $module:ident::$unit:ident;
+        $($rest:tt)*
+    ) => {
+        $crate::parse::units! {
+            @variants $header [$($units)* ($module $unit $unit)]
+            $($rest)*
+        }
+    };
+
+    (
+        @variants [$(#[$attr:meta])* $parse:ident, $units:ident, $quantity:ident, $enum:ident]
+        [$(($module:ident $unit:ident $variant:ident))*]
+    ) => {
+        /// Predefined units of the quantity.
+        pub(crate) const $units: &[$crate::registry::Entry] = &[$(
+            $crate::registry::Entry::of::<$module::$unit, f64>($crate::registry::Kind::$quantity),
+        )*];
+
+        $(#[$attr])*
+        #[cfg(feature = "std")]
+        pub fn $parse<V: $crate::scalar::Scalar + 'static>(
+            s: &str,
+        ) -> Result<Box<dyn $quantity<V>>, $crate::parse::ParseError> {
+            let (number, symbol) = $crate::parse::split(s)?;
+            let value = V::try_from_rational(number).ok_or($crate::parse::ParseError::OutOfRange)?;
+
+            $units
+                .iter()
+                .find(|entry| entry.has_symbol(symbol))
+                .and_then(|entry| entry.boxed(value))
+                .ok_or_else(|| $crate::parse::mismatch(symbol))
+        }
+
+        #[doc = concat!("Units of ", stringify!($quantity), ", selectable at runtime.")]
+        ///
+        /// See the [`runtime`](crate::runtime) module.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[non_exhaustive]
+        pub enum $enum {
+            $(
+                #[doc = concat!(
+                    "[`", stringify!($unit), "`](", stringify!($module), "::", stringify!($unit), ")"
+                )]
+                $variant,
+            )*
+        }
+
+        impl $enum {
+            /// All units, in the order of the registry.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Convert `value` from the unit `from` to the unit `to`.
+            pub fn convert(value: f64, from: Self, to: Self) -> f64 {
+                $crate::runtime::convert(value, from, to)
+            }
+
+            /// The registry entry of the unit.
+            pub fn entry(self) -> &'static $crate::registry::Entry {
+                &$units[self as usize]
+            }
+        }
+
+        impl $crate::runtime::RuntimeUnit for $enum {
+            type Dimension = $crate::parse::units!(@first $($module::$unit)*);
+
+            const ALL: &'static [Self] = Self::ALL;
+
+            fn entry(self) -> &'static $crate::registry::Entry {
+                self.entry()
+            }
+        }
+
+        impl core::fmt::Display for $enum {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::runtime::fmt_unit(*self, f)
+            }
+        }
+
+        impl core::str::FromStr for $enum {
+            type Err = $crate::parse::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::runtime::parse_unit(s)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl $crate::serde::Serialize for $enum {
+            fn serialize<S: $crate::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde::serialize_runtime_unit(*self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> $crate::serde::Deserialize<'de> for $enum {
+            fn deserialize<D: $crate::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                $crate::serde::deserialize_runtime_unit(deserializer)
+            }
+        }
+    };
+
+    (@first $module:ident::$unit:ident $($rest:tt)*) => {
+        <$module::$unit as $crate::Quantity>::Dimension
+    };
+}
+
+pub(crate) use units;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::angle::metric::{ArcMinute, ArcSecond, Degree, Radian};
+    use crate::area::metric::SquareMeter;
+    use crate::assert_quantity_eq;
+    use crate::charge::metric::Coulomb;
+    use crate::energy::metric::Joule;
+    use crate::force::metric::Newton;
+    use crate::illuminance::imperial::FootCandle;
+    use crate::illuminance::metric::Lux;
+    use crate::length::imperial::{Foot, Inch};
+    use crate::length::metric::{KiloMeter, Meter};
+    use crate::luminance::metric::Nit;
+    use crate::luminous_energy::metric::LumenSecond;
+    use crate::luminous_flux::metric::Lumen;
+    use crate::luminous_intensity::metric::Candela;
+    use crate::mass::imperial::Ounce;
+    use crate::power::metric::KiloWatt;
+    use crate::pressure::metric::{Atmosphere, BarGauge, KiloPascalGauge, Pascal};
+    use crate::resistance::metric::Ohm;
+    use crate::solid_angle::metric::Steradian;
+    use crate::temperature::{Celsius, DeltaCelsius, Fahrenheit};
+    use crate::time::metric::{Minute, Second};
+    use crate::velocity::imperial::MilePerHour;
+    use crate::velocity::metric::MeterPerSecond;
+    use crate::voltage::metric::Volt;
+    use crate::volume::metric::CubicMeter;
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(parse_number("3.5"), Some(Rational::new(35, 1, -1)));
+        assert_eq!(parse_number("-.5e3"), Some(Rational::integer(-500)));
+        assert_eq!(parse_number("+12E-2"), Some(Rational::new(12, 1, -2)));
+        assert_eq!(parse_number("."), None);
+        assert_eq!(parse_number("1e"), None);
+        assert_eq!(parse_number("10e2147483647"), None);
+    }
+
+    #[test]
+    fn split_number_and_symbol() {
+        assert_eq!(split(" 3.5 km "), Ok((Rational::new(35, 1, -1), "km")));
+        assert_eq!(split("12ft"), Ok((Rational::integer(12), "ft")));
+        assert_eq!(split("2e3 em"), Ok((Rational::integer(2_000), "em")));
+        assert_eq!(split("2 em"), Ok((Rational::integer(2), "em")));
+        assert_eq!(split("km"), Err(ParseError::InvalidNumber));
+    }
+
+    #[test]
+    fn parse_and_convert() {
+        assert_eq!("3.5 km".parse(), Ok(Meter(3_500.0)));
+        assert_eq!("12 ft".parse(), Ok(Meter(3.6576)));
+        assert_eq!("3.5".parse(), Ok(KiloMeter(3.5)));
+        assert_eq!("36 in".parse(), Ok(Foot(3.0)));
+        assert_eq!("1 ft".parse(), Ok(Inch(12_i32)));
+        assert_eq!("1000 L".parse(), Ok(CubicMeter(1.0)));
+        assert_eq!("2 m^3".parse(), Ok(CubicMeter(2.0)));
+    }
+
+    #[test]
+    fn parse_compound_units() {
+        assert_eq!("60 mi/h".parse(), Ok(MilePerHour(60.0)));
+        assert_eq!("3.6 km/h".parse(), Ok(MeterPerSecond(1.0)));
+        assert_eq!("2 m·m·m".parse(), Ok(CubicMeter(2.0)));
+        assert_eq!("3 kg·m/s^2".parse(), Ok(Newton(3.0)));
+        assert_eq!("2 N·m".parse(), Ok(Joule(2.0)));
+        assert_eq!("1 kJ/s".parse(), Ok(KiloWatt(1.0)));
+        assert_eq!("3 A·s".parse(), Ok(Coulomb(3.0)));
+        assert_eq!("6 W/A".parse(), Ok(Volt(6.0)));
+        assert_eq!("2 V/A".parse(), Ok(Ohm(2.0)));
+        assert_eq!("3 kg/s".parse::<Meter>(), Err(ParseError::WrongQuantity));
+        assert_eq!("3 m/".parse::<Meter>(), Err(ParseError::InvalidExpression));
+    }
+
+    #[test]
+    fn parse_compound_notations() {
+        assert_eq!("5'11\"".parse(), Ok(Inch(71.0)));
+        assert_eq!("5′ 11″".parse(), Ok(Inch(71.0)));
+        assert_eq!("5 ft 11 in".parse(), Ok(Meter(1.8034)));
+        assert_eq!("-5 ft 6 in".parse(), Ok(Foot(-5.5)));
+        assert_eq!("1h30m15s".parse(), Ok(Second(5_415)));
+        assert_eq!("1 h 30 min".parse(), Ok(Minute(90.0)));
+        let mass: Ounce = "2 lb 3 oz".parse().unwrap();
+        assert_quantity_eq!(mass, Ounce(35.0));
+        assert_eq!("1 m² 5000 cm^2".parse(), Ok(SquareMeter(1.5)));
+        assert_eq!("1h 5m".parse(), Ok(Minute(65.0)));
+
+        // Shorthands don't stand for units in values given in one part.
+        assert_eq!("5 m".parse::<Second>(), Err(ParseError::WrongQuantity));
+        assert_eq!("5m".parse::<Minute>(), Err(ParseError::WrongQuantity));
+    }
+
+    #[test]
+    fn reject_signed_parts() {
+        assert_eq!("5 ft -6 in".parse::<Foot>(), Err(ParseError::InvalidNumber));
+        assert_eq!("5 ft +6 in".parse::<Foot>(), Err(ParseError::InvalidNumber));
+        assert_eq!("2 m^-1".parse::<Meter>(), Err(ParseError::WrongQuantity));
+    }
+
+    #[test]
+    fn sign_of_first_part() {
+        assert_eq!("-5 ft 6 in".parse(), Ok(Inch(-66.0)));
+        assert_eq!("-0 ft 6 in".parse(), Ok(Inch(-6.0)));
+        assert_eq!("-0h30m".parse(), Ok(Minute(-30.0)));
+    }
+
+    #[test]
+    fn reject_mixed_quantities() {
+        assert_eq!(
+            "3 ft 2 kg".parse::<Foot>(),
+            Err(ParseError::MixedQuantities)
+        );
+        assert_eq!("2 kg 3 ft".parse::<Foot>(), Err(ParseError::WrongQuantity));
+        assert_eq!("5 ft 11".parse::<Foot>(), Err(ParseError::UnknownUnit));
+        assert_eq!(
+            "20 °C 5 °C".parse::<Celsius>(),
+            Err(ParseError::InvalidExpression)
+        );
+    }
+
+    #[test]
+    fn parse_temperatures() {
+        assert_eq!("-40 °F".parse(), Ok(Celsius(-40.0)));
+        assert_eq!("100 °C".parse(), Ok(Fahrenheit(212.0)));
+        assert_eq!("9 Δ°F".parse(), Ok(DeltaCelsius(5.0)));
+        assert_eq!(
+            "5 °C".parse::<DeltaCelsius>(),
+            Err(ParseError::WrongQuantity)
+        );
+    }
+
+    #[test]
+    fn parse_pressures() {
+        assert_eq!("1013.25 hPa".parse(), Ok(Atmosphere(1.0)));
+        assert_eq!("2 N/m^2".parse(), Ok(Pascal(2.0)));
+        assert_eq!("1.5 barg".parse(), Ok(KiloPascalGauge(150.0)));
+        assert_eq!("150 kPa(g)".parse(), Ok(BarGauge(1.5)));
+        assert_eq!(
+            "1.5 bar".parse::<BarGauge>(),
+            Err(ParseError::WrongQuantity)
+        );
+    }
+
+    #[test]
+    fn parse_photometric_quantities() {
+        assert_eq!("3 cd·sr".parse(), Ok(Lumen(3.0)));
+        assert_eq!("300 lm/m²".parse(), Ok(Lux(300.0)));
+        assert_eq!("2 lm·s".parse(), Ok(LumenSecond(2.0)));
+        assert_eq!("6 lm/sr".parse(), Ok(Candela(6.0)));
+        assert_eq!("5 cd/m^2".parse(), Ok(Nit(5.0)));
+        let illuminance: FootCandle = "1 lm/ft²".parse().unwrap();
+        assert_quantity_eq!(illuminance, FootCandle(1.0));
+
+        // Solid angles set apart quantities of the same SI dimension.
+        assert_eq!("3 lm".parse::<Candela>(), Err(ParseError::WrongQuantity));
+        assert_eq!("3 cd".parse::<Lumen>(), Err(ParseError::WrongQuantity));
+        assert_eq!("2 lx".parse::<Nit>(), Err(ParseError::WrongQuantity));
+        assert_eq!(
+            "1 lm 2 cd".parse::<Lumen>(),
+            Err(ParseError::MixedQuantities)
+        );
+    }
+
+    #[test]
+    fn parse_angles() {
+        let angle: Degree = "12°34'56.7\"".parse().unwrap();
+        assert!((angle.0 - 12.582_416_666_666_667).abs() < 1e-9);
+        assert_eq!("1° 30′".parse(), Ok(ArcSecond(5_400.0)));
+        assert_eq!("90 deg".parse::<Degree>(), Ok(Degree(90.0)));
+
+        // The shorthands remain feet and inches for lengths.
+        assert_eq!("5'11\"".parse(), Ok(Inch(71.0)));
+
+        // Plane and solid angles set apart quantities of the same SI dimension.
+        assert_eq!("3 rad".parse::<Steradian>(), Err(ParseError::WrongQuantity));
+        assert_eq!("3 sr".parse::<Radian>(), Err(ParseError::WrongQuantity));
+        assert_quantity_eq!("3'".parse::<Radian>().unwrap(), ArcMinute(3.0));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("km".parse::<Meter>(), Err(ParseError::InvalidNumber));
+        assert_eq!(
+            "10e2147483647 m".parse::<Meter>(),
+            Err(ParseError::InvalidNumber)
+        );
+        assert_eq!(
+            "3.5 lightsabers".parse::<Meter>(),
+            Err(ParseError::UnknownUnit)
+        );
+        assert_eq!("3.5 s".parse::<Meter>(), Err(ParseError::WrongQuantity));
+    }
+
+    #[test]
+    fn parse_out_of_range() {
+        assert_eq!("-3 m".parse::<Meter<u8>>(), Err(ParseError::OutOfRange));
+        assert_eq!("300".parse::<Meter<u8>>(), Err(ParseError::OutOfRange));
+        assert_eq!("0.3 km".parse::<Meter<u8>>(), Err(ParseError::OutOfRange));
+        assert_eq!("0.25 km".parse::<Meter<u8>>(), Ok(Meter(250)));
+        assert_eq!(
+            "200 ft 200 in".parse::<Inch<u8>>(),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!("-40 °F".parse::<Celsius<i8>>(), Ok(Celsius(-40)));
+        assert_eq!(
+            "-500 °F".parse::<Celsius<i8>>(),
+            Err(ParseError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn parse_overflowing_factors() {
+        assert_eq!("1 au^4/m^3".parse::<Foot>(), Err(ParseError::OutOfRange));
+        assert_eq!("1 nmi^5/au^4".parse::<Foot>(), Err(ParseError::OutOfRange));
+        assert_eq!(
+            "1 ft 1 au^4/m^3".parse::<Foot>(),
+            Err(ParseError::OutOfRange)
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn parse_out_of_range_decimals() {
+        use rust_decimal::Decimal;
+
+        assert_eq!(
+            "1e30 m".parse::<Meter<Decimal>>(),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!(
+            "1e20 km".parse::<Meter<Decimal>>(),
+            Ok(Meter(Decimal::from(10_u128.pow(23))))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_quantities() {
+        let length = crate::length::parse::<f64>("3.5 km").unwrap();
+        assert_eq!(length.to_value(), 3.5);
+        assert_eq!(length.to_base(), 3_500.0);
+
+        let mass = crate::mass::parse::<f64>("2 t").unwrap();
+        assert_eq!(mass.to_base(), 2_000.0);
+
+        let temperature = crate::temperature::parse::<f64>("25 °C").unwrap();
+        assert_quantity_eq!(*temperature, Celsius(25.0));
+
+        let energy = crate::energy::parse::<f64>("3.5 kWh").unwrap();
+        assert_eq!(energy.to_base(), 12_600_000.0);
+        assert_eq!(crate::force::parse::<f64>("1 lbf").unwrap().symbol(), "lbf");
+        let power = crate::power::parse::<f64>("150 hp").unwrap();
+        assert_quantity_eq!(*power, KiloWatt(111.854_980_737_340_53));
+        let charge = crate::charge::parse::<f64>("2.5 Ah").unwrap();
+        assert_eq!(charge.to_base(), 9_000.0);
+        assert_eq!(
+            crate::resistance::parse::<f64>("4.7 kΩ").unwrap().to_base(),
+            4_700.0
+        );
+
+        assert!(matches!(
+            crate::length::parse::<f64>("3 kg"),
+            Err(ParseError::WrongQuantity)
+        ));
+        assert!(matches!(
+            crate::time::parse::<f64>("3"),
+            Err(ParseError::UnknownUnit)
+        ));
+    }
+}
@@ -0,0 +1,438 @@
+use core::str::FromStr;
+
+use super::{ParseError, find};
+use crate::Quantity;
+use crate::dimension::Dimension;
+use crate::dyn_quantity::{DimensionError, DynDimension, DynQuantity};
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::unit::{Conversion, affine};
+
+/// SI prefixes and the exponents of their powers of ten.
+const PREFIXES: [(&str, i32); 25] = [
+    ("Q", 30),
+    ("R", 27),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("da", 1),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("µ", -6),
+    ("μ", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+    ("r", -27),
+    ("q", -30),
+];
+
+/// Superscript digits, by their value.
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// A unit given by an expression of other units (e.g. `kg*m/s^2`).
+///
+/// The expression consists of symbols of units, optionally with SI prefixes
+/// (e.g. `hm`), multiplied (`*` or `·`) or divided (`/`), with integer
+/// exponents (`^2`, `**2` or `²`) and parentheses. Units on an affine scale
+/// (e.g. `°C`) stand for their intervals, as usual in compound units.
+///
+/// The unit is resolved to its factor of conversion to base units and its
+/// dimension, which can be checked against the dimensions of units. Plane and
+/// solid angles (e.g. `rad` in `rad/s`, `sr` in `lm/sr`) are tracked along
+/// with the dimension, as in [`WithAngle`](crate::dimension::WithAngle) and
+/// [`WithSolidAngle`](crate::dimension::WithSolidAngle).
+///
+/// # Examples
+/// ```
+/// use newnit::dimension;
+/// use newnit::parse::CompoundUnit;
+/// use newnit::velocity::metric::MeterPerSecond;
+///
+/// let unit: CompoundUnit = "mi/h".parse().unwrap();
+/// assert!(unit.is::<dimension::Velocity>());
+/// assert_eq!(unit.convert(1.0), Ok(MeterPerSecond(0.44704)));
+///
+/// let unit: CompoundUnit = "kg/(m²·K)".parse().unwrap();
+/// assert_eq!(unit.exponents(), [-2, 1, 0, 0, -1, 0, 0]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CompoundUnit {
+    factor: Rational,
+    exponents: [i32; 7],
+    angle: i32,
+    solid_angle: i32,
+}
+
+impl CompoundUnit {
+    /// The dimensionless unit one.
+    pub const ONE: Self = Self {
+        factor: Rational::ONE,
+        exponents: [0; 7],
+        angle: 0,
+        solid_angle: 0,
+    };
+
+    /// The factor of conversion of the unit to base units.
+    pub fn factor(&self) -> Rational {
+        self.factor
+    }
+
+    /// Exponents of the dimension of the unit, in the order of
+    /// [`Dimension::EXPONENTS`].
+    pub fn exponents(&self) -> [i32; 7] {
+        self.exponents
+    }
+
+    /// Exponent of plane angle of the unit, as in [`Dimension::ANGLE`].
+    pub fn angle(&self) -> i32 {
+        self.angle
+    }
+
+    /// Exponent of solid angle of the unit, as in [`Dimension::SOLID_ANGLE`].
+    pub fn solid_angle(&self) -> i32 {
+        self.solid_angle
+    }
+
+    /// The dimension of the unit.
+    pub fn dimension(&self) -> DynDimension {
+        DynDimension {
+            angle: self.angle,
+            solid_angle: self.solid_angle,
+            ..DynDimension::new(self.exponents)
+        }
+    }
+
+    /// Whether the unit is of the dimension `D`.
+    pub fn is<D: Dimension>(&self) -> bool {
+        self.dimension() == DynDimension::of::<D>()
+    }
+
+    /// A quantity of `value` in this unit.
+    pub fn quantity<V: Scalar>(&self, value: V) -> DynQuantity<V> {
+        DynQuantity::with_dimension(value.scale(self.factor), self.dimension())
+    }
+
+    /// Convert `value` in this unit to the unit `U`, if it is of the same
+    /// dimension.
+    pub fn convert<U, V>(&self, value: V) -> Result<U, DimensionError>
+    where
+        U: Quantity<V> + Conversion,
+        V: Scalar,
+    {
+        match self.is::<U::Dimension>() {
+            true => {
+                let offset = U::OFFSET.negated().quotient(U::FACTOR);
+                let value = match self.factor.checked_quotient(U::FACTOR) {
+                    Some(factor) => affine(value, factor, offset),
+                    // Factors too far apart to divide exactly are applied
+                    // as floats.
+                    None => V::from_f64(
+                        value.to_f64() * self.factor.to_f64() / U::FACTOR.to_f64()
+                            + offset.to_f64(),
+                    ),
+                };
+                Ok(U::from_value(value))
+            }
+            false => Err(DimensionError {
+                expected: DynDimension::of::<U::Dimension>(),
+                found: self.dimension(),
+            }),
+        }
+    }
+
+    /// The product of two units, or [`None`] if its factor overflows.
+    fn product(self, rhs: Self) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for (exponent, rhs) in exponents.iter_mut().zip(rhs.exponents) {
+            *exponent = exponent.checked_add(rhs)?;
+        }
+
+        Some(Self {
+            factor: self.factor.checked_product(rhs.factor)?,
+            exponents,
+            angle: self.angle.checked_add(rhs.angle)?,
+            solid_angle: self.solid_angle.checked_add(rhs.solid_angle)?,
+        })
+    }
+
+    /// The unit raised to the integer power `n`, or [`None`] if its factor
+    /// overflows.
+    fn powi(self, n: i32) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for exponent in exponents.iter_mut() {
+            *exponent = exponent.checked_mul(n)?;
+        }
+
+        let mut factor = Rational::ONE;
+        let mut base = match n < 0 {
+            true => self.factor.reciprocal(),
+            false => self.factor,
+        };
+        let mut power = n.unsigned_abs();
+        while power > 0 {
+            if power % 2 == 1 {
+                factor = factor.checked_product(base)?;
+            }
+            power /= 2;
+            if power > 0 {
+                base = base.checked_product(base)?;
+            }
+        }
+
+        Some(Self {
+            factor,
+            exponents,
+            angle: self.angle.checked_mul(n)?,
+            solid_angle: self.solid_angle.checked_mul(n)?,
+        })
+    }
+}
+
+impl FromStr for CompoundUnit {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s.trim() };
+        let unit = parser.expression()?;
+
+        match parser.input.is_empty() {
+            true => Ok(unit),
+            false => Err(ParseError::InvalidExpression),
+        }
+    }
+}
+
+/// Recursive descent parser of unit expressions.
+struct Parser<'a> {
+    /// The rest of the input.
+    input: &'a str,
+}
+
+impl Parser<'_> {
+    /// expression = power, { ( "*" | "·" | "⋅" | "/" ), power }
+    fn expression(&mut self) -> Result<CompoundUnit, ParseError> {
+        let mut unit = self.power()?;
+
+        loop {
+            let rhs = if self.eat(['*', '·', '⋅']) {
+                self.power()?
+            } else if self.eat(['/']) {
+                self.power()?
+                    .powi(-1)
+                    .ok_or(ParseError::InvalidExpression)?
+            } else {
+                return Ok(unit);
+            };
+
+            unit = unit.product(rhs).ok_or(ParseError::InvalidExpression)?;
+        }
+    }
+
+    /// power = primary, [ ( "^" | "**" ), integer | superscript integer ]
+    fn power(&mut self) -> Result<CompoundUnit, ParseError> {
+        let unit = self.primary()?;
+
+        let exponent = if self.eat(['^']) || self.eat_str("**") {
+            self.integer(|c| c.to_digit(10), ['-'])?
+        } else if self
+            .input
+            .starts_with(|c| superscript(c).is_some() || c == '⁻')
+        {
+            self.integer(superscript, ['⁻'])?
+        } else {
+            return Ok(unit);
+        };
+
+        unit.powi(exponent).ok_or(ParseError::InvalidExpression)
+    }
+
+    /// primary = "(", expression, ")" | symbol
+    fn primary(&mut self) -> Result<CompoundUnit, ParseError> {
+        if self.eat(['(']) {
+            let unit = self.expression()?;
+            return match self.eat([')']) {
+                true => Ok(unit),
+                false => Err(ParseError::InvalidExpression),
+            };
+        }
+
+        let end = self
+            .input
+            .find(|c: char| "*·⋅/^()⁻".contains(c) || superscript(c).is_some())
+            .unwrap_or(self.input.len());
+        let (symbol, rest) = self.input.split_at(end);
+        self.input = rest.trim_start();
+
+        match symbol.trim() {
+            "" => Err(ParseError::InvalidExpression),
+            "1" => Ok(CompoundUnit::ONE),
+            symbol => resolve_symbol(symbol).ok_or(ParseError::UnknownUnit),
+        }
+    }
+
+    /// An integer with the given digits and minus signs.
+    fn integer<const N: usize>(
+        &mut self,
+        digit: impl Fn(char) -> Option<u32>,
+        minus: [char; N],
+    ) -> Result<i32, ParseError> {
+        let negative = self.eat(minus);
+        let end = self
+            .input
+            .find(|c| digit(c).is_none())
+            .unwrap_or(self.input.len());
+        let (digits, rest) = self.input.split_at(end);
+        self.input = rest.trim_start();
+
+        if digits.is_empty() {
+            return Err(ParseError::InvalidExpression);
+        }
+
+        let value = digits
+            .chars()
+            .filter_map(&digit)
+            .try_fold(0_i32, |value, digit| {
+                value.checked_mul(10)?.checked_add(digit as i32)
+            })
+            .ok_or(ParseError::InvalidExpression)?;
+
+        Ok(if negative { -value } else { value })
+    }
+
+    /// Consume one of the characters at the start of the input, if present.
+    fn eat<const N: usize>(&mut self, chars: [char; N]) -> bool {
+        match self.input.strip_prefix(chars) {
+            Some(rest) => {
+                self.input = rest.trim_start();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consume the string at the start of the input, if present.
+    fn eat_str(&mut self, prefix: &str) -> bool {
+        match self.input.strip_prefix(prefix) {
+            Some(rest) => {
+                self.input = rest.trim_start();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The value of a superscript digit.
+fn superscript(c: char) -> Option<u32> {
+    SUPERSCRIPTS
+        .iter()
+        .position(|superscript| *superscript == c)
+        .map(|digit| digit as u32)
+}
+
+/// Resolve the symbol of a known unit, optionally with an SI prefix.
+fn resolve_symbol(symbol: &str) -> Option<CompoundUnit> {
+    let unit = |factor, info: &super::UnitInfo| CompoundUnit {
+        factor,
+        exponents: info.exponents,
+        angle: info.angle,
+        solid_angle: info.solid_angle,
+    };
+
+    if let Some(info) = find(symbol) {
+        return Some(unit(info.factor, info));
+    }
+
+    PREFIXES.iter().find_map(|(prefix, exponent)| {
+        let info = find(symbol.strip_prefix(prefix)?)?;
+        let factor = info
+            .factor
+            .checked_product(Rational::new(1, 1, *exponent))?;
+        Some(unit(factor, info))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::force::metric::Newton;
+    use crate::length::imperial::Foot;
+    use crate::quantity::Derived;
+    use crate::velocity::metric::{KiloMeterPerHour, MeterPerSecond};
+    use crate::{assert_quantity_eq, dimension};
+
+    fn parse(s: &str) -> CompoundUnit {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_products_and_quotients() {
+        assert!(parse("kg*m/s^2").is::<dimension::Force>());
+        assert!(parse("kg·m·s⁻²").is::<dimension::Force>());
+        assert!(parse("m/s/s").is::<dimension::Acceleration>());
+        assert_eq!(parse("km/h").factor(), Rational::new(1, 36, 1));
+        assert_eq!(parse("1/s").exponents(), [0, 0, -1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn parse_exponents() {
+        assert_eq!(parse("ft²"), parse("ft^2"));
+        assert_eq!(parse("ft**2"), parse("ft*ft"));
+        assert_eq!(parse("ft^2").factor(), Rational::new(9290304, 1, -8));
+        assert_eq!(parse("m^-1"), parse("1/m"));
+        assert_eq!(parse("s⁻¹"), parse("1/s"));
+    }
+
+    #[test]
+    fn parse_parentheses() {
+        let unit = parse("kg/(m²·K)");
+        assert_eq!(unit.exponents(), [-2, 1, 0, 0, -1, 0, 0]);
+        assert_eq!(parse("(m/s)^2"), parse("m^2/s^2"));
+        assert_eq!(parse("°C"), parse("K"));
+    }
+
+    #[test]
+    fn parse_prefixes() {
+        assert_eq!(parse("hm").factor(), Rational::integer(100));
+        assert_eq!(parse("dam").factor(), Rational::integer(10));
+        assert_eq!(parse("dam").exponents(), parse("m").exponents());
+        assert_eq!(parse("kmol/m³").factor(), Rational::integer(1_000));
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        let parse = |s: &str| s.parse::<CompoundUnit>();
+        assert_eq!(parse("kg*"), Err(ParseError::InvalidExpression));
+        assert_eq!(parse("(m/s"), Err(ParseError::InvalidExpression));
+        assert_eq!(parse("m^"), Err(ParseError::InvalidExpression));
+        assert_eq!(parse("m/s)"), Err(ParseError::InvalidExpression));
+        assert_eq!(parse("m/parsnip"), Err(ParseError::UnknownUnit));
+    }
+
+    #[test]
+    fn convert_to_units() {
+        let unit = parse("m/min");
+        assert_eq!(unit.convert(60.0), Ok(MeterPerSecond(1.0)));
+        assert_eq!(unit.convert(1_000_i32), Ok(KiloMeterPerHour(60)));
+
+        let force: Derived<dimension::Force> = parse("g*cm/s^2").convert(1e5).unwrap();
+        assert_quantity_eq!(force, Newton(1.0));
+
+        assert!(unit.convert::<Derived<dimension::Time>, f64>(1.0).is_err());
+
+        let length: Foot = parse("au^4/m^3").convert(1.0).unwrap();
+        assert!((length.0 / 1.643_185_8e45 - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,143 @@
+//! Units of power.
+//!
+//! This module contains predefined newtypes for units of power as defined in
+//! the following systems:
+//! - [`imperial`] - British Imperial units
+//! - [`metric`] - International System of Units (SI)
+//!
+//! The base used for all systems is the (metric) watt.
+
+use crate::current::Current;
+use crate::current::metric::Ampere;
+use crate::energy::metric::Joule;
+use crate::force::Force;
+use crate::force::metric::Newton;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::velocity::Velocity;
+use crate::velocity::metric::MeterPerSecond;
+use crate::voltage::Voltage;
+use crate::voltage::metric::Volt;
+use crate::{Quantity, dimension, parse};
+
+pub mod imperial;
+pub mod metric;
+
+/// Types that are units of power.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+pub trait Power<V: Scalar = f64>: Quantity<V, Dimension = dimension::Power> {
+    /// Multiply a unit of power with a unit of time.
+    fn multiply_time(&self, rhs: &dyn Time<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
+
+    /// Divide a unit of power by a unit of current.
+    fn divide_current(&self, rhs: &dyn Current<V>) -> Volt<V> {
+        Volt(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of power by a unit of voltage.
+    fn divide_voltage(&self, rhs: &dyn Voltage<V>) -> Ampere<V> {
+        Ampere(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of power by a unit of velocity.
+    fn divide_velocity(&self, rhs: &dyn Velocity<V>) -> Newton<V> {
+        Newton(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of power by a unit of force.
+    fn divide_force(&self, rhs: &dyn Force<V>) -> MeterPerSecond<V> {
+        MeterPerSecond(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Power>> Power<V> for T {}
+
+parse::units! {
+    /// Parse a power in the unit named by its symbol (e.g. `"150 hp"`).
+    parse, UNITS: Power, PowerUnit;
+    metric::{
+        QuettaWatt,
+        RonnaWatt,
+        YottaWatt,
+        ZettaWatt,
+        ExaWatt,
+        PetaWatt,
+        TeraWatt,
+        GigaWatt,
+        MegaWatt,
+        KiloWatt,
+        Watt,
+        DeciWatt,
+        CentiWatt,
+        MilliWatt,
+        MicroWatt,
+        NanoWatt,
+        PicoWatt,
+        FemtoWatt,
+        AttoWatt,
+        ZeptoWatt,
+        YoctoWatt,
+        RontoWatt,
+        QuectoWatt,
+        MetricHorsePower,
+    },
+    imperial::{
+        HorsePower,
+        ElectricHorsePower,
+        BoilerHorsePower,
+        BtuPerHour,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::energy::metric::KiloWattHour;
+    use crate::time::metric::Minute;
+
+    #[test]
+    fn multiply_and_divide() {
+        let power = metric::KiloWatt(6.0_f64);
+        assert_quantity_eq!(power.multiply_time(&Minute(30.0)), KiloWattHour(3.0));
+        assert_eq!(
+            power.divide_velocity(&MeterPerSecond(3.0)).to_value(),
+            2000.0
+        );
+        assert_eq!(power.divide_force(&Newton(2000.0)).to_value(), 3.0);
+        assert_eq!(
+            MeterPerSecond(3.0)
+                .multiply_force(&Newton(2000.0))
+                .to_value(),
+            6000.0
+        );
+    }
+
+    #[test]
+    fn horsepower() {
+        assert_quantity_eq!(
+            imperial::HorsePower(100.0_f64),
+            metric::KiloWatt(74.569_987_158_227_02)
+        );
+        assert_quantity_eq!(
+            imperial::BtuPerHour(3600.0_f64),
+            metric::Watt(1_055.055_852_62)
+        );
+    }
+
+    #[test]
+    fn metric_horsepower() {
+        let power = metric::Watt::from(&metric::MetricHorsePower(100.0_f64));
+        assert_eq!(power, metric::Watt(73_549.875));
+        assert!(metric::MetricHorsePower(1.0_f64) < imperial::HorsePower(1.0));
+        assert_eq!(
+            "75 hp(M)".parse::<metric::KiloWatt>().unwrap(),
+            metric::MetricHorsePower(75.0)
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! British Imperial units of power
+//!
+//! This module contains predefined newtypes for units of power used with the
+//! British Imperial system. The base unit is the watt.
+//!
+//! The horsepower (hp) is the mechanical horsepower of 550 ft·lbf/s.
+
+use super::Power;
+use crate::make_unit;
+use newnit_derive::{Power, Unit};
+
+make_unit!(
+    HorsePower,
+    "hp",
+    "horsepower",
+    "horsepower",
+    Imperial,
+    550 * 0.3048 * 0.453_592_37 * 9.80665,
+    Power
+);
+make_unit!(
+    ElectricHorsePower,
+    "hp(E)",
+    "electric horsepower",
+    "electric horsepower",
+    Imperial,
+    746.0,
+    Power
+);
+make_unit!(
+    BoilerHorsePower,
+    "hp(S)",
+    "boiler horsepower",
+    "boiler horsepower",
+    Imperial,
+    9809.5,
+    Power
+);
+make_unit!(
+    BtuPerHour,
+    "BTU/h",
+    "BTU per hour",
+    "BTUs per hour",
+    Imperial,
+    1055.05585262 / 3600,
+    Power
+);
@@ -0,0 +1,173 @@
+//! Metric units of power
+//!
+//! This module contains predefined newtypes for units of power as defined in
+//! the International System of Units (SI). The base unit is the watt.
+//!
+//! Additional definition provided for the metric horsepower (hp(M), also known
+//! as PS, a symbol taken by the petasiemens) = 735.49875 W.
+
+use super::Power;
+use crate::make_unit;
+use newnit_derive::{Power, Unit};
+
+make_unit!(
+    QuettaWatt,
+    "QW",
+    "quettawatt",
+    "quettawatts",
+    Metric,
+    1E+30,
+    Power
+);
+make_unit!(
+    RonnaWatt,
+    "RW",
+    "ronnawatt",
+    "ronnawatts",
+    Metric,
+    1E+27,
+    Power
+);
+make_unit!(
+    YottaWatt,
+    "YW",
+    "yottawatt",
+    "yottawatts",
+    Metric,
+    1E+24,
+    Power
+);
+make_unit!(
+    ZettaWatt,
+    "ZW",
+    "zettawatt",
+    "zettawatts",
+    Metric,
+    1E+21,
+    Power
+);
+make_unit!(ExaWatt, "EW", "exawatt", "exawatts", Metric, 1E+18, Power);
+make_unit!(
+    PetaWatt,
+    "PW",
+    "petawatt",
+    "petawatts",
+    Metric,
+    1E+15,
+    Power
+);
+make_unit!(
+    TeraWatt,
+    "TW",
+    "terawatt",
+    "terawatts",
+    Metric,
+    1E+12,
+    Power
+);
+make_unit!(GigaWatt, "GW", "gigawatt", "gigawatts", Metric, 1E+9, Power);
+make_unit!(MegaWatt, "MW", "megawatt", "megawatts", Metric, 1E+6, Power);
+make_unit!(KiloWatt, "kW", "kilowatt", "kilowatts", Metric, 1E+3, Power);
+make_unit!(Watt, "W", "watt", "watts", Metric, 1.0, Power);
+make_unit!(DeciWatt, "dW", "deciwatt", "deciwatts", Metric, 1E-1, Power);
+make_unit!(
+    CentiWatt,
+    "cW",
+    "centiwatt",
+    "centiwatts",
+    Metric,
+    1E-2,
+    Power
+);
+make_unit!(
+    MilliWatt,
+    "mW",
+    "milliwatt",
+    "milliwatts",
+    Metric,
+    1E-3,
+    Power
+);
+make_unit!(
+    MicroWatt,
+    "µW",
+    "microwatt",
+    "microwatts",
+    Metric,
+    1E-6,
+    Power
+);
+make_unit!(NanoWatt, "nW", "nanowatt", "nanowatts", Metric, 1E-9, Power);
+make_unit!(
+    PicoWatt,
+    "pW",
+    "picowatt",
+    "picowatts",
+    Metric,
+    1E-12,
+    Power
+);
+make_unit!(
+    FemtoWatt,
+    "fW",
+    "femtowatt",
+    "femtowatts",
+    Metric,
+    1E-15,
+    Power
+);
+make_unit!(
+    AttoWatt,
+    "aW",
+    "attowatt",
+    "attowatts",
+    Metric,
+    1E-18,
+    Power
+);
+make_unit!(
+    ZeptoWatt,
+    "zW",
+    "zeptowatt",
+    "zeptowatts",
+    Metric,
+    1E-21,
+    Power
+);
+make_unit!(
+    YoctoWatt,
+    "yW",
+    "yoctowatt",
+    "yoctowatts",
+    Metric,
+    1E-24,
+    Power
+);
+make_unit!(
+    RontoWatt,
+    "rW",
+    "rontowatt",
+    "rontowatts",
+    Metric,
+    1E-27,
+    Power
+);
+make_unit!(
+    QuectoWatt,
+    "qW",
+    "quectowatt",
+    "quectowatts",
+    Metric,
+    1E-30,
+    Power
+);
+
+make_unit!(
+    MetricHorsePower,
+    "hp(M)",
+    "metric horsepower",
+    "metric horsepower",
+    Metric,
+    735.49875,
+    Power
+);
@@ -0,0 +1,317 @@
+//! Units of pressure.
+//!
+//! This module contains predefined newtypes for units of pressure as defined
+//! in the following systems:
+//! - [`imperial`] - British Imperial units
+//! - [`metric`] - International System of Units (SI)
+//!
+//! The base used for all systems is the (metric) pascal.
+//!
+//! Units of [`Pressure`] measure absolute pressures (e.g. psia), which can't be
+//! negative. Units of [`GaugePressure`] (e.g. psig) measure pressures relative
+//! to the atmospheric pressure, as points on a scale like temperatures. The
+//! difference of two absolute or two gauge pressures is a
+//! [`PressureDifference`] (e.g. psid), which in turn can be added to or
+//! subtracted from either kind of pressure. As the atmospheric pressure
+//! varies, gauge and absolute pressures don't convert into each other, except
+//! with an explicit reference pressure of the atmosphere.
+//!
+//! # Examples
+//! ```
+//! use newnit::pressure::imperial::{
+//!     PoundPerSquareInch, PoundPerSquareInchDifferential,
+//!     PoundPerSquareInchGauge,
+//! };
+//! use newnit::pressure::metric::{
+//!     Atmosphere, BarGauge, KiloPascal, KiloPascalDifferential,
+//! };
+//! use newnit::pressure::{GaugePressure, Pressure};
+//!
+//! let tyre = PoundPerSquareInchGauge(32.0_f64);
+//! let absolute =
+//!     PoundPerSquareInch::from(&tyre.to_absolute(&Atmosphere(1.0)));
+//! assert!((absolute.0 - 46.695_95).abs() < 1e-5);
+//!
+//! let inflated = BarGauge::from(&tyre) + &KiloPascalDifferential(20.0);
+//! assert!((inflated.0 - 2.406_32).abs() < 1e-5);
+//!
+//! let drop: PoundPerSquareInchDifferential =
+//!     PoundPerSquareInch(60.0) - &PoundPerSquareInch(45.0);
+//! assert!((drop.0 - 15.0_f64).abs() < 1e-9);
+//!
+//! let gauge = KiloPascal(250.0).to_gauge(&KiloPascal(100.0));
+//! assert_eq!(BarGauge::from(&gauge), BarGauge(1.5));
+//! ```
+//!
+//! Mixing gauge and absolute pressures is a compile time error, both in
+//! conversions:
+//!
+//! ```compile_fail
+//! use newnit::pressure::imperial::{PoundPerSquareInch, PoundPerSquareInchGauge};
+//!
+//! let absolute = PoundPerSquareInch::from(&PoundPerSquareInchGauge(32.0));
+//! ```
+//!
+//! and in arithmetic:
+//!
+//! ```compile_fail
+//! use newnit::pressure::imperial::{PoundPerSquareInch, PoundPerSquareInchGauge};
+//!
+//! let sum = PoundPerSquareInchGauge(32.0) + &PoundPerSquareInch(14.7);
+//! ```
+
+use crate::area::Area;
+use crate::energy::metric::Joule;
+use crate::force::metric::Newton;
+use crate::scalar::Scalar;
+use crate::volume::Volume;
+use crate::{Quantity, dimension, parse};
+
+pub mod imperial;
+pub mod metric;
+
+/// Types that are units of (absolute) pressure.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication, and
+/// the conversion to gauge pressure.
+pub trait Pressure<V: Scalar = f64>: Quantity<V, Dimension = dimension::Pressure> {
+    /// Multiply a unit of pressure with a unit of area.
+    fn multiply_area(&self, rhs: &dyn Area<V>) -> Newton<V> {
+        Newton(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of pressure with a unit of volume.
+    fn multiply_volume(&self, rhs: &dyn Volume<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
+
+    /// The pressure as a gauge pressure, relative to the given (absolute)
+    /// pressure of the atmosphere.
+    fn to_gauge(&self, atmosphere: &dyn Pressure<V>) -> metric::PascalGauge<V> {
+        metric::PascalGauge(self.to_base() - atmosphere.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Pressure>> Pressure<V> for T {}
+
+/// Types that are units of differences of (absolute or gauge) pressures.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+pub trait PressureDifference<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::PressureDifference>
+{
+    /// Multiply a unit of pressure difference with a unit of area.
+    fn multiply_area(&self, rhs: &dyn Area<V>) -> Newton<V> {
+        Newton(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::PressureDifference>> PressureDifference<V>
+    for T
+{
+}
+
+/// Types that are units of gauge pressure, relative to the atmospheric
+/// pressure.
+pub trait GaugePressure<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::Affine<dimension::PressureDifference>>
+{
+    /// The pressure as an absolute pressure, given the (absolute) pressure of
+    /// the atmosphere.
+    fn to_absolute(&self, atmosphere: &dyn Pressure<V>) -> metric::Pascal<V> {
+        metric::Pascal(self.to_base() + atmosphere.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Affine<dimension::PressureDifference>>>
+    GaugePressure<V> for T
+{
+}
+
+parse::units! {
+    /// Parse an (absolute) pressure in the unit named by its symbol (e.g.
+    /// `"1013 hPa"`).
+    parse, UNITS: Pressure, PressureUnit;
+    metric::{
+        QuettaPascal,
+        RonnaPascal,
+        YottaPascal,
+        ZettaPascal,
+        ExaPascal,
+        PetaPascal,
+        TeraPascal,
+        GigaPascal,
+        MegaPascal,
+        KiloPascal,
+        HectoPascal,
+        Pascal,
+        DeciPascal,
+        CentiPascal,
+        MilliPascal,
+        MicroPascal,
+        NanoPascal,
+        PicoPascal,
+        FemtoPascal,
+        AttoPascal,
+        ZeptoPascal,
+        YoctoPascal,
+        RontoPascal,
+        QuectoPascal,
+        Bar,
+        MilliBar,
+        Atmosphere,
+        Torr,
+        MilliMeterOfMercury,
+    },
+    imperial::{
+        PoundPerSquareInch,
+        InchOfMercury,
+        InchOfWater,
+    },
+}
+
+parse::units! {
+    /// Parse a gauge pressure in the unit named by its symbol (e.g. `"32
+    /// psig"`).
+    parse_gauge, GAUGE_UNITS: GaugePressure, GaugePressureUnit;
+    metric::{
+        PascalGauge,
+        KiloPascalGauge,
+        BarGauge,
+    },
+    imperial::{
+        PoundPerSquareInchGauge,
+        InchOfWaterGauge,
+    },
+}
+
+parse::units! {
+    /// Parse a pressure difference in the unit named by its symbol (e.g. `"5
+    /// psid"`).
+    parse_difference, DIFFERENCE_UNITS: PressureDifference, PressureDifferenceUnit;
+    metric::{
+        PascalDifferential,
+        KiloPascalDifferential,
+        BarDifferential,
+    },
+    imperial::{
+        PoundPerSquareInchDifferential,
+        InchOfWaterDifferential,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::approx::Tolerance;
+    use crate::area::imperial::SquareInch;
+    #[cfg(feature = "std")]
+    use crate::area::metric::SquareMeter;
+    use crate::assert_quantity_eq;
+    use crate::checked::{Checked, ValueError};
+    use crate::force::Force;
+    use crate::force::imperial::PoundForce;
+    use crate::volume::metric::CubicMeter;
+
+    #[test]
+    fn convert_pressures() {
+        assert_quantity_eq!(metric::Atmosphere(1.0_f64), metric::HectoPascal(1013.25));
+        assert_quantity_eq!(
+            metric::Torr(760.0_f64),
+            metric::MilliMeterOfMercury(760.0),
+            Tolerance::Relative(1e-6)
+        );
+        assert_quantity_eq!(
+            metric::Atmosphere(1.0_f64),
+            imperial::InchOfMercury(29.921_25),
+            Tolerance::Relative(1e-6)
+        );
+    }
+
+    #[test]
+    fn force_per_area() {
+        let pressure = PoundForce(1.0_f64).divide_area(&SquareInch(1.0));
+        assert_quantity_eq!(pressure, imperial::PoundPerSquareInch(1.0));
+
+        let force = pressure.multiply_area(&SquareInch(2.0));
+        assert_quantity_eq!(force, PoundForce(2.0));
+        assert_quantity_eq!(SquareInch(2.0).multiply_pressure(&pressure), force);
+        assert_eq!(
+            metric::KiloPascal(2.0).multiply_volume(&CubicMeter(3.0)).0,
+            6000.0
+        );
+    }
+
+    #[test]
+    fn gauge_and_absolute() {
+        let atmosphere = metric::Atmosphere(1.0_f64);
+        let gauge = metric::BarGauge(2.0_f64);
+        let absolute = metric::Bar::from(&gauge.to_absolute(&atmosphere));
+        assert_quantity_eq!(absolute, metric::Bar(3.013_25));
+        assert_quantity_eq!(absolute.to_gauge(&atmosphere), metric::BarGauge(2.0));
+
+        let vacuum = metric::Pascal(0.0_f64).to_gauge(&atmosphere);
+        assert_eq!(vacuum, metric::KiloPascalGauge(-101.325));
+    }
+
+    #[test]
+    fn gauge_differences() {
+        let difference = metric::BarGauge(3.0_f64) - &metric::BarGauge(1.0);
+        assert_eq!(difference, metric::BarDifferential(2.0));
+
+        let mut gauge = imperial::PoundPerSquareInchGauge(30.0_f64)
+            + &imperial::PoundPerSquareInchDifferential(2.0);
+        gauge -= &metric::PascalDifferential(0.0);
+        assert_eq!(gauge, imperial::PoundPerSquareInchGauge(32.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn absolute_differences() {
+        let difference = metric::Bar(3.0_f64) - &metric::Bar(1.0);
+        assert_eq!(difference, metric::BarDifferential(2.0));
+        let difference = metric::Atmosphere(1.0_f64) - &metric::HectoPascal(1000.0);
+        assert_eq!(difference, metric::PascalDifferential(1325.0));
+
+        let mut absolute = metric::KiloPascal(100.0_f64) + &metric::BarDifferential(0.5);
+        assert_eq!(absolute, metric::KiloPascal(150.0));
+        absolute -= &metric::KiloPascalDifferential(20.0);
+        assert_eq!(absolute, metric::KiloPascal(130.0));
+        assert_eq!(
+            absolute - &metric::PascalDifferential(30_000.0),
+            metric::KiloPascal(100.0)
+        );
+
+        // Partial pressures add up to the total pressure.
+        let total = metric::KiloPascal(79.0_f64) + &metric::KiloPascal(21.0);
+        assert_eq!(total, metric::KiloPascal(100.0));
+
+        let force = metric::PascalDifferential(2.0_f64).multiply_area(&SquareInch(1.0));
+        assert_quantity_eq!(force, metric::Pascal(2.0).multiply_area(&SquareInch(1.0)));
+
+        let drop = metric::PascalDifferential(3.0_f64);
+        assert_eq!((drop * &drop).to_string(), "9 m^-2·kg^2·s^-4");
+        assert_eq!(f64::from(drop / &metric::PascalDifferential(1.5)), 2.0);
+        assert_eq!(f64::from(metric::Pascal(6.0) / &drop), 2.0);
+        assert_eq!((SquareMeter(2.0) * &drop).to_string(), "6 m·kg·s^-2");
+    }
+
+    #[test]
+    fn negative_absolute_pressures() {
+        assert_eq!(metric::Pascal::try_new(-5.0), Err(ValueError::BelowMinimum));
+        assert_eq!(
+            metric::Bar(1.0).checked_sub(&metric::BarDifferential(2.0)),
+            Err(ValueError::BelowMinimum)
+        );
+        assert_eq!(
+            metric::PascalDifferential::try_new(-5.0),
+            Ok(metric::PascalDifferential(-5.0))
+        );
+        assert_eq!(
+            metric::PascalGauge::try_new(-5.0),
+            Ok(metric::PascalGauge(-5.0))
+        );
+    }
+}
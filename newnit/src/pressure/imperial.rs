@@ -0,0 +1,90 @@
+//! British Imperial units of pressure
+//!
+//! This module contains predefined newtypes for units of (absolute) pressure,
+//! of gauge pressure and of pressure differences based on units of length and
+//! force defined in the British Imperial system. The base unit is the pascal.
+//!
+//! The inch of mercury (inHg) and the inch of water (inH₂O) are the
+//! conventional units of 25.4 mmHg and 25.4 mm of water at 4 °C respectively.
+
+use super::{GaugePressure, Pressure, PressureDifference};
+use crate::make_unit;
+use newnit_derive::{GaugePressure, Pressure, PressureDifference, Unit};
+
+make_unit!(
+    #[unit(alias = "psia")]
+    PoundPerSquareInch,
+    "psi",
+    "pound per square inch",
+    "pounds per square inch",
+    Imperial,
+    0.453_592_37 * 9.80665 / (0.0254 * 0.0254),
+    0.0,
+    Pressure,
+    PoundPerSquareInchDifferential
+);
+make_unit!(
+    InchOfMercury,
+    "inHg",
+    "inch of mercury",
+    "inches of mercury",
+    Imperial,
+    25.4 * 133.322_387_415,
+    Pressure
+);
+make_unit!(
+    #[unit(alias = "inH2O")]
+    InchOfWater,
+    "inH₂O",
+    "inch of water",
+    "inches of water",
+    Imperial,
+    0.0254 * 1000 * 9.80665,
+    0.0,
+    Pressure,
+    InchOfWaterDifferential
+);
+
+make_unit!(
+    PoundPerSquareInchGauge,
+    "psig",
+    "pound per square inch gauge",
+    "pounds per square inch gauge",
+    Imperial,
+    0.453_592_37 * 9.80665 / (0.0254 * 0.0254),
+    0.0,
+    GaugePressure,
+    PoundPerSquareInchDifferential
+);
+make_unit!(
+    #[unit(alias = "inH2O(g)")]
+    InchOfWaterGauge,
+    "inH₂O(g)",
+    "inch of water gauge",
+    "inches of water gauge",
+    Imperial,
+    0.0254 * 1000 * 9.80665,
+    0.0,
+    GaugePressure,
+    InchOfWaterDifferential
+);
+
+make_unit!(
+    PoundPerSquareInchDifferential,
+    "psid",
+    "pound per square inch differential",
+    "pounds per square inch differential",
+    Imperial,
+    0.453_592_37 * 9.80665 / (0.0254 * 0.0254),
+    PressureDifference
+);
+make_unit!(
+    #[unit(alias = "inH2O(d)")]
+    InchOfWaterDifferential,
+    "inH₂O(d)",
+    "inch of water differential",
+    "inches of water differential",
+    Imperial,
+    0.0254 * 1000 * 9.80665,
+    PressureDifference
+);
@@ -0,0 +1,348 @@
+//! Metric units of pressure
+//!
+//! This module contains predefined newtypes for units of (absolute) pressure
+//! as defined in the International System of Units (SI), of gauge pressure
+//! relative to the atmospheric pressure, and of pressure differences. The base
+//! unit is the pascal.
+//!
+//! Additional definitions provided for the bar = 1E+5 Pa, the standard
+//! atmosphere (atm) = 101 325 Pa, the torr = 1/760 atm and the (conventional)
+//! millimetre of mercury (mmHg) = 133.322387415 Pa.
+
+use super::{GaugePressure, Pressure, PressureDifference};
+use crate::make_unit;
+use newnit_derive::{GaugePressure, Pressure, PressureDifference, Unit};
+
+make_unit!(
+    QuettaPascal,
+    "QPa",
+    "quettapascal",
+    "quettapascals",
+    Metric,
+    1E+30,
+    Pressure
+);
+make_unit!(
+    RonnaPascal,
+    "RPa",
+    "ronnapascal",
+    "ronnapascals",
+    Metric,
+    1E+27,
+    Pressure
+);
+make_unit!(
+    YottaPascal,
+    "YPa",
+    "yottapascal",
+    "yottapascals",
+    Metric,
+    1E+24,
+    Pressure
+);
+make_unit!(
+    ZettaPascal,
+    "ZPa",
+    "zettapascal",
+    "zettapascals",
+    Metric,
+    1E+21,
+    Pressure
+);
+make_unit!(
+    ExaPascal,
+    "EPa",
+    "exapascal",
+    "exapascals",
+    Metric,
+    1E+18,
+    Pressure
+);
+make_unit!(
+    PetaPascal,
+    "PPa",
+    "petapascal",
+    "petapascals",
+    Metric,
+    1E+15,
+    Pressure
+);
+make_unit!(
+    TeraPascal,
+    "TPa",
+    "terapascal",
+    "terapascals",
+    Metric,
+    1E+12,
+    Pressure
+);
+make_unit!(
+    GigaPascal,
+    "GPa",
+    "gigapascal",
+    "gigapascals",
+    Metric,
+    1E+9,
+    Pressure
+);
+make_unit!(
+    MegaPascal,
+    "MPa",
+    "megapascal",
+    "megapascals",
+    Metric,
+    1E+6,
+    Pressure
+);
+make_unit!(
+    KiloPascal,
+    "kPa",
+    "kilopascal",
+    "kilopascals",
+    Metric,
+    1E+3,
+    0.0,
+    Pressure,
+    KiloPascalDifferential
+);
+make_unit!(
+    HectoPascal,
+    "hPa",
+    "hectopascal",
+    "hectopascals",
+    Metric,
+    1E+2,
+    Pressure
+);
+make_unit!(
+    Pascal,
+    "Pa",
+    "pascal",
+    "pascals",
+    Metric,
+    1.0,
+    0.0,
+    Pressure,
+    PascalDifferential
+);
+make_unit!(
+    DeciPascal,
+    "dPa",
+    "decipascal",
+    "decipascals",
+    Metric,
+    1E-1,
+    Pressure
+);
+make_unit!(
+    CentiPascal,
+    "cPa",
+    "centipascal",
+    "centipascals",
+    Metric,
+    1E-2,
+    Pressure
+);
+make_unit!(
+    MilliPascal,
+    "mPa",
+    "millipascal",
+    "millipascals",
+    Metric,
+    1E-3,
+    Pressure
+);
+make_unit!(
+    MicroPascal,
+    "µPa",
+    "micropascal",
+    "micropascals",
+    Metric,
+    1E-6,
+    Pressure
+);
+make_unit!(
+    NanoPascal,
+    "nPa",
+    "nanopascal",
+    "nanopascals",
+    Metric,
+    1E-9,
+    Pressure
+);
+make_unit!(
+    PicoPascal,
+    "pPa",
+    "picopascal",
+    "picopascals",
+    Metric,
+    1E-12,
+    Pressure
+);
+make_unit!(
+    FemtoPascal,
+    "fPa",
+    "femtopascal",
+    "femtopascals",
+    Metric,
+    1E-15,
+    Pressure
+);
+make_unit!(
+    AttoPascal,
+    "aPa",
+    "attopascal",
+    "attopascals",
+    Metric,
+    1E-18,
+    Pressure
+);
+make_unit!(
+    ZeptoPascal,
+    "zPa",
+    "zeptopascal",
+    "zeptopascals",
+    Metric,
+    1E-21,
+    Pressure
+);
+make_unit!(
+    YoctoPascal,
+    "yPa",
+    "yoctopascal",
+    "yoctopascals",
+    Metric,
+    1E-24,
+    Pressure
+);
+make_unit!(
+    RontoPascal,
+    "rPa",
+    "rontopascal",
+    "rontopascals",
+    Metric,
+    1E-27,
+    Pressure
+);
+make_unit!(
+    QuectoPascal,
+    "qPa",
+    "quectopascal",
+    "quectopascals",
+    Metric,
+    1E-30,
+    Pressure
+);
+
+make_unit!(
+    #[unit(alias = "bara")]
+    Bar,
+    "bar",
+    "bar",
+    "bars",
+    Metric,
+    1E+5,
+    0.0,
+    Pressure,
+    BarDifferential
+);
+make_unit!(
+    MilliBar,
+    "mbar",
+    "millibar",
+    "millibars",
+    Metric,
+    1E+2,
+    Pressure
+);
+make_unit!(
+    Atmosphere,
+    "atm",
+    "atmosphere",
+    "atmospheres",
+    Metric,
+    101_325.0,
+    Pressure
+);
+make_unit!(
+    Torr,
+    "Torr",
+    "torr",
+    "torrs",
+    Metric,
+    101_325 / 760,
+    Pressure
+);
+make_unit!(
+    MilliMeterOfMercury,
+    "mmHg",
+    "millimetre of mercury",
+    "millimetres of mercury",
+    Metric,
+    133.322_387_415,
+    Pressure
+);
+
+make_unit!(
+    PascalGauge,
+    "Pa(g)",
+    "pascal gauge",
+    "pascals gauge",
+    Metric,
+    1.0,
+    0.0,
+    GaugePressure,
+    PascalDifferential
+);
+make_unit!(
+    KiloPascalGauge,
+    "kPa(g)",
+    "kilopascal gauge",
+    "kilopascals gauge",
+    Metric,
+    1E+3,
+    0.0,
+    GaugePressure,
+    KiloPascalDifferential
+);
+make_unit!(
+    #[unit(alias = "bar(g)")]
+    BarGauge,
+    "barg",
+    "bar gauge",
+    "bars gauge",
+    Metric,
+    1E+5,
+    0.0,
+    GaugePressure,
+    BarDifferential
+);
+
+make_unit!(
+    PascalDifferential,
+    "Pa(d)",
+    "pascal differential",
+    "pascals differential",
+    Metric,
+    1.0,
+    PressureDifference
+);
+make_unit!(
+    KiloPascalDifferential,
+    "kPa(d)",
+    "kilopascal differential",
+    "kilopascals differential",
+    Metric,
+    1E+3,
+    PressureDifference
+);
+make_unit!(
+    #[unit(alias = "bar(d)")]
+    BarDifferential,
+    "bard",
+    "bar differential",
+    "bars differential",
+    Metric,
+    1E+5,
+    PressureDifference
+);
@@ -0,0 +1,502 @@
+//! Quantity trait binding units to their dimension.
+//!
+//! Every unit of this library implements [`Quantity`], which assigns it a
+//! [`Dimension`]. The quantity traits (e.g. [`Length`], [`Mass`]) are
+//! implemented for all units of their respective dimension, so they work as
+//! aliases for the dimensions.
+//!
+//! Multiplying or dividing two units yields a [`Derived`] unit of the product
+//! or quotient dimension, expressed in base units. Iterators of derived units
+//! sum up, and those of dimensionless ones (e.g. ratios) also multiply up
+//! ([`Product`](core::iter::Product)). Iterators of other units deliberately
+//! don't multiply up, as the dimension of their product depends on the number
+//! of items.
+//!
+//! # Examples
+//! ```
+//! use newnit::Unit;
+//! use newnit::length::metric::Meter;
+//! use newnit::mass::metric::KiloGram;
+//! use newnit::quantity::Derived;
+//! use newnit::time::metric::Second;
+//! use newnit::velocity::metric::MeterPerSecond;
+//! use newnit::{dimension, velocity::Velocity};
+//!
+//! let speed = Meter(6.0) / &Second(2.0);
+//! assert_eq!(MeterPerSecond::from(&speed), MeterPerSecond(3.0));
+//!
+//! let force: Derived<dimension::Force> =
+//!     KiloGram(2.0) * &Meter(3.0) / &(Second(1.0) * &Second(2.0));
+//! assert_eq!(force.to_value(), 3.0);
+//! ```
+//!
+//! Mismatched dimensions are a compile time error:
+//!
+//! ```compile_fail
+//! use newnit::dimension;
+//! use newnit::length::metric::Meter;
+//! use newnit::quantity::Derived;
+//! use newnit::time::metric::Second;
+//!
+//! let force: Derived<dimension::Force> = Meter(6.0) / &Second(2.0);
+//! ```
+//!
+//! [`Length`]: crate::length::Length
+//! [`Mass`]: crate::mass::Mass
+
+use core::fmt;
+use core::iter::{Product as IterProduct, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::Unit;
+use crate::dimension::{
+    Affine, BaseUnits, Difference, Dim, Dimension, Dimensionless, Product, Quotient,
+};
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::unit::Conversion;
+
+/// A unit of a quantity of a given [`Dimension`].
+///
+/// Rather than implementing this trait manually, use one of the quantity
+/// derive macros from the [`derive`](crate::derive) module.
+pub trait Quantity<V = f64>: Unit<V> {
+    /// The dimension of the quantity measured by this unit.
+    type Dimension: Dimension;
+
+    /// The least physically possible value of the quantity in base units, if
+    /// any.
+    ///
+    /// Defaults to the minimum of the dimension ([`Dimension::MINIMUM`]).
+    /// Absolute pressures can't be negative either, unlike other quantities of
+    /// their dimension (e.g. stresses, which may be compressive).
+    fn minimum() -> Option<Rational>
+    where
+        Self: Sized,
+    {
+        <Self::Dimension as Dimension>::MINIMUM
+    }
+}
+
+/// Operands of subtraction from points of dimension `D` on a scale.
+///
+/// Subtracting a point `P` from another point yields its interval `I`, while
+/// subtracting an interval from a point yields a point `P`. The trait is
+/// implemented for the dimensions of the right hand side operand, so that both
+/// cases can be told apart by the type system. Points are either on an affine
+/// scale (e.g. temperatures), with intervals of the dimension the scale wraps,
+/// or quantities that can't be negative (e.g. absolute pressures), with
+/// intervals of their [`Difference`].
+pub trait AffineOperand<D, P, I> {
+    /// The unit of the difference.
+    type Difference;
+
+    /// The difference of a value `point` of the point `P` and a value `other`
+    /// of the operand `R`, as a value of the unit of the difference.
+    ///
+    /// The operand is converted to the unit of the point directly (with the
+    /// offsets only if it is a point on an affine scale), so that no value
+    /// passes through base units.
+    fn difference<R: Conversion, V: Scalar>(point: V, other: V) -> V;
+}
+
+impl<D, P: Conversion, I: Conversion> AffineOperand<Affine<D>, P, I> for Affine<D> {
+    type Difference = I;
+
+    fn difference<R: Conversion, V: Scalar>(point: V, other: V) -> V {
+        I::convert_difference_from::<P, V>(point - P::convert_from::<R, V>(other))
+    }
+}
+
+impl<D, P: Conversion, I> AffineOperand<Affine<D>, P, I> for D {
+    type Difference = P;
+
+    fn difference<R: Conversion, V: Scalar>(point: V, other: V) -> V {
+        point - P::convert_difference_from::<R, V>(other)
+    }
+}
+
+impl<L, M, T, C, Th, N, J, P: Conversion, I: Conversion>
+    AffineOperand<Dim<L, M, T, C, Th, N, J>, P, I> for Dim<L, M, T, C, Th, N, J>
+{
+    type Difference = I;
+
+    // Quantities that can't be negative have no offsets, so both are converted
+    // to the unit of the difference directly.
+    fn difference<R: Conversion, V: Scalar>(point: V, other: V) -> V {
+        I::convert_difference_from::<P, V>(point) - I::convert_difference_from::<R, V>(other)
+    }
+}
+
+impl<L, M, T, C, Th, N, J, P: Conversion, I> AffineOperand<Dim<L, M, T, C, Th, N, J>, P, I>
+    for Difference<Dim<L, M, T, C, Th, N, J>>
+{
+    type Difference = P;
+
+    fn difference<R: Conversion, V: Scalar>(point: V, other: V) -> V {
+        point - P::convert_difference_from::<R, V>(other)
+    }
+}
+
+/// Right hand side operands of multiplication of the unit `U` storing `V`.
+///
+/// Units can be multiplied by their scalar type (resulting in the same unit)
+/// or by a reference to any other unit (resulting in a [`Derived`] unit of the
+/// product dimension).
+pub trait Factor<U, V> {
+    /// The unit of the product.
+    type Output;
+
+    /// Multiply `unit` by `factor`.
+    fn multiply(unit: U, factor: Self) -> Self::Output;
+}
+
+/// Right hand side operands of division of the unit `U` storing `V`.
+///
+/// Units can be divided by their scalar type (resulting in the same unit) or
+/// by a reference to any other unit (resulting in a [`Derived`] unit of the
+/// quotient dimension).
+pub trait Divisor<U, V> {
+    /// The unit of the quotient.
+    type Output;
+
+    /// Divide `unit` by `divisor`.
+    fn divide(unit: U, divisor: Self) -> Self::Output;
+}
+
+impl<U, R, V> Factor<U, V> for &R
+where
+    U: Quantity<V>,
+    U::Dimension: Mul<R::Dimension>,
+    R: Quantity<V> + ?Sized,
+    V: Scalar,
+{
+    type Output = Derived<Product<U::Dimension, R::Dimension>, V>;
+
+    fn multiply(unit: U, factor: Self) -> Self::Output {
+        Derived::new(unit.to_base() * factor.to_base())
+    }
+}
+
+impl<U, R, V> Divisor<U, V> for &R
+where
+    U: Quantity<V>,
+    U::Dimension: Div<R::Dimension>,
+    R: Quantity<V> + ?Sized,
+    V: Scalar,
+{
+    type Output = Derived<Quotient<U::Dimension, R::Dimension>, V>;
+
+    fn divide(unit: U, divisor: Self) -> Self::Output {
+        Derived::new(unit.to_base() / divisor.to_base())
+    }
+}
+
+// Scalars are foreign types, so the impls have to be provided for each type
+// separately (a blanket impl would overlap with the impls for references).
+macro_rules! impl_scalar_operands {
+    ($($scalar:ty),*) => {
+        $(
+            impl<U: Unit<$scalar>> Factor<U, $scalar> for $scalar {
+                type Output = U;
+
+                fn multiply(mut unit: U, factor: Self) -> Self::Output {
+                    unit.set_value(unit.to_value() * factor);
+                    unit
+                }
+            }
+
+            impl<U: Unit<$scalar>> Divisor<U, $scalar> for $scalar {
+                type Output = U;
+
+                fn divide(mut unit: U, divisor: Self) -> Self::Output {
+                    unit.set_value(unit.to_value() / divisor);
+                    unit
+                }
+            }
+
+            impl<D> Mul<Derived<D, $scalar>> for $scalar {
+                type Output = Derived<D, $scalar>;
+
+                fn mul(self, rhs: Derived<D, $scalar>) -> Self::Output {
+                    Derived::new(self * rhs.0)
+                }
+            }
+
+            impl From<Derived<Dimensionless, $scalar>> for $scalar {
+                fn from(value: Derived<Dimensionless, $scalar>) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_operands!(f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+#[cfg(feature = "rust_decimal")]
+impl_scalar_operands!(rust_decimal::Decimal);
+
+#[cfg(feature = "num-rational")]
+impl_scalar_operands!(num_rational::Ratio<i32>, num_rational::Ratio<i64>);
+
+/// A unit derived by multiplication or division of other units.
+///
+/// The wrapped value is expressed in the coherent base unit of the dimension
+/// `D` (e.g. kg·m·s⁻² for force), so it converts to any other unit of the same
+/// dimension.
+pub struct Derived<D, V = f64>(V, PhantomData<D>);
+
+impl<D, V> Derived<D, V> {
+    /// Create a derived unit from its value in base units.
+    pub fn new(value: V) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<D, V: Clone> Clone for Derived<D, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<D, V: Copy> Copy for Derived<D, V> {}
+
+impl<D, V: Default> Default for Derived<D, V> {
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+impl<D, V: PartialEq> PartialEq for Derived<D, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<D, V: PartialOrd> PartialOrd for Derived<D, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<D: Dimension, V: fmt::Debug> fmt::Debug for Derived<D, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Derived")
+            .field(&self.0)
+            .field(&D::EXPONENTS)
+            .finish()
+    }
+}
+
+impl<D: Dimension, V: fmt::Display> fmt::Display for Derived<D, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+
+        match BaseUnits::of::<D>() {
+            units if units.is_one() => Ok(()),
+            units => write!(f, " {units}"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<D: Dimension, V: defmt::Format> defmt::Format for Derived<D, V> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Derived({}, {})", self.0, D::EXPONENTS);
+    }
+}
+
+impl<D, V: Scalar> Unit<V> for Derived<D, V> {
+    fn to_base(&self) -> V {
+        self.0
+    }
+
+    fn to_value(&self) -> V {
+        self.0
+    }
+
+    fn set_value(&mut self, value: V) {
+        self.0 = value;
+    }
+
+    fn set_from_base(&mut self, base: V) {
+        self.0 = base;
+    }
+
+    fn from_base(base: V) -> Self {
+        Self::new(base)
+    }
+}
+
+impl<D, V> Conversion for Derived<D, V> {
+    const FACTOR: Rational = Rational::ONE;
+    const OFFSET: Rational = Rational::ZERO;
+}
+
+impl<D: Dimension, V: Scalar> Quantity<V> for Derived<D, V> {
+    type Dimension = D;
+}
+
+impl<D: Dimension, V: Scalar, T: Quantity<V, Dimension = D>> From<&T> for Derived<D, V> {
+    fn from(other: &T) -> Self {
+        Self::new(other.to_base())
+    }
+}
+
+impl<D: Dimension, V: Scalar, T: Quantity<V, Dimension = D>> Add<&T> for Derived<D, V> {
+    type Output = Self;
+
+    fn add(self, other: &T) -> Self::Output {
+        Self::new(self.0 + other.to_base())
+    }
+}
+
+impl<D: Dimension, V: Scalar, T: Quantity<V, Dimension = D>> AddAssign<&T> for Derived<D, V> {
+    fn add_assign(&mut self, other: &T) {
+        self.0 = self.0 + other.to_base();
+    }
+}
+
+impl<D: Dimension, V: Scalar, T: Quantity<V, Dimension = D>> Sub<&T> for Derived<D, V> {
+    type Output = Self;
+
+    fn sub(self, other: &T) -> Self::Output {
+        Self::new(self.0 - other.to_base())
+    }
+}
+
+impl<D: Dimension, V: Scalar, T: Quantity<V, Dimension = D>> SubAssign<&T> for Derived<D, V> {
+    fn sub_assign(&mut self, other: &T) {
+        self.0 = self.0 - other.to_base();
+    }
+}
+
+impl<D, V: Scalar, R: Factor<Self, V>> Mul<R> for Derived<D, V> {
+    type Output = R::Output;
+
+    fn mul(self, rhs: R) -> Self::Output {
+        R::multiply(self, rhs)
+    }
+}
+
+impl<D, V: Scalar> MulAssign<V> for Derived<D, V> {
+    fn mul_assign(&mut self, rhs: V) {
+        self.0 = self.0 * rhs;
+    }
+}
+
+impl<D, V: Scalar, R: Divisor<Self, V>> Div<R> for Derived<D, V> {
+    type Output = R::Output;
+
+    fn div(self, rhs: R) -> Self::Output {
+        R::divide(self, rhs)
+    }
+}
+
+impl<D, V: Scalar> DivAssign<V> for Derived<D, V> {
+    fn div_assign(&mut self, rhs: V) {
+        self.0 = self.0 / rhs;
+    }
+}
+
+impl<D, V: Scalar + Neg<Output = V>> Neg for Derived<D, V> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.0)
+    }
+}
+
+impl<D, V: Scalar> Sum for Derived<D, V> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.fold(V::default(), |sum, unit| sum + unit.0))
+    }
+}
+
+impl<'a, D: 'a, V: Scalar> Sum<&'a Self> for Derived<D, V> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.fold(V::default(), |sum, unit| sum + unit.0))
+    }
+}
+
+/// Only dimensionless units multiply into a unit of the same dimension.
+impl<V: Scalar> IterProduct for Derived<Dimensionless, V> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let one = V::from_rational(Rational::ONE);
+        Self::new(iter.fold(one, |product, unit| product * unit.0))
+    }
+}
+
+impl<'a, V: Scalar> IterProduct<&'a Self> for Derived<Dimensionless, V> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let one = V::from_rational(Rational::ONE);
+        Self::new(iter.fold(one, |product, unit| product * unit.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::metric::SquareMeter;
+    use crate::assert_quantity_eq;
+    use crate::dimension;
+    use crate::force::metric::Newton;
+    use crate::length::imperial::Foot;
+    use crate::length::metric::Meter;
+    use crate::mass::metric::KiloGram;
+    use crate::time::metric::{Minute, Second};
+    use crate::velocity::metric::MeterPerSecond;
+
+    #[test]
+    fn divide_length_by_time() {
+        let velocity = Meter(120.0_f64) / &Minute(1.0);
+        assert_quantity_eq!(MeterPerSecond::from(&velocity), MeterPerSecond(2.0));
+    }
+
+    #[test]
+    fn multiply_into_force() {
+        let force: Derived<dimension::Force> =
+            KiloGram(3.0) * &Meter(2.0) / &(Second(2.0) * &Second(1.0));
+        assert_quantity_eq!(force, Newton(3.0));
+    }
+
+    #[test]
+    fn divide_same_quantity_is_dimensionless() {
+        let ratio: Derived<Dimensionless> = Meter(0.6096) / &Foot(1.0);
+        assert_quantity_eq!(ratio, Derived::new(2.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn multiply_with_trait_object() {
+        let length: Box<dyn crate::length::Length> = Box::new(Foot(10.0));
+        let area = Meter(2.0) * &*length;
+        assert_quantity_eq!(area, SquareMeter(6.096));
+    }
+
+    #[test]
+    fn multiply_single_precision() {
+        let area = Meter(2.0_f32) * &Meter(3.0_f32) * 2.0;
+        assert_eq!(area.to_value(), 12.0_f32);
+    }
+
+    #[test]
+    fn sum_and_multiply_derived() {
+        let areas = [Meter(2.0) * &Meter(3.0), Foot(1.0) * &Meter(1.0)];
+        let area: Derived<dimension::Area> = areas.iter().sum();
+        assert_quantity_eq!(area, SquareMeter(6.3048));
+
+        let ratios = [Meter(3.0) / &Meter(2.0), Foot(1.0) / &Meter(0.1524)];
+        let ratio = ratios.into_iter().product::<Derived<Dimensionless>>();
+        assert_quantity_eq!(ratio, Derived::new(3.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_derived() {
+        let force = Derived::<dimension::Force>::new(2.5);
+        assert_eq!(force.to_string(), "2.5 m·kg·s^-2");
+    }
+}
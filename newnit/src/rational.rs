@@ -0,0 +1,531 @@
+//! Exact rational numbers for conversion factors of units.
+//!
+//! Conversion factors of most units are defined exactly (e.g. 1 ft = 0.3048 m,
+//! 1 °F = 5/9 K), but aren't representable as [`f64`]s. Factors are therefore
+//! stored as [`Rational`]s, so that the factor of conversion between any two
+//! units can be computed exactly at compile time (e.g. 1 in = 1/12 ft).
+//!
+//! The exception are factors involving π (e.g. of the degree or the parsec),
+//! which are built from the truncated [`Rational::PI`] and so are only
+//! approximate. Their ratios to each other are still exact (e.g. 1 turn =
+//! 360°), but conversions between them and units without π (e.g. degrees to
+//! radians) are accurate to about 19 significant digits, which is beyond the
+//! precision of [`f64`] but not of exact scalars such as `Ratio` or `Decimal`.
+//!
+//! # Examples
+//! ```
+//! use newnit::rational::Rational;
+//!
+//! let inch = Rational::new(254, 1, -4);
+//! let foot = Rational::new(3048, 1, -4);
+//!
+//! assert_eq!(inch.quotient(foot), Rational::new(1, 12, 0));
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+
+/// Unwrap an [`Option`] in a `const fn`, returning [`None`] if it is empty.
+macro_rules! try_some {
+    ($option:expr) => {
+        match $option {
+            Some(value) => value,
+            None => return None,
+        }
+    };
+}
+
+/// An exact rational number.
+///
+/// The number is stored as `numer / denom * 10^exponent`, normalized so that
+/// equal numbers have equal representations (the denominator is positive and
+/// coprime to both the numerator and ten). Powers of ten are kept in the
+/// exponent, which allows both the tiny and the huge factors of SI prefixes to
+/// be represented.
+///
+/// All operations are `const`, and panic if the result overflows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+    exponent: i32,
+}
+
+impl Rational {
+    /// One.
+    pub const ONE: Self = Self::integer(1);
+    /// π truncated to 19 significant digits, the most for which the factors of
+    /// units of solid angle (e.g. (π / 180)² sr for the square degree) don't
+    /// overflow.
+    ///
+    /// Unlike other constants, this is an approximation, and so are the
+    /// factors built from it.
+    pub const PI: Self = Self::new(3_141_592_653_589_793_238, 1, -18);
+    /// Zero.
+    pub const ZERO: Self = Self::integer(0);
+
+    /// Create the rational number `numer / denom * 10^exponent`.
+    ///
+    /// # Panics
+    ///
+    /// If `denom` is zero.
+    pub const fn new(numer: i128, denom: i128, exponent: i32) -> Self {
+        assert!(
+            denom != 0,
+            "Denominator of a rational number can't be zero."
+        );
+
+        Self::normalized(numer, denom, exponent).expect("Overflow in rational arithmetic.")
+    }
+
+    /// Create the rational number `numer / denom * 10^exponent`, or [`None`]
+    /// if `denom` is zero or the number overflows when normalized.
+    pub const fn checked_new(numer: i128, denom: i128, exponent: i32) -> Option<Self> {
+        match denom {
+            0 => None,
+            _ => Self::normalized(numer, denom, exponent),
+        }
+    }
+
+    /// Normalize `numer / denom * 10^exponent` (with a non-zero `denom`), or
+    /// [`None`] if the normalized number overflows.
+    const fn normalized(numer: i128, denom: i128, exponent: i32) -> Option<Self> {
+        if numer == 0 {
+            return Some(Self {
+                numer: 0,
+                denom: 1,
+                exponent: 0,
+            });
+        }
+
+        let (numer, denom) = match denom < 0 {
+            true => (
+                try_some!(numer.checked_neg()),
+                try_some!(denom.checked_neg()),
+            ),
+            false => (numer, denom),
+        };
+        let divisor = gcd(numer, denom);
+        let (mut numer, mut denom) = (numer / divisor, denom / divisor);
+        let mut exponent = exponent;
+
+        // Factors 2 and 5 of the denominator are traded for powers of ten,
+        // which leaves a single representation of each number.
+        while denom % 2 == 0 {
+            denom /= 2;
+            numer = try_some!(numer.checked_mul(5));
+            exponent = try_some!(exponent.checked_sub(1));
+        }
+        while denom % 5 == 0 {
+            denom /= 5;
+            numer = try_some!(numer.checked_mul(2));
+            exponent = try_some!(exponent.checked_sub(1));
+        }
+        while numer % 10 == 0 {
+            numer /= 10;
+            exponent = try_some!(exponent.checked_add(1));
+        }
+
+        Some(Self {
+            numer,
+            denom,
+            exponent,
+        })
+    }
+
+    /// Create a rational number equal to the integer `value`.
+    pub const fn integer(value: i128) -> Self {
+        Self::new(value, 1, 0)
+    }
+
+    /// The numerator, excluding the power of ten.
+    pub const fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    /// The denominator, excluding the power of ten.
+    pub const fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    /// The exponent of the power of ten.
+    pub const fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Whether the number is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    /// Whether the number is one.
+    pub const fn is_one(&self) -> bool {
+        self.numer == 1 && self.denom == 1 && self.exponent == 0
+    }
+
+    /// The product of `self` and `rhs`.
+    pub const fn product(self, rhs: Self) -> Self {
+        self.checked_product(rhs)
+            .expect("Overflow in rational arithmetic.")
+    }
+
+    /// The product of `self` and `rhs`, or [`None`] if it overflows.
+    pub const fn checked_product(self, rhs: Self) -> Option<Self> {
+        let left = gcd(self.numer, rhs.denom);
+        let right = gcd(rhs.numer, self.denom);
+
+        Self::normalized(
+            try_some!(i128::checked_mul(self.numer / left, rhs.numer / right)),
+            try_some!(i128::checked_mul(self.denom / right, rhs.denom / left)),
+            try_some!(self.exponent.checked_add(rhs.exponent)),
+        )
+    }
+
+    /// The quotient of `self` and `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    pub const fn quotient(self, rhs: Self) -> Self {
+        self.product(rhs.reciprocal())
+    }
+
+    /// The sum of `self` and `rhs`.
+    pub const fn sum(self, rhs: Self) -> Self {
+        self.checked_sum(rhs)
+            .expect("Overflow in rational arithmetic.")
+    }
+
+    /// The sum of `self` and `rhs`, or [`None`] if it overflows.
+    pub const fn checked_sum(self, rhs: Self) -> Option<Self> {
+        let exponent = if self.exponent < rhs.exponent {
+            self.exponent
+        } else {
+            rhs.exponent
+        };
+
+        let left = try_some!(i128::checked_mul(
+            self.numer,
+            try_some!(i128::checked_pow(10, self.exponent.abs_diff(exponent))),
+        ));
+        let right = try_some!(i128::checked_mul(
+            rhs.numer,
+            try_some!(i128::checked_pow(10, rhs.exponent.abs_diff(exponent))),
+        ));
+
+        Self::normalized(
+            try_some!(i128::checked_add(
+                try_some!(i128::checked_mul(left, rhs.denom)),
+                try_some!(i128::checked_mul(right, self.denom)),
+            )),
+            try_some!(i128::checked_mul(self.denom, rhs.denom)),
+            exponent,
+        )
+    }
+
+    /// The ordering of `self` and `rhs`, or [`None`] if computing it
+    /// overflows.
+    pub const fn checked_cmp(self, rhs: Self) -> Option<Ordering> {
+        // The denominator is always positive, so the sign is the numerator's.
+        let (sign, rhs_sign) = (self.numer.signum(), rhs.numer.signum());
+        if sign != rhs_sign {
+            return Some(match sign < rhs_sign {
+                true => Ordering::Less,
+                false => Ordering::Greater,
+            });
+        }
+
+        let difference = try_some!(self.checked_sum(rhs.negated()));
+        Some(match difference.numer.signum() {
+            -1 => Ordering::Less,
+            0 => Ordering::Equal,
+            _ => Ordering::Greater,
+        })
+    }
+
+    /// The difference of `self` and `rhs`.
+    pub const fn difference(self, rhs: Self) -> Self {
+        self.sum(rhs.negated())
+    }
+
+    /// The difference of `self` and `rhs`, or [`None`] if it overflows.
+    pub const fn checked_difference(self, rhs: Self) -> Option<Self> {
+        let numer = try_some!(rhs.numer.checked_neg());
+        self.checked_sum(Self { numer, ..rhs })
+    }
+
+    /// The quotient of `self` and `rhs`, or [`None`] if it overflows or `rhs`
+    /// is zero.
+    pub const fn checked_quotient(self, rhs: Self) -> Option<Self> {
+        let exponent = try_some!(rhs.exponent.checked_neg());
+        let reciprocal = try_some!(Self::checked_new(rhs.denom, rhs.numer, exponent));
+        self.checked_product(reciprocal)
+    }
+
+    /// The number with the opposite sign.
+    pub const fn negated(self) -> Self {
+        Self {
+            numer: -self.numer,
+            ..self
+        }
+    }
+
+    /// The reciprocal of the number.
+    ///
+    /// # Panics
+    ///
+    /// If the number is zero.
+    pub const fn reciprocal(self) -> Self {
+        Self::new(self.denom, self.numer, -self.exponent)
+    }
+
+    /// The product of the number and `value`, rounded to the nearest integer
+    /// (half away from zero), or [`None`] if the computation overflows.
+    pub const fn scale_integer(self, value: i128) -> Option<i128> {
+        let Some(power) = i128::checked_pow(10, self.exponent.unsigned_abs()) else {
+            return None;
+        };
+        let Some(numer) = i128::checked_mul(value, self.numer) else {
+            return None;
+        };
+
+        let (numer, denom) = match self.exponent < 0 {
+            true => (Some(numer), self.denom.checked_mul(power)),
+            false => (numer.checked_mul(power), Some(self.denom)),
+        };
+        let (Some(numer), Some(denom)) = (numer, denom) else {
+            return None;
+        };
+
+        // The denominator is always positive.
+        let quotient = numer / denom;
+        let remainder = numer % denom;
+
+        Some(match 2 * remainder.abs() >= denom {
+            true => quotient + numer.signum(),
+            false => quotient,
+        })
+    }
+
+    /// The closest [`f64`] to the number.
+    pub fn to_f64(self) -> f64 {
+        // Beyond these exponents, the number overflows or underflows whatever
+        // its numerator and denominator.
+        if self.numer == 0 || self.exponent < -440 {
+            return 0.0 * self.numer.signum() as f64;
+        }
+        if self.exponent > 400 {
+            return f64::INFINITY * self.numer.signum() as f64;
+        }
+
+        // The number is written out in decimal and parsed, which rounds
+        // correctly. Quotients that don't terminate are expanded far enough
+        // that no rounding boundary of `f64`s lies between the expansion and
+        // the number, and a last digit of one marks the expansion as inexact.
+        let mut digits = Digits::default();
+        let (numer, denom) = (self.numer.unsigned_abs(), self.denom.unsigned_abs());
+        let mut exponent = self.exponent;
+        if self.numer < 0 {
+            digits.push(b'-');
+        }
+        // Writing to the buffer only fails if it is full, which the bounds on
+        // the exponent rule out.
+        let _ = write!(digits, "{}", numer / denom);
+
+        let mut remainder = numer % denom;
+        for _ in 0..100 + self.exponent.unsigned_abs() {
+            if remainder == 0 {
+                break;
+            }
+            let digit;
+            (digit, remainder) = next_digit(remainder, denom);
+            digits.push(b'0' + digit);
+            exponent -= 1;
+        }
+        if remainder != 0 {
+            digits.push(b'1');
+            exponent -= 1;
+        }
+        let _ = write!(digits, "e{exponent}");
+
+        digits.as_str().parse().unwrap_or(f64::NAN)
+    }
+}
+
+/// The next digit of the decimal expansion of `remainder / denom` (with
+/// `remainder < denom`), and the remainder after it.
+///
+/// The remainder is multiplied by ten by repeated addition modulo `denom`, as
+/// the product may overflow.
+fn next_digit(remainder: u128, denom: u128) -> (u8, u128) {
+    let (mut digit, mut product) = (0, 0);
+    for _ in 0..10 {
+        if remainder >= denom - product {
+            product = remainder - (denom - product);
+            digit += 1;
+        } else {
+            product += remainder;
+        }
+    }
+    (digit, product)
+}
+
+/// Buffer of the decimal digits of a [`Rational`] converted to an [`f64`].
+struct Digits {
+    bytes: [u8; 640],
+    len: usize,
+}
+
+impl Default for Digits {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 640],
+            len: 0,
+        }
+    }
+}
+
+impl Digits {
+    fn push(&mut self, byte: u8) {
+        if let Some(slot) = self.bytes.get_mut(self.len) {
+            *slot = byte;
+            self.len += 1;
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only ASCII digits, signs and exponents are written.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for Digits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Greatest common divisor (always positive, for non-zero arguments).
+pub(crate) const fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 { 1 } else { a }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalized() {
+        assert_eq!(Rational::new(3048, 10000, 0), Rational::new(381, 125, -1));
+        assert_eq!(Rational::new(-5, -9, 0), Rational::new(5, 9, 0));
+        assert_eq!(Rational::new(0, 7, 12), Rational::ZERO);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let inch = Rational::new(254, 1, -4);
+        let foot = Rational::new(3048, 1, -4);
+        assert_eq!(inch.quotient(foot), Rational::new(1, 12, 0));
+        assert_eq!(
+            foot.product(Rational::integer(3)),
+            Rational::new(9144, 1, -4)
+        );
+
+        let celsius = Rational::new(27315, 1, -2);
+        let fahrenheit = celsius.difference(Rational::new(160, 9, 0));
+        assert_eq!(fahrenheit, Rational::new(229835, 900, 0));
+        assert_eq!(fahrenheit.sum(Rational::new(160, 9, 0)), celsius);
+    }
+
+    #[test]
+    fn compare() {
+        let third = Rational::new(1, 3, 0);
+        assert_eq!(
+            third.checked_cmp(Rational::new(333, 1, -3)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            third.checked_cmp(Rational::new(2, 6, 0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            Rational::new(-1, 1, 60).checked_cmp(third),
+            Some(Ordering::Less)
+        );
+        assert_eq!(Rational::new(1, 1, 60).checked_cmp(third), None);
+    }
+
+    #[test]
+    fn checked_new() {
+        assert_eq!(
+            Rational::checked_new(5, 10, 0),
+            Some(Rational::new(1, 2, 0))
+        );
+        assert_eq!(Rational::checked_new(1, 0, 0), None);
+        assert_eq!(Rational::checked_new(i128::MAX, 1 << 100, 0), None);
+    }
+
+    #[test]
+    fn huge_exponents() {
+        let quetta = Rational::new(1, 1, 60);
+        let quecto = Rational::new(1, 1, -60);
+        assert_eq!(quetta.product(quecto), Rational::ONE);
+        assert_eq!(quetta.to_f64(), 1E+60);
+        assert_eq!(quecto.to_f64(), 1E-60);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn closest_f64() {
+        assert_eq!(Rational::new(1, 1, -30).to_f64(), 1E-30);
+        assert_eq!(Rational::new(1, 1, -24).to_f64(), 1E-24);
+        assert_eq!(Rational::new(-3048, 1, -4).to_f64(), -0.3048);
+        assert_eq!(Rational::new(1, 3, 0).to_f64(), 1.0 / 3.0);
+        assert_eq!(Rational::new(5, 9, -40).to_f64(), 5.555_555_555_555_556e-41);
+        assert_eq!(Rational::PI.to_f64(), core::f64::consts::PI);
+        assert_eq!(
+            Rational::new(1, i128::MAX, 0).to_f64(),
+            1.0 / i128::MAX as f64
+        );
+        assert_eq!(Rational::new(1, 1, 500).to_f64(), f64::INFINITY);
+        assert_eq!(Rational::new(-1, 1, -500).to_f64(), 0.0);
+
+        for numer in 1..200 {
+            for exponent in -40..40 {
+                let expected: f64 = format!("{numer}e{exponent}").parse().unwrap();
+                assert_eq!(Rational::new(numer, 1, exponent).to_f64(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn scale_integers() {
+        let milli = Rational::new(1, 1, -3);
+        assert_eq!(milli.scale_integer(1_500), Some(2));
+        assert_eq!(milli.scale_integer(-1_499), Some(-1));
+        assert_eq!(Rational::new(254, 1, 1).scale_integer(3), Some(7_620));
+        assert_eq!(Rational::new(-1, 3, 0).scale_integer(5), Some(-2));
+        assert_eq!(Rational::new(1, 1, 60).scale_integer(1), None);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let third = Rational::new(1, 3, 0);
+        assert_eq!(third.checked_sum(third), Some(Rational::new(2, 3, 0)));
+
+        let huge = Rational::new(i128::MAX, 7, 0);
+        assert_eq!(huge.checked_product(huge), None);
+        assert_eq!(huge.checked_sum(Rational::new(1, 11, 0)), None);
+    }
+}
@@ -0,0 +1,597 @@
+//! Registry of the units known to the library.
+//!
+//! Every registered unit is listed under the quantity it measures, with its
+//! symbol, names, alternative symbols or names (aliases, e.g. `L` for the
+//! cubic decimetre), system of measurement and conversion to the base unit.
+//! The registry lists all of them, finds units by symbol, name or alias, and
+//! creates values of the found units as trait objects of their quantity (e.g.
+//! `Box<dyn Length>`).
+//!
+//! Units register themselves: every unit defined by
+//! [`make_unit!`](crate::make_unit), and every unit deriving a quantity trait
+//! with `#[quantity(register)]`, is collected into the registry when linking,
+//! along with the predefined units. The linker sections this relies on are
+//! supported on Linux, Android, macOS, iOS, Windows, FreeBSD, OpenBSD,
+//! illumos, Fuchsia and bare metal (`target_os = "none"`). On other targets
+//! (e.g. WebAssembly, NetBSD) the registry lists the predefined units only.
+//!
+//! # Examples
+//! ```
+//! use newnit::length::Length;
+//! use newnit::registry::{self, Kind};
+//! use newnit::unit::System;
+//!
+//! let foot = registry::find("feet").unwrap();
+//! assert_eq!(foot.kind(), Kind::Length);
+//! assert_eq!(foot.symbol(), "ft");
+//! assert_eq!(foot.system(), System::Imperial);
+//!
+//! # #[cfg(feature = "std")] {
+//! let length: Box<dyn Length> = foot.boxed(3.0).unwrap();
+//! assert!((length.to_base() - 0.9144).abs() < 1e-9);
+//! # }
+//!
+//! let litre = registry::find("L").unwrap();
+//! assert_eq!(litre.symbol(), "dm³");
+//! assert_eq!(litre.aliases(), ["L", "litre", "litres"]);
+//!
+//! let imperial = registry::of(Kind::Length)
+//!     .filter(|unit| unit.system() == System::Imperial);
+//! assert_eq!(imperial.count(), 7);
+//! ```
+
+#[cfg(feature = "std")]
+use std::any::Any;
+
+use crate::Quantity;
+#[cfg(feature = "std")]
+use crate::Unit;
+use crate::parse::{self, UnitInfo};
+use crate::rational::Rational;
+#[cfg(feature = "std")]
+use crate::scalar::Scalar;
+use crate::unit::{Conversion, Metadata, System};
+
+/// A quantity measured by registered units.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Kind {
+    /// Units of [`Angle`](crate::angle::Angle).
+    Angle,
+    /// Units of [`Area`](crate::area::Area).
+    Area,
+    /// Units of [`Capacitance`](crate::capacitance::Capacitance).
+    Capacitance,
+    /// Units of [`Charge`](crate::charge::Charge).
+    Charge,
+    /// Units of [`Conductance`](crate::conductance::Conductance).
+    Conductance,
+    /// Units of [`Current`](crate::current::Current).
+    Current,
+    /// Units of [`Energy`](crate::energy::Energy).
+    Energy,
+    /// Units of [`Force`](crate::force::Force).
+    Force,
+    /// Units of [`GaugePressure`](crate::pressure::GaugePressure).
+    GaugePressure,
+    /// Units of [`Illuminance`](crate::illuminance::Illuminance).
+    Illuminance,
+    /// Units of [`Inductance`](crate::inductance::Inductance).
+    Inductance,
+    /// Units of [`Length`](crate::length::Length).
+    Length,
+    /// Units of [`Luminance`](crate::luminance::Luminance).
+    Luminance,
+    /// Units of [`LuminousEnergy`](crate::luminous_energy::LuminousEnergy).
+    LuminousEnergy,
+    /// Units of [`LuminousFlux`](crate::luminous_flux::LuminousFlux).
+    LuminousFlux,
+    /// Units of [`LuminousIntensity`](crate::luminous_intensity::LuminousIntensity).
+    LuminousIntensity,
+    /// Units of [`MagneticFlux`](crate::magnetic_flux::MagneticFlux).
+    MagneticFlux,
+    /// Units of [`MagneticFluxDensity`](crate::magnetic_flux_density::MagneticFluxDensity).
+    MagneticFluxDensity,
+    /// Units of [`Mass`](crate::mass::Mass).
+    Mass,
+    /// Units of [`Power`](crate::power::Power).
+    Power,
+    /// Units of [`Pressure`](crate::pressure::Pressure).
+    Pressure,
+    /// Units of [`PressureDifference`](crate::pressure::PressureDifference).
+    PressureDifference,
+    /// Units of [`Resistance`](crate::resistance::Resistance).
+    Resistance,
+    /// Units of [`SolidAngle`](crate::solid_angle::SolidAngle).
+    SolidAngle,
+    /// Units of [`SubstanceAmount`](crate::substance_amount::SubstanceAmount).
+    SubstanceAmount,
+    /// Units of [`Temperature`](crate::temperature::Temperature).
+    Temperature,
+    /// Units of [`TemperatureInterval`](crate::temperature::TemperatureInterval).
+    TemperatureInterval,
+    /// Units of [`Time`](crate::time::Time).
+    Time,
+    /// Units of [`Velocity`](crate::velocity::Velocity).
+    Velocity,
+    /// Units of [`Voltage`](crate::voltage::Voltage).
+    Voltage,
+    /// Units of [`Volume`](crate::volume::Volume).
+    Volume,
+}
+
+/// A registered unit.
+#[derive(Copy, Clone, Debug)]
+pub struct Entry {
+    kind: Kind,
+    info: UnitInfo,
+    aliases: &'static [&'static str],
+    #[cfg(feature = "std")]
+    construct: fn(&mut dyn Any) -> Option<Box<dyn Any>>,
+}
+
+impl Entry {
+    /// Entry of the unit `U` of values of type `V` of the given quantity.
+    #[doc(hidden)]
+    pub const fn of<U: Quantity<V> + Conversion + Metadata + Construct, V>(kind: Kind) -> Self {
+        Self {
+            kind,
+            info: UnitInfo::of::<U, V>(),
+            aliases: U::ALIASES,
+            #[cfg(feature = "std")]
+            construct: construct::<U>,
+        }
+    }
+
+    /// The quantity measured by the unit.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// The symbol of the unit (e.g. `km`).
+    pub fn symbol(&self) -> &'static str {
+        self.info.symbol
+    }
+
+    /// The singular name of the unit (e.g. `kilometre`).
+    pub fn name(&self) -> &'static str {
+        self.info.name
+    }
+
+    /// The plural name of the unit (e.g. `kilometres`).
+    pub fn plural(&self) -> &'static str {
+        self.info.plural
+    }
+
+    /// Alternative symbols or names of the unit (e.g. `L` for `dm³`).
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// The system of measurement the unit belongs to.
+    pub fn system(&self) -> System {
+        self.info.system
+    }
+
+    /// The factor of conversion to the base unit.
+    pub fn factor(&self) -> Rational {
+        self.info.factor
+    }
+
+    /// The offset of conversion to the base unit.
+    pub fn offset(&self) -> Rational {
+        self.info.offset
+    }
+
+    /// Exponents of the dimension of the unit, in the order of
+    /// [`Dimension::EXPONENTS`](crate::dimension::Dimension::EXPONENTS).
+    pub fn exponents(&self) -> [i32; 7] {
+        self.info.exponents
+    }
+
+    /// Exponent of plane angle of the dimension of the unit, as in
+    /// [`Dimension::ANGLE`](crate::dimension::Dimension::ANGLE).
+    pub fn angle(&self) -> i32 {
+        self.info.angle
+    }
+
+    /// Exponent of solid angle of the dimension of the unit, as in
+    /// [`Dimension::SOLID_ANGLE`](crate::dimension::Dimension::SOLID_ANGLE).
+    pub fn solid_angle(&self) -> i32 {
+        self.info.solid_angle
+    }
+
+    /// A value of the unit as a trait object of its quantity (e.g. `dyn
+    /// Length`), or `None` if the unit measures a different quantity or
+    /// doesn't store values of type `V`.
+    ///
+    /// Values are created of the scalar types provided by the library only
+    /// (see [`scalar`](crate::scalar)).
+    #[cfg(feature = "std")]
+    pub fn boxed<Q: Unit<V> + ?Sized + 'static, V: 'static>(&self, value: V) -> Option<Box<Q>> {
+        let boxed = (self.construct)(&mut Some(value))?;
+        boxed.downcast::<Box<Q>>().ok().map(|boxed| *boxed)
+    }
+
+    /// Information about the unit used by the parsers.
+    pub(crate) fn info(&self) -> &UnitInfo {
+        &self.info
+    }
+
+    /// Whether `symbol` is the symbol or an alias of the unit.
+    pub(crate) fn has_symbol(&self, symbol: &str) -> bool {
+        parse::matches(symbol, self.info.symbol)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| parse::matches(symbol, alias))
+    }
+
+    /// Whether `name` is a name of the unit, ignoring ASCII case.
+    pub(crate) fn has_name(&self, name: &str) -> bool {
+        let names = [self.info.name, self.info.plural];
+        names
+            .iter()
+            .chain(self.aliases)
+            .any(|unit_name| unit_name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Construction of values of a registered unit as trait objects of its
+/// quantity.
+///
+/// Implemented by [`register!`](crate::register).
+#[doc(hidden)]
+pub trait Construct {
+    /// A value of the unit as a boxed `Box<dyn Quantity<V>>`, or `None` if
+    /// the unit doesn't store values of type `V`.
+    #[cfg(feature = "std")]
+    fn construct<V: Scalar + 'static>(value: V) -> Option<Box<dyn Any>>;
+}
+
+/// A value of the unit `U` from the `value` (an `Option` of one of the scalar
+/// types provided by the library, taken from it).
+#[cfg(feature = "std")]
+fn construct<U: Construct>(value: &mut dyn Any) -> Option<Box<dyn Any>> {
+    macro_rules! scalars {
+        ($($scalar:ty),*) => {$(
+            if let Some(value) = value.downcast_mut::<Option<$scalar>>() {
+                return U::construct(value.take()?);
+            }
+        )*};
+    }
+
+    scalars!(f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    #[cfg(feature = "rust_decimal")]
+    scalars!(rust_decimal::Decimal);
+    #[cfg(feature = "num-rational")]
+    scalars!(num_rational::Ratio<i32>, num_rational::Ratio<i64>);
+
+    None
+}
+
+/// Register the unit `$unit` of the quantity `$quantity` (the name of both the
+/// quantity trait, which must be in scope, and of the [`Kind`]).
+///
+/// Generic units are given with their value type parameter (e.g.
+/// `Meter<V>`), others with their value type (e.g. `Meter(f64)`). Used by the
+/// derive macros of quantity traits with `#[quantity(register)]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! register {
+    ($unit:ident<V>: $quantity:ident) => {
+        const _: () = {
+            $crate::__construct!($unit: $quantity, |value: V| {
+                ::core::option::Option::Some($crate::registry::__private::Box::new(
+                    $crate::registry::__private::Box::new($unit(value))
+                        as $crate::registry::__private::Box<dyn $quantity<V>>,
+                ))
+            });
+
+            $crate::__register_entry! { $unit<f64>: $quantity }
+        };
+    };
+
+    ($unit:ident($value:ty): $quantity:ident) => {
+        const _: () = {
+            $crate::__construct!($unit: $quantity, |value: V| {
+                let value = (&mut ::core::option::Option::Some(value)
+                    as &mut dyn $crate::registry::__private::Any)
+                    .downcast_mut::<::core::option::Option<$value>>()?
+                    .take()?;
+
+                ::core::option::Option::Some($crate::registry::__private::Box::new(
+                    $crate::registry::__private::Box::new($unit(value))
+                        as $crate::registry::__private::Box<dyn $quantity<$value>>,
+                ))
+            });
+
+            $crate::__register_entry! { $unit<$value>: $quantity }
+        };
+    };
+}
+
+/// Impl of [`Construct`] for the unit `$unit`, constructing its values with
+/// `$construct`.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __construct {
+    ($unit:ident: $quantity:ident, |$value:ident: V| $construct:block) => {
+        impl $crate::registry::Construct for $unit {
+            fn construct<V: $crate::scalar::Scalar + 'static>(
+                $value: V,
+            ) -> ::core::option::Option<
+                $crate::registry::__private::Box<dyn $crate::registry::__private::Any>,
+            > $construct
+        }
+    };
+}
+
+/// Impl of [`Construct`] for the unit `$unit`, whose values aren't boxed
+/// without `std`.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __construct {
+    ($unit:ident: $quantity:ident, |$value:ident: V| $construct:block) => {
+        impl $crate::registry::Construct for $unit {}
+    };
+}
+
+/// Entry of the unit `$unit` of values of type `$value` in the registry.
+#[cfg(linked)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_entry {
+    ($unit:ident<$value:ty>: $quantity:ident) => {
+        #[$crate::registry::__private::distributed_slice($crate::registry::UNITS)]
+        #[linkme(crate = $crate::registry::__private::linkme)]
+        static ENTRY: $crate::registry::Entry =
+            $crate::registry::Entry::of::<$unit, $value>($crate::registry::Kind::$quantity);
+    };
+}
+
+/// No entry of the unit `$unit`, on targets whose linker doesn't collect the
+/// registered units.
+#[cfg(not(linked))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_entry {
+    ($unit:ident<$value:ty>: $quantity:ident) => {};
+}
+
+/// Not public API, used by [`register!`](crate::register).
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use std::{any::Any, boxed::Box};
+
+    pub use linkme::{self, distributed_slice};
+}
+
+/// Units registered by [`register!`](crate::register).
+#[cfg(linked)]
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static UNITS: [Entry];
+
+/// All registered units.
+#[cfg(linked)]
+pub fn entries() -> impl Iterator<Item = &'static Entry> {
+    UNITS.iter()
+}
+
+/// All registered units.
+#[cfg(not(linked))]
+pub fn entries() -> impl Iterator<Item = &'static Entry> {
+    PREDEFINED.iter().flat_map(|entries| entries.iter())
+}
+
+/// Units predefined by the library, by quantity, as listed for their parsers.
+///
+/// Registered on targets whose linker doesn't collect the registered units,
+/// and checked against the linked units on the others.
+#[cfg_attr(all(linked, not(test)), allow(dead_code))]
+const PREDEFINED: [&[Entry]; 31] = [
+    crate::angle::UNITS,
+    crate::area::UNITS,
+    crate::capacitance::UNITS,
+    crate::charge::UNITS,
+    crate::conductance::UNITS,
+    crate::current::UNITS,
+    crate::energy::UNITS,
+    crate::force::UNITS,
+    crate::illuminance::UNITS,
+    crate::inductance::UNITS,
+    crate::length::UNITS,
+    crate::luminance::UNITS,
+    crate::luminous_energy::UNITS,
+    crate::luminous_flux::UNITS,
+    crate::luminous_intensity::UNITS,
+    crate::magnetic_flux::UNITS,
+    crate::magnetic_flux_density::UNITS,
+    crate::mass::UNITS,
+    crate::power::UNITS,
+    crate::pressure::UNITS,
+    crate::pressure::GAUGE_UNITS,
+    crate::pressure::DIFFERENCE_UNITS,
+    crate::resistance::UNITS,
+    crate::solid_angle::UNITS,
+    crate::substance_amount::UNITS,
+    crate::temperature::UNITS,
+    crate::temperature::INTERVAL_UNITS,
+    crate::time::UNITS,
+    crate::velocity::UNITS,
+    crate::voltage::UNITS,
+    crate::volume::UNITS,
+];
+
+/// Registered units of the given quantity.
+pub fn of(kind: Kind) -> impl Iterator<Item = &'static Entry> {
+    entries().filter(move |entry| entry.kind == kind)
+}
+
+/// Find a registered unit by its symbol or alias (matched exactly, see
+/// [`parse`]), or by its singular or plural name (ignoring ASCII case).
+pub fn find(s: &str) -> Option<&'static Entry> {
+    let s = s.trim();
+    find_symbol(s).or_else(|| entries().find(|entry| entry.has_name(s)))
+}
+
+/// Find a registered unit by its symbol or alias.
+pub(crate) fn find_symbol(symbol: &str) -> Option<&'static Entry> {
+    entries().find(|entry| entry.has_symbol(symbol))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::assert_quantity_eq;
+    #[cfg(feature = "std")]
+    use crate::length::Length;
+    use crate::make_unit;
+    #[cfg(feature = "std")]
+    use crate::mass::Mass;
+    #[cfg(feature = "std")]
+    use crate::temperature::{Celsius, Temperature};
+    #[cfg(feature = "std")]
+    use crate::time::Time;
+    use crate::velocity::Velocity;
+    #[cfg(feature = "std")]
+    use crate::velocity::metric::MeterPerSecond;
+    use newnit_derive::{Time, Unit, Velocity};
+
+    make_unit!(
+        #[unit(alias = "fpf", alias = "furlongs per fortnight")]
+        FurlongPerFortnight,
+        "fur/ftn",
+        "furlong per fortnight",
+        "furlongs per fortnight",
+        Other,
+        201.168 / 1_209_600,
+        Velocity
+    );
+
+    #[derive(Unit, Time)]
+    #[unit(factor = 0.01, symbol = "jf", name = "jiffy", plural = "jiffies")]
+    #[quantity(register)]
+    struct Jiffy(f32);
+
+    #[test]
+    fn find_by_symbol_name_or_alias() {
+        let entry = find("km").unwrap();
+        assert_eq!(entry.kind(), Kind::Length);
+        assert_eq!(entry.name(), "kilometre");
+        assert_eq!(entry.factor(), Rational::new(1, 1, 3));
+
+        assert_eq!(find("Kilometres").unwrap().symbol(), "km");
+        assert_eq!(find("t").unwrap().name(), "megagram");
+        assert_eq!(find("tonne").unwrap().kind(), Kind::Mass);
+        assert_eq!(find("Mm").unwrap().name(), "megametre");
+        assert_eq!(find("m^2").unwrap().kind(), Kind::Area);
+        assert!(find("parsnip").is_none());
+    }
+
+    #[test]
+    fn unique_symbols() {
+        for entry in entries() {
+            let symbols = core::iter::once(entry.symbol()).chain(entry.aliases().iter().copied());
+            for symbol in symbols {
+                let found = find_symbol(symbol).unwrap();
+                assert_eq!(found.symbol(), entry.symbol(), "duplicate symbol {symbol}");
+            }
+        }
+    }
+
+    #[test]
+    fn register_predefined_units() {
+        let predefined = PREDEFINED.iter().flat_map(|entries| entries.iter());
+        for entry in predefined {
+            let registered = find_symbol(entry.symbol()).unwrap();
+            assert_eq!(registered.kind(), entry.kind(), "{}", entry.symbol());
+            assert_eq!(registered.aliases(), entry.aliases());
+        }
+    }
+
+    #[cfg(linked)]
+    #[cfg(feature = "std")]
+    #[test]
+    fn list_linked_units() {
+        // Units missing from the lists of their module are missing from its
+        // parser and unit enum, and from the registry of unlinked targets.
+        let predefined: Vec<&Entry> = PREDEFINED
+            .iter()
+            .flat_map(|entries| entries.iter())
+            .collect();
+        let mut unlisted: Vec<&str> = entries()
+            .filter(|entry| {
+                !predefined.iter().any(|listed| {
+                    listed.kind() == entry.kind() && listed.symbol() == entry.symbol()
+                })
+            })
+            .map(Entry::symbol)
+            .collect();
+        unlisted.sort_unstable();
+
+        // Only the units defined in these tests aren't predefined.
+        assert_eq!(unlisted, ["fur/ftn", "jf"]);
+        assert_eq!(entries().count(), predefined.len() + unlisted.len());
+    }
+
+    #[cfg(linked)]
+    #[cfg(feature = "std")]
+    #[test]
+    fn register_user_units() {
+        let entry = find("furlongs per fortnight").unwrap();
+        assert_eq!(entry.kind(), Kind::Velocity);
+        assert_eq!(entry.symbol(), "fur/ftn");
+        assert_eq!(find("fpf").unwrap().symbol(), "fur/ftn");
+
+        let velocity: Box<dyn Velocity> = entry.boxed(1.0).unwrap();
+        assert_quantity_eq!(*velocity, MeterPerSecond(201.168 / 1_209_600.0));
+
+        let entry = find("jiffies").unwrap();
+        assert_eq!(entry.kind(), Kind::Time);
+        let time: Box<dyn Time<f32>> = entry.boxed(3.0).unwrap();
+        assert_eq!(time.to_base(), 0.03);
+        assert!(entry.boxed::<dyn Time, _>(3.0).is_none());
+    }
+
+    #[test]
+    fn list_by_quantity() {
+        assert!(of(Kind::Temperature).all(|entry| entry.info().affine));
+        assert_eq!(of(Kind::TemperatureInterval).count(), 3);
+        assert!(of(Kind::Velocity).any(|entry| entry.symbol() == "kn"));
+        assert!(of(Kind::Energy).any(|entry| entry.symbol() == "kWh"));
+        assert!(of(Kind::GaugePressure).all(|entry| entry.info().affine));
+        assert!(of(Kind::PressureDifference).all(|entry| !entry.info().affine));
+        assert_eq!(find("psid").unwrap().kind(), Kind::PressureDifference);
+        assert_eq!(find("mAh").unwrap().kind(), Kind::Charge);
+        assert_eq!(
+            find("lm").unwrap().exponents(),
+            find("cd").unwrap().exponents()
+        );
+        assert_eq!(find("lm").unwrap().solid_angle(), 1);
+        assert_eq!(find("cd/m²").unwrap().kind(), Kind::Luminance);
+        assert!(entries().count() > 250);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn create_trait_objects() {
+        let entry = find("°F").unwrap();
+        let temperature: Box<dyn Temperature> = entry.boxed(212.0).unwrap();
+        assert_quantity_eq!(*temperature, Celsius(100.0));
+        assert_eq!(temperature.symbol(), "°F");
+
+        let mile = find("nmi").unwrap();
+        let length: Box<dyn Length<f32>> = mile.boxed(1.0).unwrap();
+        assert_eq!(length.to_base(), 1_852.0);
+        assert_eq!(length.name(), "nautical mile");
+
+        assert!(mile.boxed::<dyn Mass, _>(1.0).is_none());
+    }
+}
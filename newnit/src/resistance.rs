@@ -0,0 +1,236 @@
+//! Units of electrical resistance.
+//!
+//! This module contains predefined newtypes for units of electrical resistance
+//! as defined in the International System of Units (SI). The base unit is the
+//! [`Ohm`].
+//!
+//! [`Ohm`]: metric::Ohm
+
+use crate::capacitance::Capacitance;
+use crate::conductance::metric::Siemens;
+use crate::current::Current;
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::time::metric::Second;
+use crate::voltage::metric::Volt;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of electrical resistance.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication, and
+/// the reciprocal conductance.
+pub trait Resistance<V: Scalar = f64>: Quantity<V, Dimension = dimension::Resistance> {
+    /// Multiply a unit of resistance with a unit of current.
+    fn multiply_current(&self, rhs: &dyn Current<V>) -> Volt<V> {
+        Volt(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of resistance with a unit of capacitance.
+    fn multiply_capacitance(&self, rhs: &dyn Capacitance<V>) -> Second<V> {
+        Second(self.to_base() * rhs.to_base())
+    }
+
+    /// The conductance, the reciprocal of the resistance.
+    fn conductance(&self) -> Siemens<V> {
+        Siemens(V::from_rational(Rational::ONE) / self.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Resistance>> Resistance<V> for T {}
+
+pub mod metric {
+    use super::Resistance;
+    use crate::make_unit;
+    use newnit_derive::{Resistance, Unit};
+
+    make_unit!(
+        QuettaOhm,
+        "QΩ",
+        "quettaohm",
+        "quettaohms",
+        Metric,
+        1E+30,
+        Resistance
+    );
+    make_unit!(
+        RonnaOhm,
+        "RΩ",
+        "ronnaohm",
+        "ronnaohms",
+        Metric,
+        1E+27,
+        Resistance
+    );
+    make_unit!(
+        YottaOhm,
+        "YΩ",
+        "yottaohm",
+        "yottaohms",
+        Metric,
+        1E+24,
+        Resistance
+    );
+    make_unit!(
+        ZettaOhm,
+        "ZΩ",
+        "zettaohm",
+        "zettaohms",
+        Metric,
+        1E+21,
+        Resistance
+    );
+    make_unit!(ExaOhm, "EΩ", "exaohm", "exaohms", Metric, 1E+18, Resistance);
+    make_unit!(
+        PetaOhm, "PΩ", "petaohm", "petaohms", Metric, 1E+15, Resistance
+    );
+    make_unit!(
+        TeraOhm, "TΩ", "teraohm", "teraohms", Metric, 1E+12, Resistance
+    );
+    make_unit!(
+        GigaOhm, "GΩ", "gigaohm", "gigaohms", Metric, 1E+9, Resistance
+    );
+    make_unit!(
+        MegaOhm, "MΩ", "megaohm", "megaohms", Metric, 1E+6, Resistance
+    );
+    make_unit!(
+        KiloOhm, "kΩ", "kiloohm", "kiloohms", Metric, 1E+3, Resistance
+    );
+    make_unit!(Ohm, "Ω", "ohm", "ohms", Metric, 1.0, Resistance);
+    make_unit!(
+        DeciOhm, "dΩ", "deciohm", "deciohms", Metric, 1E-1, Resistance
+    );
+    make_unit!(
+        CentiOhm,
+        "cΩ",
+        "centiohm",
+        "centiohms",
+        Metric,
+        1E-2,
+        Resistance
+    );
+    make_unit!(
+        MilliOhm,
+        "mΩ",
+        "milliohm",
+        "milliohms",
+        Metric,
+        1E-3,
+        Resistance
+    );
+    make_unit!(
+        MicroOhm,
+        "µΩ",
+        "microohm",
+        "microohms",
+        Metric,
+        1E-6,
+        Resistance
+    );
+    make_unit!(
+        NanoOhm, "nΩ", "nanoohm", "nanoohms", Metric, 1E-9, Resistance
+    );
+    make_unit!(
+        PicoOhm, "pΩ", "picoohm", "picoohms", Metric, 1E-12, Resistance
+    );
+    make_unit!(
+        FemtoOhm,
+        "fΩ",
+        "femtoohm",
+        "femtoohms",
+        Metric,
+        1E-15,
+        Resistance
+    );
+    make_unit!(
+        AttoOhm, "aΩ", "attoohm", "attoohms", Metric, 1E-18, Resistance
+    );
+    make_unit!(
+        ZeptoOhm,
+        "zΩ",
+        "zeptoohm",
+        "zeptoohms",
+        Metric,
+        1E-21,
+        Resistance
+    );
+    make_unit!(
+        YoctoOhm,
+        "yΩ",
+        "yoctoohm",
+        "yoctoohms",
+        Metric,
+        1E-24,
+        Resistance
+    );
+    make_unit!(
+        RontoOhm,
+        "rΩ",
+        "rontoohm",
+        "rontoohms",
+        Metric,
+        1E-27,
+        Resistance
+    );
+    make_unit!(
+        QuectoOhm,
+        "qΩ",
+        "quectoohm",
+        "quectoohms",
+        Metric,
+        1E-30,
+        Resistance
+    );
+}
+
+parse::units! {
+    /// Parse an electrical resistance in the unit named by its symbol (e.g.
+    /// `"4.7 kΩ"`).
+    parse, UNITS: Resistance, ResistanceUnit;
+    metric::{
+        QuettaOhm,
+        RonnaOhm,
+        YottaOhm,
+        ZettaOhm,
+        ExaOhm,
+        PetaOhm,
+        TeraOhm,
+        GigaOhm,
+        MegaOhm,
+        KiloOhm,
+        Ohm,
+        DeciOhm,
+        CentiOhm,
+        MilliOhm,
+        MicroOhm,
+        NanoOhm,
+        PicoOhm,
+        FemtoOhm,
+        AttoOhm,
+        ZeptoOhm,
+        YoctoOhm,
+        RontoOhm,
+        QuectoOhm,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::capacitance::metric::MicroFarad;
+    use crate::conductance::metric::MilliSiemens;
+    use crate::current::metric::MilliAmpere;
+    use crate::time::metric::MilliSecond;
+
+    #[test]
+    fn multiply_and_invert() {
+        let resistance = metric::KiloOhm(2.0_f64);
+        assert_quantity_eq!(resistance.multiply_current(&MilliAmpere(3.0)), Volt(6.0));
+        assert_quantity_eq!(
+            resistance.multiply_capacitance(&MicroFarad(10.0)),
+            MilliSecond(20.0)
+        );
+        assert_quantity_eq!(resistance.conductance(), MilliSiemens(0.5));
+    }
+}
@@ -0,0 +1,415 @@
+//! Units selected at runtime.
+//!
+//! Every quantity module provides an enum of its predefined units (e.g.
+//! [`LengthUnit`](crate::length::LengthUnit)), to choose a unit at runtime,
+//! e.g. from a user setting. The enums list all of their units ([`ALL`]),
+//! parse from the symbol, name or alias of a unit and display its symbol (or
+//! its name in the alternate form `{:#}`), and convert values between their
+//! units (`convert`). With the `serde` feature, they serialize as the symbol
+//! of the unit.
+//!
+//! A [`Value`] pairs an [`f64`] with a unit of such an enum, and is a unit of
+//! the quantity of the enum itself (e.g. [`Length`] implements the
+//! [`Length`](crate::length::Length) trait), so values in runtime selected
+//! units keep their dimension checked at compile time.
+//!
+//! # Examples
+//! ```
+//! use newnit::Unit;
+//! use newnit::length::LengthUnit;
+//! use newnit::length::metric::Meter;
+//! use newnit::runtime::{self, RuntimeUnit};
+//!
+//! let unit: LengthUnit = "ft".parse().unwrap();
+//! assert_eq!(unit, LengthUnit::Foot);
+//! assert_eq!(format!("{unit:#}"), "foot");
+//! assert_eq!(LengthUnit::convert(3.0, unit, LengthUnit::Inch), 36.0);
+//! assert!(LengthUnit::ALL.contains(&LengthUnit::KiloMeter));
+//!
+//! let altitude = runtime::Length::new(1200.0, unit);
+//! assert_eq!(altitude.to_string(), "1200 ft");
+//! assert!((Meter::from_base(altitude.to_base()).0 - 365.76).abs() < 1e-9);
+//! ```
+//!
+//! [`ALL`]: RuntimeUnit::ALL
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::dimension::Dimension;
+use crate::parse::{self, ParseError};
+use crate::registry::{self, Entry};
+use crate::scalar::Scalar;
+use crate::unit::{System, affine};
+use crate::{Quantity, Unit};
+
+/// Enums of the predefined units of a quantity (e.g.
+/// [`LengthUnit`](crate::length::LengthUnit)).
+///
+/// Implemented by the enums of all quantity modules.
+pub trait RuntimeUnit: Copy + Eq + 'static {
+    /// The dimension of the quantity measured by the units.
+    type Dimension: Dimension;
+
+    /// All units, in the order of the registry.
+    const ALL: &'static [Self];
+
+    /// The registry entry of the unit.
+    fn entry(self) -> &'static Entry;
+}
+
+/// Convert `value` from the unit `from` to the unit `to`.
+pub fn convert<U: RuntimeUnit>(value: f64, from: U, to: U) -> f64 {
+    let (from, to) = (from.entry(), to.entry());
+    let reciprocal = to.factor().reciprocal();
+
+    // Direct conversion between the units, through base units on overflow.
+    let factor = from.factor().checked_product(reciprocal);
+    let offset = from
+        .offset()
+        .checked_sum(to.offset().negated())
+        .and_then(|offset| offset.checked_product(reciprocal));
+    match factor.zip(offset) {
+        Some((factor, offset)) => affine(value, factor, offset),
+        None => from_base(to, to_base(from, value)),
+    }
+}
+
+/// A value in a unit selected at runtime, of the quantity of the unit enum
+/// `U`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Value<U> {
+    value: f64,
+    unit: U,
+}
+
+impl<U: RuntimeUnit> Value<U> {
+    /// Create a value in the given unit.
+    pub fn new(value: f64, unit: U) -> Self {
+        Self { value, unit }
+    }
+
+    /// The unit of the value.
+    pub fn unit(&self) -> U {
+        self.unit
+    }
+
+    /// The value converted to the given unit.
+    pub fn to(&self, unit: U) -> Self {
+        Self::new(convert(self.value, self.unit, unit), unit)
+    }
+}
+
+impl<U: RuntimeUnit> Unit for Value<U> {
+    fn to_base(&self) -> f64 {
+        to_base(self.unit.entry(), self.value)
+    }
+
+    fn to_value(&self) -> f64 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    fn set_from_base(&mut self, base: f64) {
+        self.value = from_base(self.unit.entry(), base);
+    }
+
+    /// The value in the base unit of the quantity.
+    fn from_base(base: f64) -> Self {
+        let unit = U::ALL.iter().copied().find(|unit| {
+            let entry = unit.entry();
+            entry.factor().is_one() && entry.offset().is_zero()
+        });
+        let unit = unit.unwrap_or(U::ALL[0]);
+
+        Self::new(from_base(unit.entry(), base), unit)
+    }
+
+    fn symbol(&self) -> &'static str {
+        self.unit.entry().symbol()
+    }
+
+    fn name(&self) -> &'static str {
+        self.unit.entry().name()
+    }
+
+    fn plural(&self) -> &'static str {
+        self.unit.entry().plural()
+    }
+
+    fn system(&self) -> System {
+        self.unit.entry().system()
+    }
+}
+
+impl<U: RuntimeUnit> Quantity for Value<U> {
+    type Dimension = U::Dimension;
+}
+
+impl<U: RuntimeUnit> fmt::Display for Value<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+
+        let entry = self.unit.entry();
+        match f.alternate() {
+            false => write!(f, " {}", entry.symbol()),
+            true if self.value == 1.0 => write!(f, " {}", entry.name()),
+            true => write!(f, " {}", entry.plural()),
+        }
+    }
+}
+
+impl<U: RuntimeUnit> FromStr for Value<U> {
+    type Err = ParseError;
+
+    /// Parse a value followed by the symbol, name or alias of a unit of the
+    /// enum (e.g. `"1200 ft"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, symbol) = parse::split(s)?;
+        Ok(Self::new(f64::from_rational(number), parse_unit(symbol)?))
+    }
+}
+
+/// A length in a unit selected at runtime.
+pub type Length = Value<crate::length::LengthUnit>;
+/// An area in a unit selected at runtime.
+pub type Area = Value<crate::area::AreaUnit>;
+/// A volume in a unit selected at runtime.
+pub type Volume = Value<crate::volume::VolumeUnit>;
+/// A mass in a unit selected at runtime.
+pub type Mass = Value<crate::mass::MassUnit>;
+/// A duration in a unit selected at runtime.
+pub type Time = Value<crate::time::TimeUnit>;
+/// A velocity in a unit selected at runtime.
+pub type Velocity = Value<crate::velocity::VelocityUnit>;
+/// A force in a unit selected at runtime.
+pub type Force = Value<crate::force::ForceUnit>;
+/// An energy in a unit selected at runtime.
+pub type Energy = Value<crate::energy::EnergyUnit>;
+/// A power in a unit selected at runtime.
+pub type Power = Value<crate::power::PowerUnit>;
+/// An (absolute) pressure in a unit selected at runtime.
+pub type Pressure = Value<crate::pressure::PressureUnit>;
+/// A gauge pressure in a unit selected at runtime.
+pub type GaugePressure = Value<crate::pressure::GaugePressureUnit>;
+/// A pressure difference in a unit selected at runtime.
+pub type PressureDifference = Value<crate::pressure::PressureDifferenceUnit>;
+/// An electric charge in a unit selected at runtime.
+pub type Charge = Value<crate::charge::ChargeUnit>;
+/// A voltage in a unit selected at runtime.
+pub type Voltage = Value<crate::voltage::VoltageUnit>;
+/// An electrical resistance in a unit selected at runtime.
+pub type Resistance = Value<crate::resistance::ResistanceUnit>;
+/// An electrical conductance in a unit selected at runtime.
+pub type Conductance = Value<crate::conductance::ConductanceUnit>;
+/// A capacitance in a unit selected at runtime.
+pub type Capacitance = Value<crate::capacitance::CapacitanceUnit>;
+/// An inductance in a unit selected at runtime.
+pub type Inductance = Value<crate::inductance::InductanceUnit>;
+/// A magnetic flux in a unit selected at runtime.
+pub type MagneticFlux = Value<crate::magnetic_flux::MagneticFluxUnit>;
+/// A magnetic flux density in a unit selected at runtime.
+pub type MagneticFluxDensity = Value<crate::magnetic_flux_density::MagneticFluxDensityUnit>;
+/// A temperature in a unit selected at runtime.
+pub type Temperature = Value<crate::temperature::TemperatureUnit>;
+/// A temperature interval in a unit selected at runtime.
+pub type TemperatureInterval = Value<crate::temperature::TemperatureIntervalUnit>;
+/// An electric current in a unit selected at runtime.
+pub type Current = Value<crate::current::CurrentUnit>;
+/// A luminous intensity in a unit selected at runtime.
+pub type LuminousIntensity = Value<crate::luminous_intensity::LuminousIntensityUnit>;
+/// A plane angle in a unit selected at runtime.
+pub type Angle = Value<crate::angle::AngleUnit>;
+/// A solid angle in a unit selected at runtime.
+pub type SolidAngle = Value<crate::solid_angle::SolidAngleUnit>;
+/// A luminous flux in a unit selected at runtime.
+pub type LuminousFlux = Value<crate::luminous_flux::LuminousFluxUnit>;
+/// An illuminance in a unit selected at runtime.
+pub type Illuminance = Value<crate::illuminance::IlluminanceUnit>;
+/// A luminance in a unit selected at runtime.
+pub type Luminance = Value<crate::luminance::LuminanceUnit>;
+/// A luminous energy in a unit selected at runtime.
+pub type LuminousEnergy = Value<crate::luminous_energy::LuminousEnergyUnit>;
+/// An amount of substance in a unit selected at runtime.
+pub type SubstanceAmount = Value<crate::substance_amount::SubstanceAmountUnit>;
+
+/// Display the symbol of the unit, or its name in the alternate form.
+pub(crate) fn fmt_unit<U: RuntimeUnit>(unit: U, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match f.alternate() {
+        false => f.pad(unit.entry().symbol()),
+        true => f.pad(unit.entry().name()),
+    }
+}
+
+/// Parse a unit of the enum `U` from its symbol, name or alias.
+///
+/// Only the units of `U` are matched, as symbols and names may be shared with
+/// units of other quantities (e.g. the kelvin is both a temperature and a
+/// temperature interval).
+pub(crate) fn parse_unit<U: RuntimeUnit>(s: &str) -> Result<U, ParseError> {
+    let units = || U::ALL.iter().copied();
+    units()
+        .find(|unit| unit.entry().has_symbol(s))
+        .or_else(|| units().find(|unit| unit.entry().has_name(s)))
+        .ok_or(match registry::find(s) {
+            Some(_) => ParseError::WrongQuantity,
+            None => ParseError::UnknownUnit,
+        })
+}
+
+/// The value in base units of `value` in the unit of the `entry`.
+fn to_base(entry: &Entry, value: f64) -> f64 {
+    affine(value, entry.factor(), entry.offset())
+}
+
+/// The value in the unit of the `entry` of `base` in base units.
+fn from_base(entry: &Entry, base: f64) -> f64 {
+    let factor = entry.factor().reciprocal();
+    match entry.offset().negated().checked_product(factor) {
+        Some(offset) => affine(base, factor, offset),
+        None => (base - entry.offset().to_f64()) * factor.to_f64(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "std")]
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::assert_quantity_eq;
+    #[cfg(feature = "std")]
+    use crate::length;
+    use crate::length::LengthUnit;
+    #[cfg(feature = "std")]
+    use crate::length::imperial::Foot;
+    #[cfg(feature = "std")]
+    use crate::temperature::Celsius;
+    #[cfg(feature = "std")]
+    use crate::temperature::TemperatureIntervalUnit;
+    use crate::temperature::TemperatureUnit;
+    #[cfg(feature = "std")]
+    use crate::velocity::VelocityUnit;
+    #[cfg(feature = "std")]
+    use crate::volume::VolumeUnit;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_and_display_units() {
+        assert_eq!("km".parse(), Ok(LengthUnit::KiloMeter));
+        assert_eq!("Kilometres".parse(), Ok(LengthUnit::KiloMeter));
+        assert_eq!("nmi".parse(), Ok(LengthUnit::NauticalMile));
+        assert_eq!("L".parse(), Ok(VolumeUnit::CubicDeciMeter));
+        assert_eq!("kg".parse::<LengthUnit>(), Err(ParseError::WrongQuantity));
+        assert_eq!("ell".parse::<LengthUnit>(), Err(ParseError::UnknownUnit));
+
+        assert_eq!(VelocityUnit::Knot.to_string(), "kn");
+        assert_eq!(format!("{:#}", VolumeUnit::UsGallon), "gallon");
+        assert_eq!(format!("{:>4}|", LengthUnit::Foot), "  ft|");
+    }
+
+    /// Every unit of `U` parses back from its symbol and its name.
+    #[cfg(feature = "std")]
+    fn round_trip<U>()
+    where
+        U: RuntimeUnit + FromStr<Err = ParseError> + fmt::Display + fmt::Debug,
+    {
+        for unit in U::ALL {
+            assert_eq!(unit.to_string().parse(), Ok(*unit));
+            assert_eq!(format!("{unit:#}").parse(), Ok(*unit));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn round_trip_all_units() {
+        round_trip::<crate::angle::AngleUnit>();
+        round_trip::<crate::area::AreaUnit>();
+        round_trip::<crate::capacitance::CapacitanceUnit>();
+        round_trip::<crate::charge::ChargeUnit>();
+        round_trip::<crate::conductance::ConductanceUnit>();
+        round_trip::<crate::current::CurrentUnit>();
+        round_trip::<crate::energy::EnergyUnit>();
+        round_trip::<crate::force::ForceUnit>();
+        round_trip::<crate::illuminance::IlluminanceUnit>();
+        round_trip::<crate::inductance::InductanceUnit>();
+        round_trip::<LengthUnit>();
+        round_trip::<crate::luminance::LuminanceUnit>();
+        round_trip::<crate::luminous_energy::LuminousEnergyUnit>();
+        round_trip::<crate::luminous_flux::LuminousFluxUnit>();
+        round_trip::<crate::luminous_intensity::LuminousIntensityUnit>();
+        round_trip::<crate::magnetic_flux::MagneticFluxUnit>();
+        round_trip::<crate::magnetic_flux_density::MagneticFluxDensityUnit>();
+        round_trip::<crate::mass::MassUnit>();
+        round_trip::<crate::power::PowerUnit>();
+        round_trip::<crate::pressure::GaugePressureUnit>();
+        round_trip::<crate::pressure::PressureUnit>();
+        round_trip::<crate::pressure::PressureDifferenceUnit>();
+        round_trip::<crate::resistance::ResistanceUnit>();
+        round_trip::<crate::solid_angle::SolidAngleUnit>();
+        round_trip::<crate::substance_amount::SubstanceAmountUnit>();
+        round_trip::<TemperatureIntervalUnit>();
+        round_trip::<TemperatureUnit>();
+        round_trip::<crate::time::TimeUnit>();
+        round_trip::<VelocityUnit>();
+        round_trip::<crate::voltage::VoltageUnit>();
+        round_trip::<VolumeUnit>();
+
+        assert_eq!("kelvin".parse(), Ok(TemperatureIntervalUnit::DeltaKelvin));
+        assert_eq!("K".parse(), Ok(TemperatureUnit::Kelvin));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn list_all_units() {
+        assert_eq!(
+            LengthUnit::ALL.len(),
+            registry::of(registry::Kind::Length).count()
+        );
+        assert_eq!(LengthUnit::ALL[0].entry().symbol(), "Qm");
+
+        for (i, unit) in VolumeUnit::ALL.iter().enumerate() {
+            assert_eq!(*unit as usize, i);
+            assert_eq!(unit.to_string().parse(), Ok(*unit));
+        }
+    }
+
+    #[test]
+    fn convert_between_units() {
+        assert_eq!(
+            LengthUnit::convert(1.0, LengthUnit::Foot, LengthUnit::Inch),
+            12.0
+        );
+        assert_eq!(
+            TemperatureUnit::convert(212.0, TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius),
+            100.0
+        );
+
+        let from = LengthUnit::QuettaMeter;
+        let to = LengthUnit::QuectoMeter;
+        assert!((LengthUnit::convert(1.0, from, to) / 1E60 - 1.0).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn values_in_runtime_units() {
+        let length = Length::new(3.0, LengthUnit::Foot);
+        assert_eq!(
+            length.to(LengthUnit::Inch),
+            Length::new(36.0, LengthUnit::Inch)
+        );
+        assert_eq!(Foot::from_base(length.to_base()), Foot(3.0));
+        assert_eq!(format!("{length:#}"), "3 feet");
+
+        let length: &dyn length::Length = &length;
+        assert_quantity_eq!(*length, Foot(3.0));
+
+        let temperature: Temperature = "25 °C".parse().unwrap();
+        assert_quantity_eq!(temperature, Celsius(25.0));
+        assert_eq!(Temperature::from_base(0.0).unit(), TemperatureUnit::Kelvin);
+
+        assert_eq!("3 kg".parse::<Length>(), Err(ParseError::WrongQuantity));
+    }
+}
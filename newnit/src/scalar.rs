@@ -0,0 +1,631 @@
+//! Numeric storage types of units.
+//!
+//! Units are generic over the numeric type storing their value, which defaults
+//! to [`f64`]. Any type implementing the [`Scalar`] trait can be used, the
+//! library provides implementations for:
+//! - [`f32`] and [`f64`]
+//! - all primitive integer types (conversions round to the nearest integer, and
+//!   saturate at the bounds of the type)
+//! - `rust_decimal::Decimal` (with the `rust_decimal` feature)
+//! - `num_rational::Ratio<i32>` and `Ratio<i64>` (with the `num-rational`
+//!   feature)
+//!
+//! Decimals and ratios are scaled exactly whenever the result is representable,
+//! approximated otherwise, and saturate at the bounds of the type as well.
+//!
+//! # Examples
+//! ```
+//! use newnit::Unit;
+//! use newnit::length::metric::{Meter, MilliMeter};
+//!
+//! let width = MilliMeter(1_500_i32);
+//! let height = MilliMeter(800_i32);
+//!
+//! assert_eq!(width + &height, MilliMeter(2_300));
+//! assert_eq!(width * 2, MilliMeter(3_000));
+//!
+//! let single_precision = Meter::from(&MilliMeter(250.0_f32));
+//! assert_eq!(single_precision.to_value(), 0.25);
+//! ```
+
+use core::fmt::{Debug, Display};
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::rational::Rational;
+#[cfg(feature = "num-rational")]
+use crate::rational::gcd;
+
+/// A numeric type which can store the value of a unit.
+///
+/// Conversion factors and offsets of units are [`Rational`]s, so implementors
+/// define how to convert from (and to) [`f64`] and [`Rational`], and how to
+/// scale a value by a conversion factor.
+pub trait Scalar:
+    Copy
+    + Default
+    + Debug
+    + Display
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Converts an [`f64`] to the closest representable value.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts the value to the closest [`f64`].
+    fn to_f64(self) -> f64;
+
+    /// Converts a [`Rational`] to the closest representable value.
+    fn from_rational(value: Rational) -> Self {
+        Self::from_f64(value.to_f64())
+    }
+
+    /// Converts an [`f64`] to the closest representable value, or [`None`] if
+    /// it is out of range.
+    fn try_from_f64(value: f64) -> Option<Self> {
+        Some(Self::from_f64(value))
+    }
+
+    /// Converts a [`Rational`] to the closest representable value, or [`None`]
+    /// if it is out of range.
+    fn try_from_rational(value: Rational) -> Option<Self> {
+        Self::try_from_f64(value.to_f64())
+    }
+
+    /// The value as an exact [`Rational`], or [`None`] if the type doesn't
+    /// represent values exactly (floats) or the value is out of range.
+    fn to_rational(self) -> Option<Rational> {
+        None
+    }
+
+    /// Multiplies the value by a conversion factor.
+    ///
+    /// Values of types representing them exactly are multiplied as
+    /// [`Rational`]s and converted back only at the end, so that they are
+    /// scaled exactly whenever the result is representable. Others (or products
+    /// that overflow) are scaled as [`f64`]s.
+    fn scale(self, factor: Rational) -> Self {
+        match self
+            .to_rational()
+            .and_then(|value| value.checked_product(factor))
+        {
+            Some(value) => Self::from_rational(value),
+            None => Self::from_f64(self.to_f64() * factor.to_f64()),
+        }
+    }
+
+    /// Multiplies the value by a conversion factor, or returns [`None`] if the
+    /// result is out of range.
+    fn checked_scale(self, factor: Rational) -> Option<Self> {
+        match self
+            .to_rational()
+            .and_then(|value| value.checked_product(factor))
+        {
+            Some(value) => Self::try_from_rational(value),
+            None => Self::try_from_f64(self.to_f64() * factor.to_f64()),
+        }
+    }
+
+    /// Adds `rhs`, or returns [`None`] if the sum overflows.
+    ///
+    /// Values of types representing them exactly are added as [`Rational`]s,
+    /// others (floats) can't overflow, but become infinite instead.
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        match (self.to_rational(), rhs.to_rational()) {
+            (Some(left), Some(right)) => exact(left.checked_sum(right)?),
+            _ => Some(self + rhs),
+        }
+    }
+
+    /// Subtracts `rhs`, or returns [`None`] if the difference overflows.
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match (self.to_rational(), rhs.to_rational()) {
+            (Some(left), Some(right)) => exact(left.checked_difference(right)?),
+            _ => Some(self - rhs),
+        }
+    }
+
+    /// Multiplies by `rhs`, or returns [`None`] if the product overflows.
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match (self.to_rational(), rhs.to_rational()) {
+            (Some(left), Some(right)) => exact(left.checked_product(right)?),
+            _ => Some(self * rhs),
+        }
+    }
+
+    /// Divides by `rhs`, or returns [`None`] if the quotient overflows or
+    /// `rhs` is zero (for types representing values exactly).
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        match (self.to_rational(), rhs.to_rational()) {
+            (Some(left), Some(right)) => exact(left.checked_quotient(right)?),
+            _ => Some(self / rhs),
+        }
+    }
+}
+
+/// The value of a [`Rational`], if representable exactly.
+fn exact<V: Scalar>(value: Rational) -> Option<V> {
+    V::try_from_rational(value).filter(|result| result.to_rational() == Some(value))
+}
+
+impl Scalar for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    // Scaling is done in double precision, so that single precision values
+    // don't pick up the rounding error of the conversion factor.
+    fn scale(self, factor: Rational) -> Self {
+        (self as f64).scale(factor) as f32
+    }
+}
+
+impl Scalar for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    // If the numerator and denominator of the factor (including its power of
+    // ten) are exact `f64`s, values are multiplied by the numerator and
+    // divided by the denominator. That rounds once if the product is exact
+    // (e.g. of whole numbers while it stays within 2^53), and twice otherwise.
+    // Failing that, whole numbers are scaled as `Rational`s and rounded once,
+    // other values are multiplied by the factor rounded to an `f64`.
+    fn scale(self, factor: Rational) -> Self {
+        let power = i128::checked_pow(10, factor.exponent().unsigned_abs());
+        let (numer, denom) = match factor.exponent() < 0 {
+            true => (
+                Some(factor.numer()),
+                power.and_then(|power| factor.denom().checked_mul(power)),
+            ),
+            false => (
+                power.and_then(|power| factor.numer().checked_mul(power)),
+                Some(factor.denom()),
+            ),
+        };
+
+        let exact = match (numer, denom) {
+            (Some(numer), Some(denom))
+                if numer.unsigned_abs() <= EXACT && denom <= EXACT as i128 =>
+            {
+                Some((self * numer as f64, denom as f64))
+            }
+            _ => None,
+        };
+
+        // Products with the numerator overflow for values close to the largest
+        // `f64`s, which are then scaled by the factor as a whole.
+        match exact {
+            Some((product, denom)) if product.is_finite() => product / denom,
+            _ => match whole(self)
+                .and_then(|value| factor.checked_product(Rational::integer(value)))
+            {
+                Some(value) => value.to_f64(),
+                None => self * factor.to_f64(),
+            },
+        }
+    }
+}
+
+/// Floating point scalars ([`f32`] and [`f64`]), which store fractions of any
+/// unit (e.g. for the trigonometry of [angles](crate::angle::Angle)).
+pub trait Float: Scalar {}
+
+impl Float for f32 {}
+
+impl Float for f64 {}
+
+/// The largest integer up to which all integers are exact `f64`s (2^53).
+const EXACT: u128 = 1 << 53;
+
+/// The value as an integer, if it is a whole number within the range of
+/// [`i64`].
+fn whole(value: f64) -> Option<i128> {
+    let integer = value as i64;
+    (integer as f64 == value && value.abs() < 9.2e18).then_some(integer as i128)
+}
+
+/// Rounds half away from zero (`f64::round` isn't available without `std`).
+fn round(value: f64) -> f64 {
+    // Floats this large have no fractional part.
+    if value.abs() >= 4_503_599_627_370_496.0 || value.is_nan() {
+        return value;
+    }
+
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+// Integers are scaled exactly and rounded to the nearest integer, falling back
+// to floating point if the exact computation overflows. Values out of range
+// saturate at the bounds of the type.
+macro_rules! impl_scalar_int {
+    ($($int:ty),*) => {
+        $(
+            impl Scalar for $int {
+                fn from_f64(value: f64) -> Self {
+                    round(value) as $int
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_rational(value: Rational) -> Self {
+                    match value.scale_integer(1) {
+                        Some(value) => <$int>::try_from(value).unwrap_or(match value < 0 {
+                            true => <$int>::MIN,
+                            false => <$int>::MAX,
+                        }),
+                        None => Self::from_f64(value.to_f64()),
+                    }
+                }
+
+                // The bounds of the wider types round up to a power of two as
+                // floats, which is just out of range.
+                fn try_from_f64(value: f64) -> Option<Self> {
+                    let value = round(value);
+                    (value >= <$int>::MIN as f64 && value < <$int>::MAX as f64 + 1.0)
+                        .then_some(value as $int)
+                }
+
+                fn try_from_rational(value: Rational) -> Option<Self> {
+                    match value.scale_integer(1) {
+                        Some(value) => <$int>::try_from(value).ok(),
+                        None => Self::try_from_f64(value.to_f64()),
+                    }
+                }
+
+                fn to_rational(self) -> Option<Rational> {
+                    i128::try_from(self).ok().map(Rational::integer)
+                }
+
+                fn scale(self, factor: Rational) -> Self {
+                    let value = i128::try_from(self).ok();
+                    match value.and_then(|value| factor.scale_integer(value)) {
+                        Some(value) => Self::from_rational(Rational::integer(value)),
+                        None => Self::from_f64(self as f64 * factor.to_f64()),
+                    }
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, rhs)
+                }
+
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_div(self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_scalar_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+/// The bound (`min` or `max`) at which a value out of range of a type
+/// saturates, or zero for NaNs and values too small to represent.
+#[cfg(any(feature = "rust_decimal", feature = "num-rational"))]
+fn saturated<V: Scalar>(value: f64, min: V, max: V) -> V {
+    if value.is_nan() || (-1.0 < value && value < 1.0) {
+        V::default()
+    } else if value < 0.0 {
+        min
+    } else {
+        max
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Scalar for rust_decimal::Decimal {
+    fn from_f64(value: f64) -> Self {
+        Self::try_from_f64(value).unwrap_or_else(|| saturated(value, Self::MIN, Self::MAX))
+    }
+
+    fn to_f64(self) -> f64 {
+        use rust_decimal::prelude::ToPrimitive;
+
+        ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
+    }
+
+    fn from_rational(value: Rational) -> Self {
+        Self::try_from_rational(value).unwrap_or_else(|| Self::from_f64(value.to_f64()))
+    }
+
+    fn try_from_f64(value: f64) -> Option<Self> {
+        use rust_decimal::prelude::FromPrimitive;
+
+        <Self as FromPrimitive>::from_f64(value)
+    }
+
+    fn try_from_rational(value: Rational) -> Option<Self> {
+        use rust_decimal::prelude::FromPrimitive;
+
+        let exact = || {
+            let power = 10_i128.checked_pow(value.exponent().unsigned_abs())?;
+            let power = <Self as FromPrimitive>::from_i128(power)?;
+            let numer = <Self as FromPrimitive>::from_i128(value.numer())?;
+            let denom = <Self as FromPrimitive>::from_i128(value.denom())?;
+
+            match value.exponent() < 0 {
+                true => numer.checked_div(denom)?.checked_div(power),
+                false => numer.checked_div(denom)?.checked_mul(power),
+            }
+        };
+
+        exact().or_else(|| Self::try_from_f64(value.to_f64()))
+    }
+
+    fn to_rational(self) -> Option<Rational> {
+        let exponent = -i32::try_from(rust_decimal::Decimal::scale(&self)).ok()?;
+
+        Rational::checked_new(self.mantissa(), 1, exponent)
+    }
+
+    // Decimals round the results of their operations to their precision.
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked_mul(self, rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::checked_div(self, rhs)
+    }
+}
+
+#[cfg(feature = "num-rational")]
+macro_rules! impl_scalar_ratio {
+    ($($int:ty),*) => {
+        $(
+            impl Scalar for num_rational::Ratio<$int> {
+                fn from_f64(value: f64) -> Self {
+                    Self::try_from_f64(value).unwrap_or_else(|| {
+                        saturated(value, Self::from_integer(<$int>::MIN), Self::from_integer(<$int>::MAX))
+                    })
+                }
+
+                fn to_f64(self) -> f64 {
+                    *self.numer() as f64 / *self.denom() as f64
+                }
+
+                fn from_rational(value: Rational) -> Self {
+                    Self::try_from_rational(value).unwrap_or_else(|| {
+                        Self::from_f64(value.to_f64())
+                    })
+                }
+
+                fn try_from_f64(value: f64) -> Option<Self> {
+                    Self::approximate_float(value)
+                }
+
+                fn try_from_rational(value: Rational) -> Option<Self> {
+                    // The power of ten is applied in `i128`, so that the ratio
+                    // is only converted (and reduced) once.
+                    let exact = || {
+                        let power = 10_i128.checked_pow(value.exponent().unsigned_abs())?;
+                        let (numer, denom) = match value.exponent() < 0 {
+                            true => (value.numer(), value.denom().checked_mul(power)?),
+                            false => (value.numer().checked_mul(power)?, value.denom()),
+                        };
+                        let divisor = gcd(numer, denom);
+
+                        Some(Self::new(
+                            <$int>::try_from(numer / divisor).ok()?,
+                            <$int>::try_from(denom / divisor).ok()?,
+                        ))
+                    };
+
+                    exact().or_else(|| Self::try_from_f64(value.to_f64()))
+                }
+
+                fn to_rational(self) -> Option<Rational> {
+                    Rational::checked_new((*self.numer()).into(), (*self.denom()).into(), 0)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "num-rational")]
+impl_scalar_ratio!(i32, i64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::imperial::Inch;
+    use crate::length::metric::{CentiMeter, Meter, MilliMeter};
+    use crate::{Unit, assert_quantity_eq};
+
+    #[test]
+    fn integers_round_to_nearest() {
+        let milli = Rational::new(1, 1, -3);
+        assert_eq!(1_500_i32.scale(milli), 2);
+        assert_eq!(1_499_i32.scale(milli), 1);
+        assert_eq!((-1_500_i32).scale(milli), -2);
+        assert_eq!(3_u8.scale(Rational::integer(2)), 6);
+        assert_eq!(i32::from_f64(-2.5), -3);
+        assert_eq!(i32::from_f64(0.499_999_999_999_999_94), 0);
+        assert_eq!(u8::from_f64(300.0), 255);
+    }
+
+    #[test]
+    fn integers_saturate() {
+        assert_eq!(u8::from_rational(Rational::integer(300)), 255);
+        assert_eq!(u8::from_rational(Rational::integer(-3)), 0);
+        assert_eq!(200_u8.scale(Rational::integer(1_000)), 255);
+        assert_eq!((-100_i8).scale(Rational::integer(10)), -128);
+        assert_eq!(5_000_000_u32.scale(Rational::integer(1_000)), u32::MAX);
+        assert_eq!(u128::MAX.scale(Rational::integer(2)), u128::MAX);
+        assert!(u128::MAX.scale(Rational::new(1, 2, 0)) > u128::MAX / 3);
+
+        assert_eq!(u8::try_from_rational(Rational::integer(255)), Some(255));
+        assert_eq!(u8::try_from_rational(Rational::integer(256)), None);
+        assert_eq!(u8::try_from_rational(Rational::integer(-3)), None);
+        assert_eq!(u64::try_from_f64(18_446_744_073_709_551_616.0), None);
+        assert_eq!(
+            i64::try_from_f64(-9_223_372_036_854_775_808.0),
+            Some(i64::MIN)
+        );
+        assert_eq!(i32::try_from_f64(f64::NAN), None);
+        assert!(u128::try_from_rational(Rational::new(3, 1, 38)).is_some());
+        assert_eq!(u128::try_from_rational(Rational::new(4, 1, 38)), None);
+    }
+
+    #[test]
+    fn floats_scale_exactly() {
+        assert_eq!(2.0_f32.scale(Rational::new(1, 2, 0)), 1.0);
+        assert_eq!(1_500.0_f32.scale(Rational::new(1, 1, -3)), 1.5);
+        assert_eq!(12.0_f64.scale(Rational::new(1, 12, 0)), 1.0);
+    }
+
+    #[test]
+    fn floats_scale_large_values() {
+        use crate::angle::metric::{Degree, Radian};
+        use crate::length::imperial::Foot;
+        use crate::length::metric::{KiloMeter, QuectoMeter};
+
+        let angle = Radian::from(&Degree(1e300));
+        assert_quantity_eq!(angle, Radian(1e300 * core::f64::consts::PI / 180.0));
+        assert_quantity_eq!(Foot::from(&Meter(1e307)), Foot(1e307 / 0.3048));
+        assert_eq!(Meter::from(&KiloMeter(1e305)), Meter(1e308));
+        assert_eq!(QuectoMeter::from(&Meter(1e270)), QuectoMeter(1e300));
+        assert_eq!(Meter::from(&KiloMeter(f64::MAX)).0, f64::INFINITY);
+    }
+
+    #[test]
+    fn integer_units_add_exactly() {
+        let mut length = MilliMeter(1_500_i32) + &MilliMeter(800);
+        assert_eq!(length, MilliMeter(2_300));
+
+        length -= &CentiMeter(3);
+        assert_eq!(length, MilliMeter(2_270));
+    }
+
+    #[test]
+    fn convert_integer_units() {
+        assert_eq!(MilliMeter::from(&Inch(3_u32)), MilliMeter(76));
+        assert_eq!(Meter::from(&MilliMeter(2_600_i64)), Meter(3));
+
+        assert_eq!(
+            MilliMeter::from(&Meter(5_000_000_u32)),
+            MilliMeter(u32::MAX)
+        );
+        assert_eq!(MilliMeter::from(&Meter(200_u8)), MilliMeter(255));
+        assert_eq!(Meter::from(&MilliMeter(-1_000_i16)), Meter(-1));
+    }
+
+    #[test]
+    fn single_precision_units() {
+        let length = Meter::from(&MilliMeter(250.0_f32)) * 4.0;
+        assert_eq!(length.to_value(), 1.0_f32);
+    }
+
+    #[cfg(all(feature = "rust_decimal", feature = "std"))]
+    #[test]
+    fn decimal_units() {
+        use rust_decimal::Decimal;
+
+        let length = MilliMeter(Decimal::new(1505, 1)) + &CentiMeter(Decimal::new(2, 0));
+        assert_eq!(length.to_value(), Decimal::new(1705, 1));
+        assert_eq!(length.to_string(), "170.5 mm");
+
+        assert_eq!(Decimal::try_from_rational(Rational::new(1, 1, 30)), None);
+        assert_eq!(Decimal::try_from_f64(f64::INFINITY), None);
+
+        assert_eq!(
+            Decimal::new(1505, 1).to_rational(),
+            Some(Rational::new(301, 2, 0))
+        );
+        assert!(MilliMeter(Decimal::new(1, 0)) == CentiMeter(Decimal::new(1, 1)));
+
+        assert_eq!(
+            Decimal::from_rational(Rational::new(1, 1, 30)),
+            Decimal::MAX
+        );
+        assert_eq!(Decimal::from_f64(f64::NAN), Decimal::ZERO);
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn rational_units() {
+        use num_rational::Ratio;
+
+        let length = Meter(Ratio::new(1_i64, 3)) * Ratio::new(3, 2);
+        assert_eq!(length.to_value(), Ratio::new(1, 2));
+
+        assert_eq!(
+            Ratio::<i32>::try_from_rational(Rational::new(1, 1, 30)),
+            None
+        );
+
+        assert_eq!(
+            Ratio::new(2_i32, 6).to_rational(),
+            Some(Rational::new(1, 3, 0))
+        );
+        assert!(Meter(Ratio::new(1_i64, 3)) > CentiMeter(Ratio::new(33_i64, 1)));
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn convert_rational_units_with_large_factors() {
+        use crate::angle::metric::{Degree, Radian};
+        use crate::length::astronomical::{LightYear, Parsec};
+        use num_rational::Ratio;
+
+        let angle = Radian::from(&Degree(Ratio::<i32>::new(180, 1)));
+        assert!((angle.to_value().to_f64() - core::f64::consts::PI).abs() < 1e-9);
+        assert_eq!(
+            Degree::from(&Radian(Ratio::<i64>::new(0, 1))),
+            Degree(Ratio::new(0, 1))
+        );
+
+        assert_eq!(
+            Meter::from(&LightYear(Ratio::<i32>::new(1, 1))),
+            Meter(Ratio::from_integer(i32::MAX))
+        );
+        assert_eq!(
+            Meter::from(&LightYear(Ratio::<i64>::new(-1, 1))),
+            Meter(Ratio::from_integer(-9_460_730_472_580_800))
+        );
+        let parsec = Meter::from(&Parsec(Ratio::<i64>::new(1, 1)));
+        assert!((parsec.to_value().to_f64() / 3.085_677_581_491_367e16 - 1.0).abs() < 1e-12);
+
+        assert_eq!(Ratio::<i32>::from_f64(f64::NAN), Ratio::from_integer(0));
+        assert_eq!(
+            Ratio::<i32>::from_rational(Rational::new(-1, 1, 30)),
+            Ratio::from_integer(i32::MIN)
+        );
+    }
+}
@@ -0,0 +1,510 @@
+//! Serialization of units with [serde](https://serde.rs).
+//!
+//! Units serialize as their value together with the symbol of their unit
+//! (e.g. `{"value": 3.0, "unit": "ft"}`), so that the unit isn't lost. They
+//! deserialize from a value in any unit of their quantity (including
+//! [`CompoundUnit`](crate::parse::CompoundUnit) expressions), converting it,
+//! while values of other quantities fail to deserialize.
+//!
+//! All units defined in this library implement `Serialize` and `Deserialize`
+//! with the `serde` feature enabled. Custom units opt in with
+//! `#[quantity(serde)]` of the quantity derive macros.
+//!
+//! # Examples
+//! ```
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::Meter;
+//!
+//! let json = serde_json::to_string(&Foot(3.0)).unwrap();
+//! assert_eq!(json, r#"{"value":3.0,"unit":"ft"}"#);
+//!
+//! let length: Meter = serde_json::from_str(&json).unwrap();
+//! assert!((length.0 - 0.9144).abs() < 1e-9);
+//!
+//! let error = serde_json::from_str::<Meter>(r#"{"value":3.0,"unit":"kg"}"#);
+//! assert!(
+//!     error
+//!         .unwrap_err()
+//!         .to_string()
+//!         .starts_with("Mismatched dimensions")
+//! );
+//! ```
+//!
+//! # Fields in other units
+//!
+//! Formats fixed to a unit other than the unit of a field are supported by the
+//! [`as_unit`] and [`as_unit_string`] adapters of `#[serde(with = "...")]`,
+//! which store the field in the given unit as a bare number (e.g. `1200.0`),
+//! or as a string with the symbol of the unit (e.g. `"1200 ft"`):
+//!
+//! ```
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::Meter;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Report {
+//!     #[serde(with = "newnit::serde::as_unit::<Foot>")]
+//!     altitude: Meter,
+//!     #[serde(with = "newnit::serde::as_unit_string::<Foot>")]
+//!     ceiling: Meter,
+//! }
+//!
+//! let report = Report {
+//!     altitude: Meter(365.76),
+//!     ceiling: Meter(914.4),
+//! };
+//! let json = serde_json::to_string(&report).unwrap();
+//! assert_eq!(json, r#"{"altitude":1200.0,"ceiling":"3000 ft"}"#);
+//!
+//! let report: Report =
+//!     serde_json::from_str(r#"{"altitude":100,"ceiling":"1 km"}"#).unwrap();
+//! assert!((report.altitude.0 - 30.48).abs() < 1e-9);
+//! assert!((report.ceiling.0 - 1000.0).abs() < 1e-9);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, MapAccess, SeqAccess};
+use ::serde::ser::SerializeStruct;
+pub use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::dimension::Dimension;
+use crate::dyn_quantity::{DimensionError, DynDimension};
+use crate::parse::{self, ParseError, UnitInfo};
+use crate::runtime::{self, RuntimeUnit, Value};
+use crate::scalar::Scalar;
+use crate::unit::{Conversion, affine};
+use crate::{Quantity, Unit};
+
+/// Names of the fields of serialized units.
+const FIELDS: &[&str] = &["value", "unit"];
+
+/// Serialize a unit as its value with its symbol.
+pub fn serialize<U, V, S>(unit: &U, serializer: S) -> Result<S::Ok, S::Error>
+where
+    U: Unit<V> + ?Sized,
+    V: Serialize,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct("Unit", FIELDS.len())?;
+    state.serialize_field("value", &unit.to_value())?;
+    state.serialize_field("unit", unit.symbol())?;
+    state.end()
+}
+
+/// Deserialize a unit from a value with the symbol of any unit of its
+/// quantity.
+pub fn deserialize<'de, U, V, D>(deserializer: D) -> Result<U, D::Error>
+where
+    U: Quantity<V> + Conversion,
+    V: Scalar + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let (value, unit) = deserializer.deserialize_struct("Unit", FIELDS, Visitor(PhantomData))?;
+    check::<U::Dimension, D::Error>(&unit)?;
+
+    let out_of_range = || de::Error::custom(ParseError::OutOfRange);
+    let factor = unit
+        .factor
+        .checked_quotient(U::FACTOR)
+        .ok_or_else(out_of_range)?;
+    let offset = unit
+        .offset
+        .checked_difference(U::OFFSET)
+        .and_then(|offset| offset.checked_quotient(U::FACTOR))
+        .ok_or_else(out_of_range)?;
+
+    Ok(U::from_value(affine(value, factor, offset)))
+}
+
+/// Check that the deserialized `unit` is of the dimension `D`.
+fn check<D: Dimension, E: de::Error>(unit: &UnitInfo) -> Result<(), E> {
+    if unit.exponents != D::EXPONENTS {
+        return Err(E::custom(DimensionError {
+            expected: DynDimension::of::<D>(),
+            found: unit.dimension(),
+        }));
+    }
+    if unit.affine != D::AFFINE
+        || unit.difference != D::DIFFERENCE
+        || unit.angle != D::ANGLE
+        || unit.solid_angle != D::SOLID_ANGLE
+    {
+        return Err(E::custom(ParseError::WrongQuantity));
+    }
+
+    Ok(())
+}
+
+/// Adapter storing a unit as a bare number in the unit `U`.
+///
+/// Used as `#[serde(with = "newnit::serde::as_unit::<Foot>")]` on fields of
+/// any unit of the quantity of `U` (see the [module docs](self)).
+#[allow(non_camel_case_types)]
+pub type as_unit<U> = AsUnit<U>;
+
+/// Adapter storing a unit as a string of its value in the unit `U` (e.g.
+/// `"1200 ft"`).
+///
+/// Used as `#[serde(with = "newnit::serde::as_unit_string::<Foot>")]` on
+/// fields of any unit of the quantity of `U` (see the [module docs](self)).
+/// Strings in other units of the quantity are converted, bare numbers are taken
+/// to be in the unit `U`.
+#[allow(non_camel_case_types)]
+pub type as_unit_string<U> = AsUnitString<U>;
+
+/// The functions of the [`as_unit`] adapter.
+///
+/// `#[serde(with)]` takes a path to its `serialize` and `deserialize`
+/// functions, which, being generic over `U`, are associated functions of this
+/// type.
+pub struct AsUnit<U>(PhantomData<U>);
+
+impl<U> AsUnit<U> {
+    /// Serialize the value of `unit` converted to the unit `U`.
+    pub fn serialize<T, V, S>(unit: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Quantity<V> + Conversion,
+        U: Quantity<V, Dimension = T::Dimension> + Conversion,
+        V: Scalar + Serialize,
+        S: Serializer,
+    {
+        U::convert_from::<T, V>(unit.to_value()).serialize(serializer)
+    }
+
+    /// Deserialize a value in the unit `U`, converted to the unit `T`.
+    pub fn deserialize<'de, T, V, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Quantity<V> + Conversion,
+        U: Quantity<V, Dimension = T::Dimension> + Conversion,
+        V: Scalar + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let value = V::deserialize(deserializer)?;
+        Ok(T::from_value(T::convert_from::<U, V>(value)))
+    }
+}
+
+/// The functions of the [`as_unit_string`] adapter (see [`AsUnit`]).
+pub struct AsUnitString<U>(PhantomData<U>);
+
+impl<U> AsUnitString<U> {
+    /// Serialize `unit` converted to the unit `U` as a string.
+    pub fn serialize<T, V, S>(unit: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Quantity<V> + Conversion,
+        U: Quantity<V, Dimension = T::Dimension> + Conversion + fmt::Display,
+        V: Scalar,
+        S: Serializer,
+    {
+        serializer.collect_str(&U::from_value(U::convert_from::<T, V>(unit.to_value())))
+    }
+
+    /// Deserialize a string of a value in any unit of the quantity of `U`,
+    /// converted to the unit `T`.
+    pub fn deserialize<'de, T, V, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Quantity<V> + Conversion,
+        U: Quantity<V, Dimension = T::Dimension> + Conversion,
+        V: Scalar,
+        D: Deserializer<'de>,
+    {
+        let unit: U = deserializer.deserialize_str(StrVisitor(PhantomData))?;
+        Ok(T::from_value(T::convert_from::<U, V>(unit.to_value())))
+    }
+}
+
+impl<U: RuntimeUnit> Serialize for Value<U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, U: RuntimeUnit> Deserialize<'de> for Value<U> {
+    /// Deserialize a value in a unit of the enum `U`, or in another unit of its
+    /// quantity converted to the base unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (value, unit) =
+            deserializer.deserialize_struct("Unit", FIELDS, Visitor(PhantomData))?;
+        check::<U::Dimension, D::Error>(&unit)?;
+
+        let found = U::ALL
+            .iter()
+            .find(|variant| variant.entry().symbol() == unit.symbol);
+        match found {
+            Some(variant) => Ok(Value::new(value, *variant)),
+            None => Ok(Value::from_base(affine(value, unit.factor, unit.offset))),
+        }
+    }
+}
+
+/// Serialize a unit of a [`RuntimeUnit`] enum as its symbol.
+pub(crate) fn serialize_runtime_unit<U, S>(unit: U, serializer: S) -> Result<S::Ok, S::Error>
+where
+    U: RuntimeUnit,
+    S: Serializer,
+{
+    serializer.serialize_str(unit.entry().symbol())
+}
+
+/// Deserialize a unit of a [`RuntimeUnit`] enum from its symbol, name or
+/// alias.
+pub(crate) fn deserialize_runtime_unit<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+where
+    U: RuntimeUnit,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(RuntimeUnitVisitor(PhantomData))
+}
+
+/// Visitor of a value with the symbol of its unit.
+struct Visitor<V>(PhantomData<V>);
+
+impl<'de, V: Deserialize<'de>> de::Visitor<'de> for Visitor<V> {
+    type Value = (V, UnitInfo);
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a value with the symbol of its unit")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let Symbol(unit) = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok((value, unit))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        let mut unit = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Value if value.is_some() => {
+                    return Err(de::Error::duplicate_field("value"));
+                }
+                Field::Value => value = Some(map.next_value()?),
+                Field::Unit if unit.is_some() => {
+                    return Err(de::Error::duplicate_field("unit"));
+                }
+                Field::Unit => unit = Some(map.next_value::<Symbol>()?.0),
+            }
+        }
+
+        Ok((
+            value.ok_or_else(|| de::Error::missing_field("value"))?,
+            unit.ok_or_else(|| de::Error::missing_field("unit"))?,
+        ))
+    }
+}
+
+/// A field of a serialized unit.
+#[derive(::serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Value,
+    Unit,
+}
+
+/// A unit deserialized from its symbol.
+struct Symbol(UnitInfo);
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(SymbolVisitor)
+    }
+}
+
+/// Visitor of the symbol of a unit.
+struct SymbolVisitor;
+
+impl de::Visitor<'_> for SymbolVisitor {
+    type Value = Symbol;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the symbol of a unit")
+    }
+
+    fn visit_str<E: de::Error>(self, symbol: &str) -> Result<Self::Value, E> {
+        match parse::resolve(symbol) {
+            Ok(unit) => Ok(Symbol(unit)),
+            Err(error) => Err(E::custom(format_args!("{error}: `{symbol}`"))),
+        }
+    }
+}
+
+/// Visitor of a string of a value in a unit, parsed as the unit `U`.
+struct StrVisitor<U, V>(PhantomData<(U, V)>);
+
+impl<U: Quantity<V> + Conversion, V: Scalar> de::Visitor<'_> for StrVisitor<U, V> {
+    type Value = U;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a value with the symbol of its unit")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        parse::parse_unit(s).map_err(|error| E::custom(format_args!("{error}: `{s}`")))
+    }
+}
+
+/// Visitor of the symbol of a unit of a [`RuntimeUnit`] enum.
+struct RuntimeUnitVisitor<U>(PhantomData<U>);
+
+impl<U: RuntimeUnit> de::Visitor<'_> for RuntimeUnitVisitor<U> {
+    type Value = U;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the symbol of a unit")
+    }
+
+    fn visit_str<E: de::Error>(self, symbol: &str) -> Result<Self::Value, E> {
+        runtime::parse_unit(symbol).map_err(|error| E::custom(format_args!("{error}: `{symbol}`")))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::length::LengthUnit;
+    use crate::length::imperial::Foot;
+    use crate::length::metric::{KiloMeter, Meter, MilliMeter};
+    use crate::luminous_intensity::metric::Candela;
+    use crate::temperature::{Celsius, DeltaCelsius, Fahrenheit, Kelvin};
+    use crate::velocity::metric::MeterPerSecond;
+
+    #[test]
+    fn serialize_value_with_unit() {
+        let json = serde_json::to_string(&KiloMeter(3.5)).unwrap();
+        assert_eq!(json, r#"{"value":3.5,"unit":"km"}"#);
+
+        let json = serde_json::to_string(&Celsius(21_i32)).unwrap();
+        assert_eq!(json, r#"{"value":21,"unit":"°C"}"#);
+    }
+
+    #[test]
+    fn deserialize_and_convert() {
+        let length: Meter = serde_json::from_str(r#"{"value":1.5,"unit":"km"}"#).unwrap();
+        assert_eq!(length, Meter(1_500.0));
+
+        let length: Foot = serde_json::from_str(r#"{"unit":"in","value":36}"#).unwrap();
+        assert_eq!(length, Foot(3.0));
+
+        let speed: MeterPerSecond = serde_json::from_str(r#"{"value":36,"unit":"km/h"}"#).unwrap();
+        assert_eq!(speed, MeterPerSecond(10.0));
+
+        let temperature: Kelvin = serde_json::from_str(r#"{"value":0,"unit":"°C"}"#).unwrap();
+        assert_eq!(temperature, Kelvin(273.15));
+
+        let length: Meter = serde_json::from_str("[2.0, \"m\"]").unwrap();
+        assert_eq!(length, Meter(2.0));
+    }
+
+    #[test]
+    fn reject_other_quantities() {
+        let error = serde_json::from_str::<Meter>(r#"{"value":3,"unit":"kg"}"#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("Mismatched dimensions: expected m, found kg")
+        );
+
+        let error = serde_json::from_str::<DeltaCelsius>(r#"{"value":3,"unit":"°C"}"#);
+        assert!(error.is_err());
+
+        let error = serde_json::from_str::<Candela>(r#"{"value":3,"unit":"lm"}"#);
+        assert!(error.is_err());
+
+        let error = serde_json::from_str::<Meter>(r#"{"value":3,"unit":"parsec"}"#);
+        assert!(error.unwrap_err().to_string().starts_with("Unknown unit"));
+
+        let error = serde_json::from_str::<Meter>(r#"{"value":3}"#);
+        assert!(
+            error
+                .unwrap_err()
+                .to_string()
+                .starts_with("missing field `unit`")
+        );
+
+        let error = serde_json::from_str::<Foot>(r#"{"value":1,"unit":"au^4/m^3"}"#).unwrap_err();
+        assert!(error.to_string().starts_with("Value out of range"));
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Reading {
+        #[serde(with = "as_unit::<Foot>")]
+        altitude: Meter,
+        #[serde(with = "as_unit::<Fahrenheit<i32>>")]
+        temperature: Celsius<i32>,
+        #[serde(with = "as_unit_string::<KiloMeter>")]
+        visibility: Meter,
+    }
+
+    #[test]
+    fn store_fields_in_other_units() {
+        let reading = Reading {
+            altitude: Meter(3.048),
+            temperature: Celsius(100),
+            visibility: Meter(2500.0),
+        };
+        let json = serde_json::to_string(&reading).unwrap();
+        assert_eq!(
+            json,
+            r#"{"altitude":10.0,"temperature":212,"visibility":"2.5 km"}"#
+        );
+        assert_eq!(serde_json::from_str::<Reading>(&json).unwrap(), reading);
+    }
+
+    #[test]
+    fn parse_strings_in_any_unit() {
+        let json = r#"{"altitude":1,"temperature":32,"visibility":"800 m"}"#;
+        let reading: Reading = serde_json::from_str(json).unwrap();
+        assert_quantity_eq!(reading.altitude, Foot(1.0));
+        assert_eq!(reading.temperature, Celsius(0));
+        assert_eq!(reading.visibility, Meter(800.0));
+
+        let json = r#"{"altitude":1,"temperature":32,"visibility":"3"}"#;
+        let reading: Reading = serde_json::from_str(json).unwrap();
+        assert_eq!(reading.visibility, Meter(3000.0));
+
+        let json = r#"{"altitude":1,"temperature":32,"visibility":"3 kg"}"#;
+        let error = serde_json::from_str::<Reading>(json).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("Unit of a different quantity: `3 kg`")
+        );
+
+        let length: MilliMeter<i32> =
+            AsUnit::<Foot<i32>>::deserialize(&mut serde_json::Deserializer::from_str("2")).unwrap();
+        assert_eq!(length, MilliMeter(610));
+    }
+
+    #[test]
+    fn runtime_units_and_values() {
+        let json = serde_json::to_string(&[LengthUnit::Foot, LengthUnit::NauticalMile]).unwrap();
+        assert_eq!(json, r#"["ft","nmi"]"#);
+        let units: [LengthUnit; 2] = serde_json::from_str(r#"["ft","kilometres"]"#).unwrap();
+        assert_eq!(units, [LengthUnit::Foot, LengthUnit::KiloMeter]);
+        assert!(serde_json::from_str::<LengthUnit>(r#""kg""#).is_err());
+
+        let length = runtime::Length::new(3.0, LengthUnit::Foot);
+        let json = serde_json::to_string(&length).unwrap();
+        assert_eq!(json, r#"{"value":3.0,"unit":"ft"}"#);
+        assert_eq!(
+            serde_json::from_str::<runtime::Length>(&json).unwrap(),
+            length
+        );
+
+        let length: runtime::Length =
+            serde_json::from_str(r#"{"value":2,"unit":"km/h*h"}"#).unwrap();
+        assert_eq!(length, runtime::Length::new(2_000.0, LengthUnit::Meter));
+    }
+}
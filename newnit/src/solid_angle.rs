@@ -0,0 +1,77 @@
+//! Units of solid angle.
+//!
+//! This module contains predefined newtypes for units of solid angle as
+//! defined in the International System of Units (SI). The base unit is the
+//! [`Steradian`].
+//!
+//! Additional definition provided for the square degree (deg²), about 3.05E-4
+//! sr.
+//!
+//! The SI counts solid angles as dimensionless, but their units are of the
+//! dimension [`SolidAngle`](dimension::SolidAngle) here, so that e.g. the
+//! lumen (cd·sr) isn't mistaken for the candela.
+//!
+//! [`Steradian`]: metric::Steradian
+
+use crate::luminous_flux::metric::Lumen;
+use crate::luminous_intensity::LuminousIntensity;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of solid angle.
+///
+/// Provides various multiplication methods, resulting in other units of
+/// quantity, based on the right hand side (`rhs`) in the multiplication.
+pub trait SolidAngle<V: Scalar = f64>: Quantity<V, Dimension = dimension::SolidAngle> {
+    /// Multiply a unit of solid angle with a unit of luminous intensity.
+    fn multiply_luminous_intensity(&self, rhs: &dyn LuminousIntensity<V>) -> Lumen<V> {
+        Lumen(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::SolidAngle>> SolidAngle<V> for T {}
+
+pub mod metric {
+    use super::SolidAngle;
+    use crate::make_unit;
+    use crate::rational::Rational;
+    use newnit_derive::{SolidAngle, Unit};
+
+    make_unit!(
+        Steradian,
+        "sr",
+        "steradian",
+        "steradians",
+        Metric,
+        1.0,
+        SolidAngle
+    );
+    make_unit!(
+        MilliSteradian,
+        "msr",
+        "millisteradian",
+        "millisteradians",
+        Metric,
+        1E-3,
+        SolidAngle
+    );
+    make_unit!(
+        SquareDegree,
+        "deg²",
+        "square degree",
+        "square degrees",
+        Other,
+        (Rational::PI / 180) * (Rational::PI / 180),
+        SolidAngle
+    );
+}
+
+parse::units! {
+    /// Parse a solid angle in the unit named by its symbol (e.g. `"0.5 sr"`).
+    parse, UNITS: SolidAngle, SolidAngleUnit;
+    metric::{
+        Steradian,
+        MilliSteradian,
+        SquareDegree,
+    },
+}
@@ -0,0 +1,250 @@
+//! Statistics of quantities.
+//!
+//! Statistics of quantities, possibly in mixed units (e.g. `&dyn Length`), are
+//! computed in base units and returned as [`Derived`] units of the matching
+//! dimension, which convert into any unit of their quantity. The mean,
+//! standard deviation and median have the dimension of the quantities, their
+//! variance its square (e.g. an area for lengths). The least and greatest
+//! quantity are returned as they are, in their own unit.
+//!
+//! The standard deviation and median require the `std` feature.
+//!
+//! # Examples
+//! ```
+//! use newnit::area::metric::SquareMeter;
+//! use newnit::length::Length;
+//! use newnit::length::imperial::Foot;
+//! use newnit::length::metric::{CentiMeter, Meter};
+//! use newnit::stats;
+//!
+//! let lengths: [&dyn Length; 3] =
+//!     [&Meter(1.0), &CentiMeter(50.0), &Foot(5.0)];
+//!
+//! let mean = Meter::from(&stats::mean(lengths).unwrap());
+//! assert!((mean.0 - 1.008).abs() < 1e-9);
+//!
+//! let variance = SquareMeter::from(&stats::variance(lengths).unwrap());
+//! assert!((variance.0 - 0.1747).abs() < 1e-4);
+//!
+//! assert_eq!(stats::max(lengths).unwrap().symbol(), "ft");
+//! ```
+
+#[cfg(feature = "std")]
+use core::cmp::Ordering;
+use core::ops::Mul;
+
+use crate::dimension::Product;
+use crate::quantity::Derived;
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::{Quantity, Unit};
+
+/// The variance of quantities of the dimension `D`, of the square of `D`.
+pub type Variance<D, V = f64> = Derived<Product<D, D>, V>;
+
+/// The arithmetic mean of the quantities, or `None` if there are none.
+pub fn mean<'a, Q, V>(
+    quantities: impl IntoIterator<Item = &'a Q>,
+) -> Option<Derived<Q::Dimension, V>>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    V: Scalar,
+{
+    let (count, sum) = quantities
+        .into_iter()
+        .fold((0, V::default()), |(count, sum), quantity| {
+            (count + 1, sum + quantity.to_base())
+        });
+
+    (count > 0).then(|| Derived::new(sum / from_count(count)))
+}
+
+/// The (population) variance of the quantities, or `None` if there are none.
+pub fn variance<'a, Q, V, I>(quantities: I) -> Option<Variance<Q::Dimension, V>>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    Q::Dimension: Mul<Q::Dimension>,
+    V: Scalar,
+    I: IntoIterator<Item = &'a Q>,
+    I::IntoIter: Clone,
+{
+    let quantities = quantities.into_iter();
+    let mean = mean(quantities.clone())?.to_base();
+
+    let (count, sum) = quantities.fold((0, V::default()), |(count, sum), quantity| {
+        let value = quantity.to_base();
+        // Unsigned values can't be subtracted from lesser ones.
+        let deviation = match value > mean {
+            true => value - mean,
+            false => mean - value,
+        };
+        (count + 1, sum + deviation * deviation)
+    });
+
+    Some(Derived::new(sum / from_count(count)))
+}
+
+/// The (population) standard deviation of the quantities, or `None` if there
+/// are none.
+#[cfg(feature = "std")]
+pub fn standard_deviation<'a, Q, V, I>(quantities: I) -> Option<Derived<Q::Dimension, V>>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    Q::Dimension: Mul<Q::Dimension>,
+    V: Scalar,
+    I: IntoIterator<Item = &'a Q>,
+    I::IntoIter: Clone,
+{
+    let variance = variance(quantities)?.to_base();
+    Some(Derived::new(V::from_f64(variance.to_f64().sqrt())))
+}
+
+/// The median of the quantities, or `None` if there are none.
+///
+/// Of an even number of quantities, the median is the mean of the middle two.
+/// NaN values are ignored.
+#[cfg(feature = "std")]
+pub fn median<'a, Q, V>(
+    quantities: impl IntoIterator<Item = &'a Q>,
+) -> Option<Derived<Q::Dimension, V>>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    V: Scalar,
+{
+    let mut values: Vec<V> = quantities
+        .into_iter()
+        .map(|quantity| quantity.to_base())
+        .filter(|value| !is_nan(*value))
+        .collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let middle = values.len() / 2;
+    let median = match values.len() % 2 {
+        0 => (*values.get(middle.checked_sub(1)?)? + values[middle]) / from_count(2),
+        _ => values[middle],
+    };
+
+    Some(Derived::new(median))
+}
+
+/// The least of the quantities, or `None` if there are none. NaN values are
+/// ignored.
+pub fn min<'a, Q, V>(quantities: impl IntoIterator<Item = &'a Q>) -> Option<&'a Q>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    V: Scalar,
+{
+    comparable(quantities).reduce(|min, quantity| match quantity.to_base() < min.to_base() {
+        true => quantity,
+        false => min,
+    })
+}
+
+/// The greatest of the quantities, or `None` if there are none. NaN values are
+/// ignored.
+pub fn max<'a, Q, V>(quantities: impl IntoIterator<Item = &'a Q>) -> Option<&'a Q>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    V: Scalar,
+{
+    comparable(quantities).reduce(|max, quantity| match quantity.to_base() > max.to_base() {
+        true => quantity,
+        false => max,
+    })
+}
+
+/// The quantities, except those with a NaN value.
+fn comparable<'a, Q, V>(quantities: impl IntoIterator<Item = &'a Q>) -> impl Iterator<Item = &'a Q>
+where
+    Q: Quantity<V> + ?Sized + 'a,
+    V: Scalar,
+{
+    quantities
+        .into_iter()
+        .filter(|quantity| !is_nan(quantity.to_base()))
+}
+
+/// Whether the value is NaN, i.e. incomparable even to itself.
+fn is_nan<V: Scalar>(value: V) -> bool {
+    value.partial_cmp(&value).is_none()
+}
+
+/// The number of quantities as a value.
+fn from_count<V: Scalar>(count: usize) -> V {
+    V::from_rational(Rational::integer(count as i128))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::metric::SquareMeter;
+    use crate::assert_quantity_eq;
+    use crate::length::Length;
+    use crate::length::imperial::Foot;
+    #[cfg(feature = "std")]
+    use crate::length::imperial::Inch;
+    #[cfg(feature = "std")]
+    use crate::length::metric::KiloMeter;
+    use crate::length::metric::Meter;
+    use crate::temperature::{Celsius, Fahrenheit, Temperature};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sum_units() {
+        let lengths = [Meter(1.0), Meter(2.5)];
+        assert_eq!(lengths.iter().sum::<Meter>(), Meter(3.5));
+        assert_eq!(lengths.into_iter().sum::<Meter>(), Meter(3.5));
+        assert_eq!(
+            Vec::<Meter<u32>>::new().into_iter().sum::<Meter<u32>>(),
+            Meter(0)
+        );
+
+        let mixed: Vec<Box<dyn Length>> = vec![Box::new(Foot(1.0)), Box::new(Inch(12.0))];
+        let total: Inch = mixed.iter().map(Box::as_ref).sum();
+        assert_quantity_eq!(total, Inch(24.0));
+    }
+
+    #[test]
+    fn mean_and_variance() {
+        let lengths = [Meter(2.0), Meter(4.0), Meter(4.0), Meter(4.0)];
+        assert_eq!(mean(&lengths).unwrap().to_value(), 3.5);
+        let area: SquareMeter = (&variance(&lengths).unwrap()).into();
+        assert_eq!(area, SquareMeter(0.75));
+
+        assert!(mean::<Meter, f64>([]).is_none());
+        assert!(variance::<Meter, f64, _>([]).is_none());
+
+        let temperatures: [&dyn Temperature; 2] = [&Celsius(10.0), &Fahrenheit(68.0)];
+        assert_quantity_eq!(mean(temperatures).unwrap(), Celsius(15.0));
+    }
+
+    #[test]
+    fn integer_variance() {
+        let lengths = [Meter(1_u32), Meter(3), Meter(5)];
+        assert_eq!(variance(&lengths).unwrap().to_value(), 2);
+        assert_eq!(mean(&lengths).unwrap().to_value(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn standard_deviation_and_median() {
+        let lengths: [&dyn Length; 4] = [&Meter(2.0), &Meter(4.0), &Meter(4.0), &KiloMeter(0.004)];
+        let deviation = standard_deviation(lengths).unwrap();
+        assert_quantity_eq!(deviation, Meter(0.75_f64.sqrt()));
+        assert_eq!(median(lengths).unwrap().to_value(), 4.0);
+        assert_eq!(median([&Meter(3.0), &Meter(1.0)]).unwrap().to_value(), 2.0);
+        assert_eq!(
+            median([&Meter(f64::NAN), &Meter(1.0)]).unwrap().to_value(),
+            1.0
+        );
+        assert!(median::<Meter, f64>([]).is_none());
+    }
+
+    #[test]
+    fn least_and_greatest() {
+        let lengths: [&dyn Length; 3] = [&Foot(3.0), &Meter(f64::NAN), &Meter(1.0)];
+        assert_eq!(min(lengths).unwrap().symbol(), "ft");
+        assert_eq!(max(lengths).unwrap().to_base(), 1.0);
+        assert!(max::<Meter, f64>([]).is_none());
+    }
+}
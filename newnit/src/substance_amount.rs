@@ -6,36 +6,248 @@
 //!
 //! [`Mole`]: metric::Mole
 
-use crate::Unit;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
 
-pub trait SubstanceAmount: Unit {}
+pub trait SubstanceAmount<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::SubstanceAmount>
+{
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::SubstanceAmount>> SubstanceAmount<V> for T {}
 
 pub mod metric {
     use super::SubstanceAmount;
-    use crate::{Unit, make_unit};
+    use crate::make_unit;
     use newnit_derive::{SubstanceAmount, Unit};
 
-    make_unit!(QuettaMole, 1E+30, SubstanceAmount);
-    make_unit!(RonnaMole, 1E+27, SubstanceAmount);
-    make_unit!(YottaMole, 1E+24, SubstanceAmount);
-    make_unit!(ZettaMole, 1E+21, SubstanceAmount);
-    make_unit!(ExaMole, 1E+18, SubstanceAmount);
-    make_unit!(PetaMole, 1E+15, SubstanceAmount);
-    make_unit!(TeraMole, 1E+12, SubstanceAmount);
-    make_unit!(GigaMole, 1E+9, SubstanceAmount);
-    make_unit!(MegaMole, 1E+6, SubstanceAmount);
-    make_unit!(KiloMole, 1E+3, SubstanceAmount);
-    make_unit!(Mole, 1.0, SubstanceAmount);
-    make_unit!(DeciMole, 1E-1, SubstanceAmount);
-    make_unit!(CentiMole, 1E-2, SubstanceAmount);
-    make_unit!(MilliMole, 1E-3, SubstanceAmount);
-    make_unit!(MicroMole, 1E-6, SubstanceAmount);
-    make_unit!(NanoMole, 1E-9, SubstanceAmount);
-    make_unit!(PicoMole, 1E-12, SubstanceAmount);
-    make_unit!(FemtoMole, 1E-15, SubstanceAmount);
-    make_unit!(AttoMole, 1E-18, SubstanceAmount);
-    make_unit!(ZeptoMole, 1E-21, SubstanceAmount);
-    make_unit!(YoctoMole, 1E-24, SubstanceAmount);
-    make_unit!(RontoMole, 1E-27, SubstanceAmount);
-    make_unit!(QuectoMole, 1E-30, SubstanceAmount);
+    make_unit!(
+        QuettaMole,
+        "Qmol",
+        "quettamole",
+        "quettamoles",
+        Metric,
+        1E+30,
+        SubstanceAmount
+    );
+    make_unit!(
+        RonnaMole,
+        "Rmol",
+        "ronnamole",
+        "ronnamoles",
+        Metric,
+        1E+27,
+        SubstanceAmount
+    );
+    make_unit!(
+        YottaMole,
+        "Ymol",
+        "yottamole",
+        "yottamoles",
+        Metric,
+        1E+24,
+        SubstanceAmount
+    );
+    make_unit!(
+        ZettaMole,
+        "Zmol",
+        "zettamole",
+        "zettamoles",
+        Metric,
+        1E+21,
+        SubstanceAmount
+    );
+    make_unit!(
+        ExaMole,
+        "Emol",
+        "examole",
+        "examoles",
+        Metric,
+        1E+18,
+        SubstanceAmount
+    );
+    make_unit!(
+        PetaMole,
+        "Pmol",
+        "petamole",
+        "petamoles",
+        Metric,
+        1E+15,
+        SubstanceAmount
+    );
+    make_unit!(
+        TeraMole,
+        "Tmol",
+        "teramole",
+        "teramoles",
+        Metric,
+        1E+12,
+        SubstanceAmount
+    );
+    make_unit!(
+        GigaMole,
+        "Gmol",
+        "gigamole",
+        "gigamoles",
+        Metric,
+        1E+9,
+        SubstanceAmount
+    );
+    make_unit!(
+        MegaMole,
+        "Mmol",
+        "megamole",
+        "megamoles",
+        Metric,
+        1E+6,
+        SubstanceAmount
+    );
+    make_unit!(
+        KiloMole,
+        "kmol",
+        "kilomole",
+        "kilomoles",
+        Metric,
+        1E+3,
+        SubstanceAmount
+    );
+    make_unit!(Mole, "mol", "mole", "moles", Metric, 1.0, SubstanceAmount);
+    make_unit!(
+        DeciMole,
+        "dmol",
+        "decimole",
+        "decimoles",
+        Metric,
+        1E-1,
+        SubstanceAmount
+    );
+    make_unit!(
+        CentiMole,
+        "cmol",
+        "centimole",
+        "centimoles",
+        Metric,
+        1E-2,
+        SubstanceAmount
+    );
+    make_unit!(
+        MilliMole,
+        "mmol",
+        "millimole",
+        "millimoles",
+        Metric,
+        1E-3,
+        SubstanceAmount
+    );
+    make_unit!(
+        MicroMole,
+        "µmol",
+        "micromole",
+        "micromoles",
+        Metric,
+        1E-6,
+        SubstanceAmount
+    );
+    make_unit!(
+        NanoMole,
+        "nmol",
+        "nanomole",
+        "nanomoles",
+        Metric,
+        1E-9,
+        SubstanceAmount
+    );
+    make_unit!(
+        PicoMole,
+        "pmol",
+        "picomole",
+        "picomoles",
+        Metric,
+        1E-12,
+        SubstanceAmount
+    );
+    make_unit!(
+        FemtoMole,
+        "fmol",
+        "femtomole",
+        "femtomoles",
+        Metric,
+        1E-15,
+        SubstanceAmount
+    );
+    make_unit!(
+        AttoMole,
+        "amol",
+        "attomole",
+        "attomoles",
+        Metric,
+        1E-18,
+        SubstanceAmount
+    );
+    make_unit!(
+        ZeptoMole,
+        "zmol",
+        "zeptomole",
+        "zeptomoles",
+        Metric,
+        1E-21,
+        SubstanceAmount
+    );
+    make_unit!(
+        YoctoMole,
+        "ymol",
+        "yoctomole",
+        "yoctomoles",
+        Metric,
+        1E-24,
+        SubstanceAmount
+    );
+    make_unit!(
+        RontoMole,
+        "rmol",
+        "rontomole",
+        "rontomoles",
+        Metric,
+        1E-27,
+        SubstanceAmount
+    );
+    make_unit!(
+        QuectoMole,
+        "qmol",
+        "quectomole",
+        "quectomoles",
+        Metric,
+        1E-30,
+        SubstanceAmount
+    );
+}
+
+parse::units! {
+    /// Parse an amount of substance in the unit named by its symbol (e.g. `"2 mol"`).
+    parse, UNITS: SubstanceAmount, SubstanceAmountUnit;
+    metric::{
+        QuettaMole,
+        RonnaMole,
+        YottaMole,
+        ZettaMole,
+        ExaMole,
+        PetaMole,
+        TeraMole,
+        GigaMole,
+        MegaMole,
+        KiloMole,
+        Mole,
+        DeciMole,
+        CentiMole,
+        MilliMole,
+        MicroMole,
+        NanoMole,
+        PicoMole,
+        FemtoMole,
+        AttoMole,
+        ZeptoMole,
+        YoctoMole,
+        RontoMole,
+        QuectoMole,
+    },
 }
@@ -5,24 +5,135 @@
 //! [`Fahrenheit`].
 //!
 //! The base unit is the [`Kelvin`].
+//!
+//! Temperatures are points on a scale, so they can't be added together. The
+//! difference of two temperatures is a temperature interval (e.g.
+//! [`DeltaCelsius`]), which in turn can be added to or subtracted from a
+//! temperature. Intervals are regular quantities of the temperature dimension
+//! that can be multiplied and divided by other units.
+//!
+//! # Examples
+//! ```
+//! use newnit::temperature::{Celsius, DeltaCelsius, Fahrenheit};
+//!
+//! let morning = Celsius(10.0);
+//! let afternoon = morning + &DeltaCelsius(5.0);
+//! assert_eq!(afternoon, Celsius(15.0));
+//!
+//! let warming: DeltaCelsius = afternoon - &morning;
+//! assert_eq!(warming, DeltaCelsius(5.0));
+//!
+//! let difference: DeltaCelsius = Celsius(100.0) - &Fahrenheit(32.0);
+//! assert_eq!(difference, DeltaCelsius(100.0));
+//! ```
+//!
+//! Adding two temperatures is a compile time error:
+//!
+//! ```compile_fail
+//! use newnit::temperature::Celsius;
+//!
+//! let bad_sum = Celsius(10.0) + &Celsius(5.0);
+//! ```
 
-pub trait Temperature: Unit {}
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, make_unit, parse};
+use newnit_derive::{Temperature, TemperatureInterval, Unit};
 
-use crate::{Unit, make_unit};
-use newnit_derive::{Temperature, Unit};
+/// Types that are units of (absolute) temperature.
+pub trait Temperature<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::Affine<dimension::Temperature>>
+{
+    /// The thermodynamic temperature as an interval above absolute zero.
+    ///
+    /// Unlike temperatures, the interval can be multiplied or divided by other
+    /// units (e.g. in the ideal gas law).
+    fn above_absolute_zero(&self) -> DeltaKelvin<V> {
+        DeltaKelvin(self.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Affine<dimension::Temperature>>>
+    Temperature<V> for T
+{
+}
 
-make_unit!(Kelvin, 1.0, Temperature);
-make_unit!(Celsius, 1.0, 273.15, Temperature);
+/// Types that are units of temperature intervals (differences).
+pub trait TemperatureInterval<V: Scalar = f64>:
+    Quantity<V, Dimension = dimension::Temperature>
+{
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Temperature>> TemperatureInterval<V> for T {}
+
+make_unit!(Kelvin, "K", "kelvin", "kelvins", Metric, 1.0, Temperature);
+make_unit!(
+    Celsius,
+    "°C",
+    "degree Celsius",
+    "degrees Celsius",
+    Metric,
+    1.0,
+    273.15,
+    Temperature,
+    DeltaCelsius
+);
 make_unit!(
     Fahrenheit,
-    5.555_555_555_555_555_6E-1,
-    255.372_222_222_222_22,
-    Temperature
+    "°F",
+    "degree Fahrenheit",
+    "degrees Fahrenheit",
+    Imperial,
+    5 / 9,
+    273.15 - 32 * 5 / 9,
+    Temperature,
+    DeltaFahrenheit
+);
+
+make_unit!(
+    DeltaKelvin,
+    "ΔK",
+    "kelvin",
+    "kelvins",
+    Metric,
+    1.0,
+    TemperatureInterval
+);
+make_unit!(
+    DeltaCelsius,
+    "Δ°C",
+    "degree Celsius",
+    "degrees Celsius",
+    Metric,
+    1.0,
+    TemperatureInterval
+);
+make_unit!(
+    DeltaFahrenheit,
+    "Δ°F",
+    "degree Fahrenheit",
+    "degrees Fahrenheit",
+    Imperial,
+    5 / 9,
+    TemperatureInterval
 );
 
+parse::units! {
+    /// Parse a temperature in the unit named by its symbol (e.g. `"25 °C"`).
+    parse, UNITS: Temperature, TemperatureUnit;
+    self::{Kelvin, Celsius, Fahrenheit},
+}
+
+parse::units! {
+    /// Parse a temperature interval in the unit named by its symbol (e.g.
+    /// `"5 Δ°C"`).
+    parse_interval, INTERVAL_UNITS: TemperatureInterval, TemperatureIntervalUnit;
+    self::{DeltaKelvin, DeltaCelsius, DeltaFahrenheit},
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_quantity_eq;
 
     #[test]
     fn from_celsius_to_kelvin() {
@@ -37,4 +148,86 @@ mod test {
         let fahrenheit = Fahrenheit::from(&celsius);
         assert!((fahrenheit.to_value() - 108.032).abs() < 1e-5);
     }
+
+    #[test]
+    fn convert_exactly() {
+        assert_eq!(Fahrenheit::from(&Celsius(100.0)), Fahrenheit(212.0));
+        assert_eq!(Celsius::from(&Fahrenheit(-40.0)), Celsius(-40.0));
+        assert_eq!(
+            DeltaFahrenheit::from(&DeltaKelvin(5.0)),
+            DeltaFahrenheit(9.0)
+        );
+    }
+
+    #[test]
+    fn add_interval_to_temperature() {
+        let temperature = Celsius(10.0) + &DeltaCelsius(5.0);
+        assert_eq!(temperature, Celsius(15.0));
+
+        let temperature = Celsius(0.1) + &DeltaCelsius(0.2);
+        assert_eq!(temperature, Celsius(0.1 + 0.2));
+
+        let temperature = Fahrenheit(50.0) + &DeltaCelsius(10.0);
+        assert_eq!(temperature, Fahrenheit(68.0));
+    }
+
+    #[test]
+    fn subtract_interval_from_temperature() {
+        let mut temperature = Kelvin(300.0) - &DeltaFahrenheit(9.0);
+        assert_eq!(temperature, Kelvin(295.0));
+
+        temperature -= &DeltaCelsius(5.0);
+        assert_eq!(temperature, Kelvin(290.0));
+    }
+
+    #[test]
+    fn subtract_temperatures() {
+        let difference = Celsius(10.0) - &Celsius(5.0);
+        assert_eq!(difference, DeltaCelsius(5.0));
+
+        let difference = Celsius(21.5) - &Celsius(21.25);
+        assert_eq!(difference, DeltaCelsius(0.25));
+
+        let difference = Fahrenheit(212.0) - &Celsius(0.0);
+        assert_eq!(difference, DeltaFahrenheit(180.0));
+    }
+
+    #[test]
+    fn integer_temperatures() {
+        assert_eq!(Fahrenheit(1_i32) + &DeltaFahrenheit(1), Fahrenheit(2));
+        assert_eq!(Fahrenheit(50_i32) + &DeltaCelsius(10), Fahrenheit(68));
+        assert_eq!(Celsius(20_i32) - &Celsius(5), DeltaCelsius(15));
+        assert_eq!(Fahrenheit(212_i32) - &Celsius(0), DeltaFahrenheit(180));
+
+        let mut temperature = Kelvin(300_i64);
+        temperature -= &DeltaFahrenheit(9);
+        assert_eq!(temperature, Kelvin(295));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_temperatures() {
+        use rust_decimal::Decimal;
+
+        let temperature = Celsius(Decimal::new(1, 1)) + &DeltaCelsius(Decimal::new(2, 1));
+        assert_eq!(temperature, Celsius(Decimal::new(3, 1)));
+
+        let temperature = Fahrenheit(Decimal::new(1, 0)) + &DeltaFahrenheit(Decimal::new(1, 0));
+        assert_eq!(temperature, Fahrenheit(Decimal::new(2, 0)));
+
+        let difference = Celsius(Decimal::new(2155, 2)) - &Kelvin(Decimal::new(29415, 2));
+        assert_eq!(difference, DeltaCelsius(Decimal::new(55, 2)));
+    }
+
+    #[test]
+    fn convert_intervals() {
+        let difference = DeltaFahrenheit::from(&DeltaCelsius(5.0));
+        assert_eq!(difference, DeltaFahrenheit(9.0));
+    }
+
+    #[test]
+    fn temperature_above_absolute_zero() {
+        let kelvin = Celsius(25.0).above_absolute_zero();
+        assert_quantity_eq!(kelvin, DeltaKelvin(298.15));
+    }
 }
@@ -5,50 +5,277 @@
 //!
 //! [`Second`]: metric::Second
 
-use crate::Unit;
+use crate::charge::metric::Coulomb;
+use crate::current::Current;
+use crate::energy::metric::Joule;
 use crate::length::metric::Meter;
+use crate::power::Power;
+use crate::scalar::Scalar;
 use crate::velocity::Velocity;
+use crate::{Quantity, dimension, parse};
 
-pub trait Time: Unit {
+pub trait Time<V: Scalar = f64>: Quantity<V, Dimension = dimension::Time> {
     /// Multiply a unit of time with a unit of velocity.
-    fn multiply_velocity(&self, rhs: &dyn Velocity) -> Meter {
+    fn multiply_velocity(&self, rhs: &dyn Velocity<V>) -> Meter<V> {
         Meter(self.to_base() * rhs.to_base())
     }
+
+    /// Multiply a unit of time with a unit of current.
+    fn multiply_current(&self, rhs: &dyn Current<V>) -> Coulomb<V> {
+        Coulomb(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of time with a unit of power.
+    fn multiply_power(&self, rhs: &dyn Power<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
 }
 
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Time>> Time<V> for T {}
+
 pub mod metric {
     use super::Time;
-    use crate::{Unit, make_unit};
+    use crate::make_unit;
     use newnit_derive::{Time, Unit};
 
-    make_unit!(QuettaSecond, 1E+30, Time);
-    make_unit!(RonnaSecond, 1E+27, Time);
-    make_unit!(YottaSecond, 1E+24, Time);
-    make_unit!(ZettaSecond, 1E+21, Time);
-    make_unit!(ExaSecond, 1E+18, Time);
-    make_unit!(PetaSecond, 1E+15, Time);
-    make_unit!(TeraSecond, 1E+12, Time);
-    make_unit!(GigaSecond, 1E+9, Time);
-    make_unit!(MegaSecond, 1E+6, Time);
-    make_unit!(KiloSecond, 1E+3, Time);
-    make_unit!(Second, 1.0, Time);
-    make_unit!(DeciSecond, 1E-1, Time);
-    make_unit!(CentiSecond, 1E-2, Time);
-    make_unit!(MilliSecond, 1E-3, Time);
-    make_unit!(MicroSecond, 1E-6, Time);
-    make_unit!(NanoSecond, 1E-9, Time);
-    make_unit!(PicoSecond, 1E-12, Time);
-    make_unit!(FemtoSecond, 1E-15, Time);
-    make_unit!(AttoSecond, 1E-18, Time);
-    make_unit!(ZeptoSecond, 1E-21, Time);
-    make_unit!(YoctoSecond, 1E-24, Time);
-    make_unit!(RontoSecond, 1E-27, Time);
-    make_unit!(QuectoSecond, 1E-30, Time);
+    make_unit!(
+        QuettaSecond,
+        "Qs",
+        "quettasecond",
+        "quettaseconds",
+        Metric,
+        1E+30,
+        Time
+    );
+    make_unit!(
+        RonnaSecond,
+        "Rs",
+        "ronnasecond",
+        "ronnaseconds",
+        Metric,
+        1E+27,
+        Time
+    );
+    make_unit!(
+        YottaSecond,
+        "Ys",
+        "yottasecond",
+        "yottaseconds",
+        Metric,
+        1E+24,
+        Time
+    );
+    make_unit!(
+        ZettaSecond,
+        "Zs",
+        "zettasecond",
+        "zettaseconds",
+        Metric,
+        1E+21,
+        Time
+    );
+    make_unit!(
+        ExaSecond,
+        "Es",
+        "exasecond",
+        "exaseconds",
+        Metric,
+        1E+18,
+        Time
+    );
+    make_unit!(
+        PetaSecond,
+        "Ps",
+        "petasecond",
+        "petaseconds",
+        Metric,
+        1E+15,
+        Time
+    );
+    make_unit!(
+        TeraSecond,
+        "Ts",
+        "terasecond",
+        "teraseconds",
+        Metric,
+        1E+12,
+        Time
+    );
+    make_unit!(
+        GigaSecond,
+        "Gs",
+        "gigasecond",
+        "gigaseconds",
+        Metric,
+        1E+9,
+        Time
+    );
+    make_unit!(
+        MegaSecond,
+        "Ms",
+        "megasecond",
+        "megaseconds",
+        Metric,
+        1E+6,
+        Time
+    );
+    make_unit!(
+        KiloSecond,
+        "ks",
+        "kilosecond",
+        "kiloseconds",
+        Metric,
+        1E+3,
+        Time
+    );
+    make_unit!(Second, "s", "second", "seconds", Metric, 1.0, Time);
+    make_unit!(
+        DeciSecond,
+        "ds",
+        "decisecond",
+        "deciseconds",
+        Metric,
+        1E-1,
+        Time
+    );
+    make_unit!(
+        CentiSecond,
+        "cs",
+        "centisecond",
+        "centiseconds",
+        Metric,
+        1E-2,
+        Time
+    );
+    make_unit!(
+        MilliSecond,
+        "ms",
+        "millisecond",
+        "milliseconds",
+        Metric,
+        1E-3,
+        Time
+    );
+    make_unit!(
+        MicroSecond,
+        "µs",
+        "microsecond",
+        "microseconds",
+        Metric,
+        1E-6,
+        Time
+    );
+    make_unit!(
+        NanoSecond,
+        "ns",
+        "nanosecond",
+        "nanoseconds",
+        Metric,
+        1E-9,
+        Time
+    );
+    make_unit!(
+        PicoSecond,
+        "ps",
+        "picosecond",
+        "picoseconds",
+        Metric,
+        1E-12,
+        Time
+    );
+    make_unit!(
+        FemtoSecond,
+        "fs",
+        "femtosecond",
+        "femtoseconds",
+        Metric,
+        1E-15,
+        Time
+    );
+    make_unit!(
+        AttoSecond,
+        "as",
+        "attosecond",
+        "attoseconds",
+        Metric,
+        1E-18,
+        Time
+    );
+    make_unit!(
+        ZeptoSecond,
+        "zs",
+        "zeptosecond",
+        "zeptoseconds",
+        Metric,
+        1E-21,
+        Time
+    );
+    make_unit!(
+        YoctoSecond,
+        "ys",
+        "yoctosecond",
+        "yoctoseconds",
+        Metric,
+        1E-24,
+        Time
+    );
+    make_unit!(
+        RontoSecond,
+        "rs",
+        "rontosecond",
+        "rontoseconds",
+        Metric,
+        1E-27,
+        Time
+    );
+    make_unit!(
+        QuectoSecond,
+        "qs",
+        "quectosecond",
+        "quectoseconds",
+        Metric,
+        1E-30,
+        Time
+    );
+
+    make_unit!(Minute, "min", "minute", "minutes", Metric, 60.0, Time);
+    make_unit!(Hour, "h", "hour", "hours", Metric, 3600.0, Time);
+    make_unit!(Day, "d", "day", "days", Metric, 86_400.0, Time);
+    make_unit!(Week, "wk", "week", "weeks", Metric, 604_800.0, Time);
+}
 
-    make_unit!(Minute, 60.0, Time);
-    make_unit!(Hour, 3600.0, Time);
-    make_unit!(Day, 86_400.0, Time);
-    make_unit!(Week, 604_800.0, Time);
+parse::units! {
+    /// Parse a time in the unit named by its symbol (e.g. `"90 min"`).
+    parse, UNITS: Time, TimeUnit;
+    metric::{
+        QuettaSecond,
+        RonnaSecond,
+        YottaSecond,
+        ZettaSecond,
+        ExaSecond,
+        PetaSecond,
+        TeraSecond,
+        GigaSecond,
+        MegaSecond,
+        KiloSecond,
+        Second,
+        DeciSecond,
+        CentiSecond,
+        MilliSecond,
+        MicroSecond,
+        NanoSecond,
+        PicoSecond,
+        FemtoSecond,
+        AttoSecond,
+        ZeptoSecond,
+        YoctoSecond,
+        RontoSecond,
+        QuectoSecond,
+        Minute,
+        Hour,
+        Day,
+        Week,
+    },
 }
 
 #[cfg(test)]
@@ -1,5 +1,8 @@
 //! Unit trait to define units of measurement.
 
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+
 /// A unit of measurement.
 ///
 /// For the purpose of this crate, a unit of measurement is defined purely by
@@ -8,26 +11,299 @@
 /// The choice of the base unit is arbitrary and up to the implementor (although
 /// choosing a SI base is a good idea).
 ///
+/// The value of the unit is stored as `V`, which defaults to [`f64`] (see the
+/// [`scalar`](crate::scalar) module for other supported types).
+///
 /// It is up to the implementor to additionally mark the implementing type with
 /// a trait defining its associated quantity (e.g. `Mass`, `Length`, etc.).
-pub trait Unit {
+pub trait Unit<V = f64> {
     /// Converts the quantity value represented in this unit to its equivalent
     /// value in the base unit.
-    fn to_base(&self) -> f64;
+    fn to_base(&self) -> V;
 
-    /// Returns the wrapped [`f64`] value.
-    fn to_value(&self) -> f64;
+    /// Returns the wrapped value.
+    fn to_value(&self) -> V;
 
-    /// Set the wrapped value to the passed [`f64`] value
-    fn set_value(&mut self, value: f64);
+    /// Set the wrapped value to the passed value
+    fn set_value(&mut self, value: V);
 
-    /// Set the wrapped value to one obtained by converting the passed [`f64`],
+    /// Set the wrapped value to one obtained by converting the passed value,
     /// assuming the passed value is in base units.
-    fn set_from_base(&mut self, base: f64);
+    fn set_from_base(&mut self, base: V);
 
     /// Create a representation of a quantity expressed in this unit from its
     /// value in base units.
-    fn from_base(base: f64) -> Self
+    fn from_base(base: V) -> Self
     where
         Self: Sized;
+
+    /// Create a representation of a quantity from its value in this unit.
+    fn from_value(value: V) -> Self
+    where
+        Self: Sized,
+        V: Copy,
+    {
+        let mut unit = Self::from_base(value);
+        unit.set_value(value);
+        unit
+    }
+
+    /// The symbol of the unit (e.g. `km`).
+    ///
+    /// Defaults to the name of the implementing type, without its path and
+    /// type parameters (e.g. `KiloMeter`).
+    fn symbol(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// The singular name of the unit (e.g. `kilometre`).
+    ///
+    /// Defaults to the name of the implementing type, without its path and
+    /// type parameters (e.g. `KiloMeter`).
+    fn name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// The plural name of the unit (e.g. `kilometres`).
+    ///
+    /// Defaults to the singular name.
+    fn plural(&self) -> &'static str {
+        self.name()
+    }
+
+    /// The system of measurement the unit belongs to.
+    fn system(&self) -> System {
+        System::Other
+    }
+}
+
+/// Warns of uses of the forms of [`make_unit!`](crate::make_unit) without the
+/// symbol, names and system of the unit.
+#[doc(hidden)]
+#[deprecated(
+    since = "0.2.0",
+    note = "give make_unit! the symbol, names and system of the unit"
+)]
+pub const fn __make_unit_without_metadata() {}
+
+/// Constant conversion of a unit to its base unit.
+///
+/// The conversion is defined as follows:
+///   value_in_base_unit = value_in_this_unit * `FACTOR` + `OFFSET`
+///
+/// The factor and offset are exact [`Rational`]s, so the factor of conversion
+/// between any two units is computed exactly at compile time and values are
+/// converted directly, rounding only once (if at all).
+///
+/// Unlike [`Unit`], this trait can't be used as a trait object.
+pub trait Conversion {
+    /// The factor of conversion to the base unit.
+    const FACTOR: Rational;
+
+    /// The offset of conversion to the base unit.
+    const OFFSET: Rational;
+
+    /// Converts a value of the unit `T` to a value of this unit.
+    fn convert_from<T: Conversion, V: Scalar>(value: V) -> V {
+        let factor = const { T::FACTOR.quotient(Self::FACTOR) };
+        let offset = const { T::OFFSET.difference(Self::OFFSET).quotient(Self::FACTOR) };
+
+        affine(value, factor, offset)
+    }
+
+    /// Converts a difference of values of the unit `T` to a difference of
+    /// values of this unit, to which the offsets of the units don't apply
+    /// (e.g. an interval of 9 °F to an interval of 5 °C).
+    fn convert_difference_from<T: Conversion, V: Scalar>(value: V) -> V {
+        let factor = const { T::FACTOR.quotient(Self::FACTOR) };
+
+        affine(value, factor, Rational::ZERO)
+    }
+
+    /// Converts a value of this unit to a value in the base unit.
+    fn to_base_value<V: Scalar>(value: V) -> V {
+        affine(value, Self::FACTOR, Self::OFFSET)
+    }
+
+    /// Converts a value in the base unit to a value of this unit.
+    fn from_base_value<V: Scalar>(base: V) -> V {
+        let factor = const { Self::FACTOR.reciprocal() };
+        let offset = const { Self::OFFSET.negated().quotient(Self::FACTOR) };
+
+        affine(base, factor, offset)
+    }
+}
+
+/// Constant metadata of a unit.
+///
+/// The same metadata is available through trait objects of [`Unit`].
+pub trait Metadata {
+    /// The symbol of the unit (e.g. `km`), used to parse values of the unit.
+    const SYMBOL: &'static str;
+
+    /// The singular name of the unit (e.g. `kilometre`).
+    const NAME: &'static str;
+
+    /// The plural name of the unit (e.g. `kilometres`).
+    const PLURAL: &'static str;
+
+    /// The system of measurement the unit belongs to.
+    const SYSTEM: System;
+
+    /// Alternative symbols or names of the unit (e.g. `L` for the cubic
+    /// decimetre), also used to parse values of the unit.
+    const ALIASES: &'static [&'static str] = &[];
+}
+
+/// A system of measurement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum System {
+    /// The metric system, including units accepted for use with SI (e.g.
+    /// hours).
+    Metric,
+    /// The British imperial system.
+    Imperial,
+    /// The United States customary system.
+    UsCustomary,
+    /// Units used in navigation (e.g. nautical miles).
+    Nautical,
+    /// Units used in astronomy (e.g. parsecs).
+    Astronomical,
+    /// Units of no particular system (e.g. user-defined units).
+    Other,
+}
+
+/// The name of the type `T`, without its path and type parameters (e.g.
+/// `Meter` of `newnit::length::metric::Meter<f64>`).
+fn type_name<T: ?Sized>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Maps `value` to `value * factor + offset`, skipping the identities.
+pub(crate) fn affine<V: Scalar>(value: V, factor: Rational, offset: Rational) -> V {
+    let value = if factor.is_one() {
+        value
+    } else {
+        value.scale(factor)
+    };
+
+    if offset.is_zero() {
+        value
+    } else {
+        value + V::from_rational(offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::Length;
+    use crate::length::imperial::{Foot, Inch};
+    use crate::length::metric::KiloMeter;
+    use crate::make_unit;
+    use crate::temperature::{Celsius, Fahrenheit};
+    use newnit_derive::{Length, Unit};
+
+    #[derive(Unit, Length, Debug, PartialEq)]
+    #[unit(factor = 1 / 3, of = Foot)]
+    #[quantity(from)]
+    struct Hand(f64);
+
+    #[test]
+    fn factor_relative_to_unit() {
+        assert_eq!(Hand::FACTOR, Rational::new(1016, 1, -4));
+        assert_eq!(Hand::from(&Inch(4.0)), Hand(1.0));
+        assert_eq!(Foot::from(&Hand(3.0)), Foot(1.0));
+    }
+
+    #[test]
+    fn convert_to_and_from_base() {
+        assert_eq!(<Inch as Conversion>::to_base_value(100.0), 2.54);
+        assert_eq!(<Inch as Conversion>::from_base_value(254_i32), 10_000);
+        assert_eq!(<Inch as Conversion>::convert_from::<Foot, _>(3_i32), 36);
+        assert_eq!(
+            <Celsius as Conversion>::convert_difference_from::<Fahrenheit, _>(9_i32),
+            5
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_symbol_and_name() {
+        assert_eq!(format!("{}", KiloMeter(3.0)), "3 km");
+        assert_eq!(format!("{:#}", KiloMeter(3.0)), "3 kilometres");
+        assert_eq!(format!("{:#}", KiloMeter(1.0)), "1 kilometre");
+        assert_eq!(format!("{:#}", Foot(2.5)), "2.5 feet");
+        assert_eq!(
+            format!("{:#}", crate::velocity::metric::MeterPerSecond(2)),
+            "2 metres per second"
+        );
+        assert_eq!(format!("{:.1}", Celsius(21.46)), "21.5 °C");
+    }
+
+    #[test]
+    fn metadata_of_trait_objects() {
+        let length: &dyn Length = &Foot(1.0);
+        assert_eq!(length.symbol(), "ft");
+        assert_eq!(length.name(), "foot");
+        assert_eq!(length.plural(), "feet");
+        assert_eq!(length.system(), System::Imperial);
+
+        assert_eq!(<KiloMeter as Metadata>::PLURAL, "kilometres");
+        assert_eq!(<KiloMeter as Metadata>::SYSTEM, System::Metric);
+    }
+
+    #[test]
+    fn default_metadata() {
+        assert_eq!(<Hand as Metadata>::SYMBOL, "Hand");
+        assert_eq!(Hand(1.0).plural(), "Hand");
+        assert_eq!(Hand(1.0).system(), System::Other);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_make_unit_forms() {
+        make_unit!(Span, 0.2286, Length);
+        make_unit!(Cubit, 0.4572, 0.0, Length);
+
+        assert_eq!(Span(1.0).symbol(), "Span");
+        assert_eq!(<Cubit as Metadata>::NAME, "Cubit");
+        assert_eq!(Cubit(1.0).system(), System::Other);
+        assert_eq!(Span::from(&Cubit(1.0)), Span(2.0));
+    }
+
+    #[test]
+    fn default_metadata_of_unit_impls() {
+        struct Span<V>(V);
+
+        impl<V: Copy> Unit<V> for Span<V> {
+            fn to_base(&self) -> V {
+                self.0
+            }
+
+            fn to_value(&self) -> V {
+                self.0
+            }
+
+            fn set_value(&mut self, value: V) {
+                self.0 = value;
+            }
+
+            fn set_from_base(&mut self, base: V) {
+                self.0 = base;
+            }
+
+            fn from_base(base: V) -> Self {
+                Self(base)
+            }
+        }
+
+        assert_eq!(Span(1.0).symbol(), "Span");
+        assert_eq!(Span(1.0).name(), "Span");
+        assert_eq!(Span(1.0).plural(), "Span");
+    }
 }
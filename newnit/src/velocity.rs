@@ -10,9 +10,12 @@
 //! - [`metric`] - International System of Units (SI)
 //! - [`nautical`] - International nautical units
 
-use crate::Unit;
+use crate::force::Force;
 use crate::length::metric::Meter;
+use crate::power::metric::Watt;
+use crate::scalar::Scalar;
 use crate::time::Time;
+use crate::{Quantity, dimension, parse};
 
 pub mod astronomical;
 pub mod imperial;
@@ -23,11 +26,73 @@ pub mod nautical;
 ///
 /// Provides various multiplication/ division methods, resulting in other units
 /// of quantity, based on the right hand side (`rhs`) in the multiplication.
-pub trait Velocity: Unit {
+pub trait Velocity<V: Scalar = f64>: Quantity<V, Dimension = dimension::Velocity> {
     /// Multiply a unit of velocity with a unit of time.
-    fn multiply_time(&self, rhs: &dyn Time) -> Meter {
+    fn multiply_time(&self, rhs: &dyn Time<V>) -> Meter<V> {
         Meter(self.to_base() * rhs.to_base())
     }
+
+    /// Multiply a unit of velocity with a unit of force.
+    fn multiply_force(&self, rhs: &dyn Force<V>) -> Watt<V> {
+        Watt(self.to_base() * rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Velocity>> Velocity<V> for T {}
+
+parse::units! {
+    /// Parse a velocity in the unit named by its symbol (e.g. `"50 km/h"`).
+    parse, UNITS: Velocity, VelocityUnit;
+    metric::{
+        QuettaMeterPerSecond,
+        RonnaMeterPerSecond,
+        YottaMeterPerSecond,
+        ZettaMeterPerSecond,
+        ExaMeterPerSecond,
+        PetaMeterPerSecond,
+        TeraMeterPerSecond,
+        GigaMeterPerSecond,
+        MegaMeterPerSecond,
+        KiloMeterPerSecond,
+        MeterPerSecond,
+        DeciMeterPerSecond,
+        CentiMeterPerSecond,
+        MilliMeterPerSecond,
+        MicroMeterPerSecond,
+        NanoMeterPerSecond,
+        PicoMeterPerSecond,
+        FemtoMeterPerSecond,
+        AttoMeterPerSecond,
+        ZeptoMeterPerSecond,
+        YoctoMeterPerSecond,
+        RontoMeterPerSecond,
+        QuectoMeterPerSecond,
+        AngstromPerSecond,
+        KiloMeterPerHour,
+    },
+    imperial::{
+        InchPerSecond,
+        FootPerSecond,
+        YardPerSecond,
+        ChainPerSecond,
+        FurlongPerSecond,
+        MilePerSecond,
+        LeaguePerSecond,
+        MilePerHour,
+    },
+    nautical::{
+        FathomPerSecond,
+        CablePerSecond,
+        MilePerSecond => NauticalMilePerSecond,
+        MilePerHour => Knot,
+    },
+    astronomical::{
+        MegaParsecPerSecond,
+        KiloParsecPerSecond,
+        ParsecPerSecond,
+        LightYearPerSecond,
+        AstronomicalUnitPerSecond,
+    },
 }
 
 #[cfg(test)]
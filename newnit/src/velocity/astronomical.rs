@@ -7,11 +7,51 @@
 //! [IAU]: https://www.iau.org/
 
 use super::Velocity;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Velocity};
 
-make_unit!(MegaParsecPerSecond, 3.085_677_581_491_37E+22, Velocity);
-make_unit!(KiloParsecPerSecond, 3.085_677_581_491_37E+19, Velocity);
-make_unit!(ParsecPerSecond, 3.085_677_581_491_37E+16, Velocity);
-make_unit!(LightYearPerSecond, 9.460_730_472_580_8E+15, Velocity);
-make_unit!(AstronomicalUnitPerSecond, 1.495_978_707E+11, Velocity);
+make_unit!(
+    MegaParsecPerSecond,
+    "Mpc/s",
+    "megaparsec per second",
+    "megaparsecs per second",
+    Astronomical,
+    3.085_677_581_491_37E+22,
+    Velocity
+);
+make_unit!(
+    KiloParsecPerSecond,
+    "kpc/s",
+    "kiloparsec per second",
+    "kiloparsecs per second",
+    Astronomical,
+    3.085_677_581_491_37E+19,
+    Velocity
+);
+make_unit!(
+    ParsecPerSecond,
+    "pc/s",
+    "parsec per second",
+    "parsecs per second",
+    Astronomical,
+    3.085_677_581_491_37E+16,
+    Velocity
+);
+make_unit!(
+    LightYearPerSecond,
+    "ly/s",
+    "light-year per second",
+    "light-years per second",
+    Astronomical,
+    9.460_730_472_580_8E+15,
+    Velocity
+);
+make_unit!(
+    AstronomicalUnitPerSecond,
+    "au/s",
+    "astronomical unit per second",
+    "astronomical units per second",
+    Astronomical,
+    1.495_978_707E+11,
+    Velocity
+);
@@ -5,15 +5,79 @@
 //! the meter per second.
 
 use super::Velocity;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Velocity};
 
-make_unit!(InchPerSecond, 0.0254, Velocity);
-make_unit!(FootPerSecond, 0.3048, Velocity);
-make_unit!(YardPerSecond, 0.9144, Velocity);
-make_unit!(ChainPerSecond, 20.1168, Velocity);
-make_unit!(FurlongPerSecond, 201.168, Velocity);
-make_unit!(MilePerSecond, 1_609.344, Velocity);
-make_unit!(LeaguePerSecond, 4_828.032, Velocity);
+make_unit!(
+    InchPerSecond,
+    "in/s",
+    "inch per second",
+    "inches per second",
+    Imperial,
+    0.0254,
+    Velocity
+);
+make_unit!(
+    FootPerSecond,
+    "ft/s",
+    "foot per second",
+    "feet per second",
+    Imperial,
+    0.3048,
+    Velocity
+);
+make_unit!(
+    YardPerSecond,
+    "yd/s",
+    "yard per second",
+    "yards per second",
+    Imperial,
+    0.9144,
+    Velocity
+);
+make_unit!(
+    ChainPerSecond,
+    "ch/s",
+    "chain per second",
+    "chains per second",
+    Imperial,
+    20.1168,
+    Velocity
+);
+make_unit!(
+    FurlongPerSecond,
+    "fur/s",
+    "furlong per second",
+    "furlongs per second",
+    Imperial,
+    201.168,
+    Velocity
+);
+make_unit!(
+    MilePerSecond,
+    "mi/s",
+    "mile per second",
+    "miles per second",
+    Imperial,
+    1_609.344,
+    Velocity
+);
+make_unit!(
+    LeaguePerSecond,
+    "lea/s",
+    "league per second",
+    "leagues per second",
+    Imperial,
+    4_828.032,
+    Velocity
+);
 
-make_unit!(MilePerHour, 0.44704, Velocity);
+make_unit!(
+    MilePerHour,
+    "mph",
+    "mile per hour",
+    "miles per hour",
+    Imperial,
+    0.44704,
+    Velocity
+);
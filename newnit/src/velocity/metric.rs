@@ -8,33 +8,233 @@
 //! m/s.
 
 use super::Velocity;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Velocity};
 
-make_unit!(QuettaMeterPerSecond, 1E+30, Velocity);
-make_unit!(RonnaMeterPerSecond, 1E+27, Velocity);
-make_unit!(YottaMeterPerSecond, 1E+24, Velocity);
-make_unit!(ZettaMeterPerSecond, 1E+21, Velocity);
-make_unit!(ExaMeterPerSecond, 1E+18, Velocity);
-make_unit!(PetaMeterPerSecond, 1E+15, Velocity);
-make_unit!(TeraMeterPerSecond, 1E+12, Velocity);
-make_unit!(GigaMeterPerSecond, 1E+9, Velocity);
-make_unit!(MegaMeterPerSecond, 1E+6, Velocity);
-make_unit!(KiloMeterPerSecond, 1E+3, Velocity);
-make_unit!(MeterPerSecond, 1.0, Velocity);
-make_unit!(DeciMeterPerSecond, 1E-1, Velocity);
-make_unit!(CentiMeterPerSecond, 1E-2, Velocity);
-make_unit!(MilliMeterPerSecond, 1E-3, Velocity);
-make_unit!(MicroMeterPerSecond, 1E-6, Velocity);
-make_unit!(NanoMeterPerSecond, 1E-9, Velocity);
-make_unit!(PicoMeterPerSecond, 1E-12, Velocity);
-make_unit!(FemtoMeterPerSecond, 1E-15, Velocity);
-make_unit!(AttoMeterPerSecond, 1E-18, Velocity);
-make_unit!(ZeptoMeterPerSecond, 1E-21, Velocity);
-make_unit!(YoctoMeterPerSecond, 1E-24, Velocity);
-make_unit!(RontoMeterPerSecond, 1E-27, Velocity);
-make_unit!(QuectoMeterPerSecond, 1E-30, Velocity);
+make_unit!(
+    QuettaMeterPerSecond,
+    "Qm/s",
+    "quettametre per second",
+    "quettametres per second",
+    Metric,
+    1E+30,
+    Velocity
+);
+make_unit!(
+    RonnaMeterPerSecond,
+    "Rm/s",
+    "ronnametre per second",
+    "ronnametres per second",
+    Metric,
+    1E+27,
+    Velocity
+);
+make_unit!(
+    YottaMeterPerSecond,
+    "Ym/s",
+    "yottametre per second",
+    "yottametres per second",
+    Metric,
+    1E+24,
+    Velocity
+);
+make_unit!(
+    ZettaMeterPerSecond,
+    "Zm/s",
+    "zettametre per second",
+    "zettametres per second",
+    Metric,
+    1E+21,
+    Velocity
+);
+make_unit!(
+    ExaMeterPerSecond,
+    "Em/s",
+    "exametre per second",
+    "exametres per second",
+    Metric,
+    1E+18,
+    Velocity
+);
+make_unit!(
+    PetaMeterPerSecond,
+    "Pm/s",
+    "petametre per second",
+    "petametres per second",
+    Metric,
+    1E+15,
+    Velocity
+);
+make_unit!(
+    TeraMeterPerSecond,
+    "Tm/s",
+    "terametre per second",
+    "terametres per second",
+    Metric,
+    1E+12,
+    Velocity
+);
+make_unit!(
+    GigaMeterPerSecond,
+    "Gm/s",
+    "gigametre per second",
+    "gigametres per second",
+    Metric,
+    1E+9,
+    Velocity
+);
+make_unit!(
+    MegaMeterPerSecond,
+    "Mm/s",
+    "megametre per second",
+    "megametres per second",
+    Metric,
+    1E+6,
+    Velocity
+);
+make_unit!(
+    KiloMeterPerSecond,
+    "km/s",
+    "kilometre per second",
+    "kilometres per second",
+    Metric,
+    1E+3,
+    Velocity
+);
+make_unit!(
+    MeterPerSecond,
+    "m/s",
+    "metre per second",
+    "metres per second",
+    Metric,
+    1.0,
+    Velocity
+);
+make_unit!(
+    DeciMeterPerSecond,
+    "dm/s",
+    "decimetre per second",
+    "decimetres per second",
+    Metric,
+    1E-1,
+    Velocity
+);
+make_unit!(
+    CentiMeterPerSecond,
+    "cm/s",
+    "centimetre per second",
+    "centimetres per second",
+    Metric,
+    1E-2,
+    Velocity
+);
+make_unit!(
+    MilliMeterPerSecond,
+    "mm/s",
+    "millimetre per second",
+    "millimetres per second",
+    Metric,
+    1E-3,
+    Velocity
+);
+make_unit!(
+    MicroMeterPerSecond,
+    "µm/s",
+    "micrometre per second",
+    "micrometres per second",
+    Metric,
+    1E-6,
+    Velocity
+);
+make_unit!(
+    NanoMeterPerSecond,
+    "nm/s",
+    "nanometre per second",
+    "nanometres per second",
+    Metric,
+    1E-9,
+    Velocity
+);
+make_unit!(
+    PicoMeterPerSecond,
+    "pm/s",
+    "picometre per second",
+    "picometres per second",
+    Metric,
+    1E-12,
+    Velocity
+);
+make_unit!(
+    FemtoMeterPerSecond,
+    "fm/s",
+    "femtometre per second",
+    "femtometres per second",
+    Metric,
+    1E-15,
+    Velocity
+);
+make_unit!(
+    AttoMeterPerSecond,
+    "am/s",
+    "attometre per second",
+    "attometres per second",
+    Metric,
+    1E-18,
+    Velocity
+);
+make_unit!(
+    ZeptoMeterPerSecond,
+    "zm/s",
+    "zeptometre per second",
+    "zeptometres per second",
+    Metric,
+    1E-21,
+    Velocity
+);
+make_unit!(
+    YoctoMeterPerSecond,
+    "ym/s",
+    "yoctometre per second",
+    "yoctometres per second",
+    Metric,
+    1E-24,
+    Velocity
+);
+make_unit!(
+    RontoMeterPerSecond,
+    "rm/s",
+    "rontometre per second",
+    "rontometres per second",
+    Metric,
+    1E-27,
+    Velocity
+);
+make_unit!(
+    QuectoMeterPerSecond,
+    "qm/s",
+    "quectometre per second",
+    "quectometres per second",
+    Metric,
+    1E-30,
+    Velocity
+);
 
-make_unit!(AngstromPerSecond, 1E-10, Velocity);
+make_unit!(
+    AngstromPerSecond,
+    "Å/s",
+    "ångström per second",
+    "ångströms per second",
+    Metric,
+    1E-10,
+    Velocity
+);
 
-make_unit!(KiloMeterPerHour, 2.777_777_777_777_777_8E-1, Velocity);
+make_unit!(
+    KiloMeterPerHour,
+    "km/h",
+    "kilometre per hour",
+    "kilometres per hour",
+    Metric,
+    2.777_777_777_777_777_8E-1,
+    Velocity
+);
@@ -4,13 +4,45 @@
 //! marine, air and space navigation. The base unit is the meter per second.
 
 use super::Velocity;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Velocity};
 
-make_unit!(FathomPerSecond, 1.828_8, Velocity);
-make_unit!(CablePerSecond, 219.456, Velocity);
-make_unit!(MilePerSecond, 1852.0, Velocity);
+make_unit!(
+    FathomPerSecond,
+    "ftm/s",
+    "fathom per second",
+    "fathoms per second",
+    Nautical,
+    1.828_8,
+    Velocity
+);
+make_unit!(
+    CablePerSecond,
+    "cb/s",
+    "cable per second",
+    "cables per second",
+    Nautical,
+    219.456,
+    Velocity
+);
+make_unit!(
+    MilePerSecond,
+    "nmi/s",
+    "nautical mile per second",
+    "nautical miles per second",
+    Nautical,
+    1852.0,
+    Velocity
+);
 
-make_unit!(MilePerHour, 5.144_444_444_444_444_4E-1, Velocity);
+make_unit!(
+    MilePerHour,
+    "kn",
+    "knot",
+    "knots",
+    Nautical,
+    5.144_444_444_444_444_4E-1,
+    Velocity
+);
 
 pub type Knot = MilePerHour;
@@ -0,0 +1,309 @@
+//! Units of voltage (electric potential difference).
+//!
+//! This module contains predefined newtypes for units of voltage as defined in
+//! the International System of Units (SI). The base unit is the [`Volt`].
+//!
+//! [`Volt`]: metric::Volt
+
+use crate::capacitance::Capacitance;
+use crate::charge::Charge;
+use crate::charge::metric::Coulomb;
+use crate::conductance::Conductance;
+use crate::current::Current;
+use crate::current::metric::Ampere;
+use crate::energy::metric::Joule;
+use crate::magnetic_flux::metric::Weber;
+use crate::power::metric::Watt;
+use crate::resistance::Resistance;
+use crate::resistance::metric::Ohm;
+use crate::scalar::Scalar;
+use crate::time::Time;
+use crate::{Quantity, dimension, parse};
+
+/// Types that are units of voltage.
+///
+/// Provides various multiplication and division methods, resulting in other
+/// units of quantity, based on the right hand side (`rhs`) in the
+/// multiplication/ division.
+pub trait Voltage<V: Scalar = f64>: Quantity<V, Dimension = dimension::Voltage> {
+    /// Multiply a unit of voltage with a unit of current.
+    fn multiply_current(&self, rhs: &dyn Current<V>) -> Watt<V> {
+        Watt(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of voltage with a unit of electric charge.
+    fn multiply_charge(&self, rhs: &dyn Charge<V>) -> Joule<V> {
+        Joule(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of voltage with a unit of conductance.
+    fn multiply_conductance(&self, rhs: &dyn Conductance<V>) -> Ampere<V> {
+        Ampere(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of voltage with a unit of capacitance.
+    fn multiply_capacitance(&self, rhs: &dyn Capacitance<V>) -> Coulomb<V> {
+        Coulomb(self.to_base() * rhs.to_base())
+    }
+
+    /// Multiply a unit of voltage with a unit of time.
+    fn multiply_time(&self, rhs: &dyn Time<V>) -> Weber<V> {
+        Weber(self.to_base() * rhs.to_base())
+    }
+
+    /// Divide a unit of voltage by a unit of current.
+    fn divide_current(&self, rhs: &dyn Current<V>) -> Ohm<V> {
+        Ohm(self.to_base() / rhs.to_base())
+    }
+
+    /// Divide a unit of voltage by a unit of resistance.
+    fn divide_resistance(&self, rhs: &dyn Resistance<V>) -> Ampere<V> {
+        Ampere(self.to_base() / rhs.to_base())
+    }
+}
+
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Voltage>> Voltage<V> for T {}
+
+pub mod metric {
+    use super::Voltage;
+    use crate::make_unit;
+    use newnit_derive::{Unit, Voltage};
+
+    make_unit!(
+        QuettaVolt,
+        "QV",
+        "quettavolt",
+        "quettavolts",
+        Metric,
+        1E+30,
+        Voltage
+    );
+    make_unit!(
+        RonnaVolt,
+        "RV",
+        "ronnavolt",
+        "ronnavolts",
+        Metric,
+        1E+27,
+        Voltage
+    );
+    make_unit!(
+        YottaVolt,
+        "YV",
+        "yottavolt",
+        "yottavolts",
+        Metric,
+        1E+24,
+        Voltage
+    );
+    make_unit!(
+        ZettaVolt,
+        "ZV",
+        "zettavolt",
+        "zettavolts",
+        Metric,
+        1E+21,
+        Voltage
+    );
+    make_unit!(ExaVolt, "EV", "exavolt", "exavolts", Metric, 1E+18, Voltage);
+    make_unit!(
+        PetaVolt,
+        "PV",
+        "petavolt",
+        "petavolts",
+        Metric,
+        1E+15,
+        Voltage
+    );
+    make_unit!(
+        TeraVolt,
+        "TV",
+        "teravolt",
+        "teravolts",
+        Metric,
+        1E+12,
+        Voltage
+    );
+    make_unit!(
+        GigaVolt,
+        "GV",
+        "gigavolt",
+        "gigavolts",
+        Metric,
+        1E+9,
+        Voltage
+    );
+    make_unit!(
+        MegaVolt,
+        "MV",
+        "megavolt",
+        "megavolts",
+        Metric,
+        1E+6,
+        Voltage
+    );
+    make_unit!(
+        KiloVolt,
+        "kV",
+        "kilovolt",
+        "kilovolts",
+        Metric,
+        1E+3,
+        Voltage
+    );
+    make_unit!(Volt, "V", "volt", "volts", Metric, 1.0, Voltage);
+    make_unit!(
+        DeciVolt,
+        "dV",
+        "decivolt",
+        "decivolts",
+        Metric,
+        1E-1,
+        Voltage
+    );
+    make_unit!(
+        CentiVolt,
+        "cV",
+        "centivolt",
+        "centivolts",
+        Metric,
+        1E-2,
+        Voltage
+    );
+    make_unit!(
+        MilliVolt,
+        "mV",
+        "millivolt",
+        "millivolts",
+        Metric,
+        1E-3,
+        Voltage
+    );
+    make_unit!(
+        MicroVolt,
+        "µV",
+        "microvolt",
+        "microvolts",
+        Metric,
+        1E-6,
+        Voltage
+    );
+    make_unit!(
+        NanoVolt,
+        "nV",
+        "nanovolt",
+        "nanovolts",
+        Metric,
+        1E-9,
+        Voltage
+    );
+    make_unit!(
+        PicoVolt,
+        "pV",
+        "picovolt",
+        "picovolts",
+        Metric,
+        1E-12,
+        Voltage
+    );
+    make_unit!(
+        FemtoVolt,
+        "fV",
+        "femtovolt",
+        "femtovolts",
+        Metric,
+        1E-15,
+        Voltage
+    );
+    make_unit!(
+        AttoVolt,
+        "aV",
+        "attovolt",
+        "attovolts",
+        Metric,
+        1E-18,
+        Voltage
+    );
+    make_unit!(
+        ZeptoVolt,
+        "zV",
+        "zeptovolt",
+        "zeptovolts",
+        Metric,
+        1E-21,
+        Voltage
+    );
+    make_unit!(
+        YoctoVolt,
+        "yV",
+        "yoctovolt",
+        "yoctovolts",
+        Metric,
+        1E-24,
+        Voltage
+    );
+    make_unit!(
+        RontoVolt,
+        "rV",
+        "rontovolt",
+        "rontovolts",
+        Metric,
+        1E-27,
+        Voltage
+    );
+    make_unit!(
+        QuectoVolt,
+        "qV",
+        "quectovolt",
+        "quectovolts",
+        Metric,
+        1E-30,
+        Voltage
+    );
+}
+
+parse::units! {
+    /// Parse a voltage in the unit named by its symbol (e.g. `"230 V"`).
+    parse, UNITS: Voltage, VoltageUnit;
+    metric::{
+        QuettaVolt,
+        RonnaVolt,
+        YottaVolt,
+        ZettaVolt,
+        ExaVolt,
+        PetaVolt,
+        TeraVolt,
+        GigaVolt,
+        MegaVolt,
+        KiloVolt,
+        Volt,
+        DeciVolt,
+        CentiVolt,
+        MilliVolt,
+        MicroVolt,
+        NanoVolt,
+        PicoVolt,
+        FemtoVolt,
+        AttoVolt,
+        ZeptoVolt,
+        YoctoVolt,
+        RontoVolt,
+        QuectoVolt,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_quantity_eq;
+    use crate::current::metric::{Ampere, MilliAmpere};
+    use crate::resistance::metric::KiloOhm;
+
+    #[test]
+    fn ohms_law() {
+        let voltage = metric::Volt(12.0_f64);
+        assert_quantity_eq!(voltage.divide_current(&MilliAmpere(6.0)), KiloOhm(2.0));
+        assert_quantity_eq!(voltage.divide_resistance(&KiloOhm(4.0)), MilliAmpere(3.0));
+        assert_quantity_eq!(voltage.multiply_current(&Ampere(2.0)), Watt(24.0));
+    }
+}
@@ -6,28 +6,91 @@
 //! - [`imperial`] - British Imperial units
 //! - [`metric`] - International System of Units (SI)
 
-use crate::Unit;
 use crate::area::Area;
 use crate::area::metric::SquareMeter;
 use crate::length::Length;
 use crate::length::metric::Meter;
+use crate::scalar::Scalar;
+use crate::{Quantity, dimension, parse};
 
 pub mod customary;
 pub mod imperial;
 pub mod metric;
 
-pub trait Volume: Unit {
+pub trait Volume<V: Scalar = f64>: Quantity<V, Dimension = dimension::Volume> {
     /// Divide a unit of volume by a unit of length
-    fn divide_length(&self, rhs: &dyn Length) -> SquareMeter {
+    fn divide_length(&self, rhs: &dyn Length<V>) -> SquareMeter<V> {
         SquareMeter(self.to_base() / rhs.to_base())
     }
 
     /// Divide a unit of volume by a unit of area
-    fn divide_area(&self, rhs: &dyn Area) -> Meter {
+    fn divide_area(&self, rhs: &dyn Area<V>) -> Meter<V> {
         Meter(self.to_base() / rhs.to_base())
     }
 }
 
+impl<V: Scalar, T: Quantity<V, Dimension = dimension::Volume>> Volume<V> for T {}
+
+parse::units! {
+    /// Parse a volume in the unit named by its symbol (e.g. `"2.5 L"`).
+    parse, UNITS: Volume, VolumeUnit;
+    metric::{
+        CubicQuettaMeter,
+        CubicRonnaMeter,
+        CubicYottaMeter,
+        CubicZettaMeter,
+        CubicExaMeter,
+        CubicPetaMeter,
+        CubicTeraMeter,
+        CubicGigaMeter,
+        CubicMegaMeter,
+        CubicKiloMeter,
+        CubicMeter,
+        CubicDeciMeter,
+        CubicCentiMeter,
+        CubicMilliMeter,
+        CubicMicroMeter,
+        CubicNanoMeter,
+        CubicPicoMeter,
+        CubicFemtoMeter,
+        CubicAttoMeter,
+        CubicZeptoMeter,
+        CubicYoctoMeter,
+        CubicRontoMeter,
+        CubicQuectoMeter,
+        CubicAngstrom,
+        HectoLiter,
+        DeciLiter,
+        CentiLiter,
+    },
+    imperial::{
+        CubicInch,
+        CubicFoot,
+        CubicYard,
+        CubicChain,
+        CubicFurlong,
+        CubicMile,
+        CubicLeague,
+        FluidOunce => ImperialFluidOunce,
+        Gill => ImperialGill,
+        Pint => ImperialPint,
+        Quart,
+        Gallon => ImperialGallon,
+    },
+    customary::{
+        AcreFoot,
+        TeaSpoon,
+        TableSpoon,
+        FluidOunce => UsFluidOunce,
+        Gill => UsGill,
+        Cup,
+        Pint => UsPint,
+        Quarter,
+        Gallon => UsGallon,
+        Barrel,
+    },
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,35 +10,115 @@
 //! [`volume::imperial`]: super::imperial
 
 use super::Volume;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Volume};
 
 // acre ft
-make_unit!(AcreFoot, 1_233.482, Volume);
+make_unit!(
+    AcreFoot,
+    "ac ft",
+    "acre-foot",
+    "acre-feet",
+    UsCustomary,
+    1_233.482,
+    Volume
+);
 
 // tsp
-make_unit!(TeaSpoon, 4.928_921_593_75e-6, Volume);
+make_unit!(
+    TeaSpoon,
+    "tsp",
+    "teaspoon",
+    "teaspoons",
+    UsCustomary,
+    4.928_921_593_75e-6,
+    Volume
+);
 
 // tbsp
-make_unit!(TableSpoon, 14.786_764_781_25e-6, Volume);
+make_unit!(
+    TableSpoon,
+    "tbsp",
+    "tablespoon",
+    "tablespoons",
+    UsCustomary,
+    14.786_764_781_25e-6,
+    Volume
+);
 
 // US fl oz
-make_unit!(FluidOunce, 29.573_529_562_5e-6, Volume);
+make_unit!(
+    FluidOunce,
+    "fl oz",
+    "fluid ounce",
+    "fluid ounces",
+    UsCustomary,
+    29.573_529_562_5e-6,
+    Volume
+);
 
 // US gi
-make_unit!(Gill, 118.294_118_25e-6, Volume);
+make_unit!(
+    Gill,
+    "gi",
+    "gill",
+    "gills",
+    UsCustomary,
+    118.294_118_25e-6,
+    Volume
+);
 
 // c
-make_unit!(Cup, 236.588_236_5e-6, Volume);
+make_unit!(
+    Cup,
+    "cup",
+    "cup",
+    "cups",
+    UsCustomary,
+    236.588_236_5e-6,
+    Volume
+);
 
 // US pt
-make_unit!(Pint, 0.473176473e-3, Volume);
+make_unit!(
+    Pint,
+    "pt",
+    "pint",
+    "pints",
+    UsCustomary,
+    0.473176473e-3,
+    Volume
+);
 
 // US qt
-make_unit!(Quarter, 0.946_352_946e-3, Volume);
+make_unit!(
+    Quarter,
+    "qt",
+    "quart",
+    "quarts",
+    UsCustomary,
+    0.946_352_946e-3,
+    Volume
+);
 
 // US gal
-make_unit!(Gallon, 3.785_411_784e-3, Volume);
+make_unit!(
+    Gallon,
+    "gal",
+    "gallon",
+    "gallons",
+    UsCustomary,
+    3.785_411_784e-3,
+    Volume
+);
 
 // bbl
-make_unit!(Barrel, 158.987_294_928e-3, Volume);
+make_unit!(
+    Barrel,
+    "bbl",
+    "barrel",
+    "barrels",
+    UsCustomary,
+    158.987_294_928e-3,
+    Volume
+);
@@ -4,31 +4,127 @@
 //! the British Imperial system. The base unit is the cubic meter.
 
 use super::Volume;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Volume};
 
 // Trivial cubes of units of length
-make_unit!(CubicInch, 0.163_870_64E-4, Volume);
-make_unit!(CubicFoot, 0.028_316_846_592, Volume);
-make_unit!(CubicYard, 0.764_554_857_984, Volume);
-make_unit!(CubicChain, 8_140.980_127_813_6, Volume);
-make_unit!(CubicFurlong, 8_140_980.127_813_6, Volume);
-make_unit!(CubicMile, 4_168_181_825.440_6, Volume);
-make_unit!(CubicLeague, 112_540_909_286.9, Volume);
+make_unit!(
+    CubicInch,
+    "in³",
+    "cubic inch",
+    "cubic inches",
+    Imperial,
+    0.163_870_64E-4,
+    Volume
+);
+make_unit!(
+    CubicFoot,
+    "ft³",
+    "cubic foot",
+    "cubic feet",
+    Imperial,
+    0.028_316_846_592,
+    Volume
+);
+make_unit!(
+    CubicYard,
+    "yd³",
+    "cubic yard",
+    "cubic yards",
+    Imperial,
+    0.764_554_857_984,
+    Volume
+);
+make_unit!(
+    CubicChain,
+    "ch³",
+    "cubic chain",
+    "cubic chains",
+    Imperial,
+    8_140.980_127_813_6,
+    Volume
+);
+make_unit!(
+    CubicFurlong,
+    "fur³",
+    "cubic furlong",
+    "cubic furlongs",
+    Imperial,
+    8_140_980.127_813_6,
+    Volume
+);
+make_unit!(
+    CubicMile,
+    "mi³",
+    "cubic mile",
+    "cubic miles",
+    Imperial,
+    4_168_181_825.440_6,
+    Volume
+);
+make_unit!(
+    CubicLeague,
+    "lea³",
+    "cubic league",
+    "cubic leagues",
+    Imperial,
+    112_540_909_286.9,
+    Volume
+);
 
 // Non-trivial units
 
 // fl oz
-make_unit!(FluidOunce, 28.413_062_5e-6, Volume);
+make_unit!(
+    FluidOunce,
+    "imp fl oz",
+    "imperial fluid ounce",
+    "imperial fluid ounces",
+    Imperial,
+    28.413_062_5e-6,
+    Volume
+);
 
 // gi
-make_unit!(Gill, 142.065_312_5e-6, Volume);
+make_unit!(
+    Gill,
+    "imp gi",
+    "imperial gill",
+    "imperial gills",
+    Imperial,
+    142.065_312_5e-6,
+    Volume
+);
 
 // pt
-make_unit!(Pint, 568.261_25e-6, Volume);
+make_unit!(
+    Pint,
+    "imp pt",
+    "imperial pint",
+    "imperial pints",
+    Imperial,
+    568.261_25e-6,
+    Volume
+);
 
 // qt
-make_unit!(Quart, 1_136.522_5e-6, Volume);
+make_unit!(
+    Quart,
+    "imp qt",
+    "imperial quart",
+    "imperial quarts",
+    Imperial,
+    1_136.522_5e-6,
+    Volume
+);
 
 // gal
-make_unit!(Gallon, 4_546.09e-6, Volume);
+make_unit!(
+    Gallon,
+    "imp gal",
+    "imperial gallon",
+    "imperial gallons",
+    Imperial,
+    4_546.09e-6,
+    Volume
+);
@@ -9,40 +9,259 @@
 //! Additional aliases provided for commonly used alternative unit names.
 
 use super::Volume;
-use crate::{Unit, make_unit};
+use crate::make_unit;
 use newnit_derive::{Unit, Volume};
 
 // Trivial cubes of units of length
-make_unit!(CubicQuettaMeter, 1E+90, Volume);
-make_unit!(CubicRonnaMeter, 1E+81, Volume);
-make_unit!(CubicYottaMeter, 1E+72, Volume);
-make_unit!(CubicZettaMeter, 1E+63, Volume);
-make_unit!(CubicExaMeter, 1E+54, Volume);
-make_unit!(CubicPetaMeter, 1E+45, Volume);
-make_unit!(CubicTeraMeter, 1E+36, Volume);
-make_unit!(CubicGigaMeter, 1E+27, Volume);
-make_unit!(CubicMegaMeter, 1E+18, Volume);
-make_unit!(CubicKiloMeter, 1E+9, Volume);
-make_unit!(CubicMeter, 1.0, Volume);
-make_unit!(CubicDeciMeter, 1E-3, Volume);
-make_unit!(CubicCentiMeter, 1E-6, Volume);
-make_unit!(CubicMilliMeter, 1E-9, Volume);
-make_unit!(CubicMicroMeter, 1E-18, Volume);
-make_unit!(CubicNanoMeter, 1E-27, Volume);
-make_unit!(CubicPicoMeter, 1E-36, Volume);
-make_unit!(CubicFemtoMeter, 1E-45, Volume);
-make_unit!(CubicAttoMeter, 1E-54, Volume);
-make_unit!(CubicZeptoMeter, 1E-63, Volume);
-make_unit!(CubicYoctoMeter, 1E-72, Volume);
-make_unit!(CubicRontoMeter, 1E-81, Volume);
-make_unit!(CubicQuectoMeter, 1E-90, Volume);
+make_unit!(
+    CubicQuettaMeter,
+    "Qm³",
+    "cubic quettametre",
+    "cubic quettametres",
+    Metric,
+    1E+90,
+    Volume
+);
+make_unit!(
+    CubicRonnaMeter,
+    "Rm³",
+    "cubic ronnametre",
+    "cubic ronnametres",
+    Metric,
+    1E+81,
+    Volume
+);
+make_unit!(
+    CubicYottaMeter,
+    "Ym³",
+    "cubic yottametre",
+    "cubic yottametres",
+    Metric,
+    1E+72,
+    Volume
+);
+make_unit!(
+    CubicZettaMeter,
+    "Zm³",
+    "cubic zettametre",
+    "cubic zettametres",
+    Metric,
+    1E+63,
+    Volume
+);
+make_unit!(
+    CubicExaMeter,
+    "Em³",
+    "cubic exametre",
+    "cubic exametres",
+    Metric,
+    1E+54,
+    Volume
+);
+make_unit!(
+    CubicPetaMeter,
+    "Pm³",
+    "cubic petametre",
+    "cubic petametres",
+    Metric,
+    1E+45,
+    Volume
+);
+make_unit!(
+    CubicTeraMeter,
+    "Tm³",
+    "cubic terametre",
+    "cubic terametres",
+    Metric,
+    1E+36,
+    Volume
+);
+make_unit!(
+    CubicGigaMeter,
+    "Gm³",
+    "cubic gigametre",
+    "cubic gigametres",
+    Metric,
+    1E+27,
+    Volume
+);
+make_unit!(
+    CubicMegaMeter,
+    "Mm³",
+    "cubic megametre",
+    "cubic megametres",
+    Metric,
+    1E+18,
+    Volume
+);
+make_unit!(
+    CubicKiloMeter,
+    "km³",
+    "cubic kilometre",
+    "cubic kilometres",
+    Metric,
+    1E+9,
+    Volume
+);
+make_unit!(
+    CubicMeter,
+    "m³",
+    "cubic metre",
+    "cubic metres",
+    Metric,
+    1.0,
+    Volume
+);
+make_unit!(
+    #[unit(alias = "L", alias = "litre", alias = "litres")]
+    CubicDeciMeter,
+    "dm³",
+    "cubic decimetre",
+    "cubic decimetres",
+    Metric,
+    1E-3,
+    Volume
+);
+make_unit!(
+    #[unit(alias = "mL", alias = "millilitre", alias = "millilitres")]
+    CubicCentiMeter,
+    "cm³",
+    "cubic centimetre",
+    "cubic centimetres",
+    Metric,
+    1E-6,
+    Volume
+);
+make_unit!(
+    #[unit(alias = "µL", alias = "microlitre", alias = "microlitres")]
+    CubicMilliMeter,
+    "mm³",
+    "cubic millimetre",
+    "cubic millimetres",
+    Metric,
+    1E-9,
+    Volume
+);
+make_unit!(
+    CubicMicroMeter,
+    "µm³",
+    "cubic micrometre",
+    "cubic micrometres",
+    Metric,
+    1E-18,
+    Volume
+);
+make_unit!(
+    CubicNanoMeter,
+    "nm³",
+    "cubic nanometre",
+    "cubic nanometres",
+    Metric,
+    1E-27,
+    Volume
+);
+make_unit!(
+    CubicPicoMeter,
+    "pm³",
+    "cubic picometre",
+    "cubic picometres",
+    Metric,
+    1E-36,
+    Volume
+);
+make_unit!(
+    CubicFemtoMeter,
+    "fm³",
+    "cubic femtometre",
+    "cubic femtometres",
+    Metric,
+    1E-45,
+    Volume
+);
+make_unit!(
+    CubicAttoMeter,
+    "am³",
+    "cubic attometre",
+    "cubic attometres",
+    Metric,
+    1E-54,
+    Volume
+);
+make_unit!(
+    CubicZeptoMeter,
+    "zm³",
+    "cubic zeptometre",
+    "cubic zeptometres",
+    Metric,
+    1E-63,
+    Volume
+);
+make_unit!(
+    CubicYoctoMeter,
+    "ym³",
+    "cubic yoctometre",
+    "cubic yoctometres",
+    Metric,
+    1E-72,
+    Volume
+);
+make_unit!(
+    CubicRontoMeter,
+    "rm³",
+    "cubic rontometre",
+    "cubic rontometres",
+    Metric,
+    1E-81,
+    Volume
+);
+make_unit!(
+    CubicQuectoMeter,
+    "qm³",
+    "cubic quectometre",
+    "cubic quectometres",
+    Metric,
+    1E-90,
+    Volume
+);
 
-make_unit!(CubicAngstrom, 1E-30, Volume);
+make_unit!(
+    CubicAngstrom,
+    "Å³",
+    "cubic ångström",
+    "cubic ångströms",
+    Metric,
+    1E-30,
+    Volume
+);
 
 // Non-trivial units
-make_unit!(HectoLiter, 1e-1, Volume);
+make_unit!(
+    HectoLiter,
+    "hL",
+    "hectolitre",
+    "hectolitres",
+    Metric,
+    1e-1,
+    Volume
+);
 pub type Liter = CubicDeciMeter;
-make_unit!(DeciLiter, 1e-4, Volume);
-make_unit!(CentiLiter, 1e-5, Volume);
+make_unit!(
+    DeciLiter,
+    "dL",
+    "decilitre",
+    "decilitres",
+    Metric,
+    1e-4,
+    Volume
+);
+make_unit!(
+    CentiLiter,
+    "cL",
+    "centilitre",
+    "centilitres",
+    Metric,
+    1e-5,
+    Volume
+);
 pub type MilliLiter = CubicCentiMeter;
 pub type MicroLiter = CubicMilliMeter;
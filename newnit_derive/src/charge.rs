@@ -0,0 +1,13 @@
+use darling::FromDeriveInput;
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+
+use crate::impl_quantity::{QuantityArgs, impl_quantity};
+
+pub fn derive(ast: &syn::DeriveInput) -> TokenStream {
+    let args = QuantityArgs::from_derive_input(ast).expect("Failed parsing derive arguments.");
+
+    let quantity = Ident::new("Charge", Span::call_site());
+
+    impl_quantity(ast, &quantity, &quantity, &args)
+}
@@ -0,0 +1,23 @@
+use darling::FromDeriveInput;
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+
+use crate::impl_quantity::{QuantityArgs, impl_quantity};
+use crate::storage::Storage;
+
+pub fn derive(ast: &syn::DeriveInput) -> TokenStream {
+    let mut args = QuantityArgs::from_derive_input(ast).expect("Failed parsing derive arguments.");
+
+    // Gauge pressures are always points on a scale relative to the atmospheric
+    // pressure, with pressure differences stored in the same type as the
+    // pressure.
+    args.interval.get_or_insert_with(|| {
+        let value = Storage::new(ast).value;
+        syn::parse_quote!(::newnit::pressure::metric::PascalDifferential<#value>)
+    });
+
+    let quantity = Ident::new("GaugePressure", Span::call_site());
+    let dimension = Ident::new("PressureDifference", Span::call_site());
+
+    impl_quantity(ast, &quantity, &dimension, &args)
+}
@@ -1,12 +1,20 @@
 use darling::FromDeriveInput;
 use proc_macro::TokenStream;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::quote;
 
+use crate::storage::Storage;
+
 /// Arguments for quantity trait derive macros
 ///
 /// These arguments specify whether the derive macro should additionally derive
-/// [`From`], [`PartialEq`] and select [`std::ops`] traits respectively.
+/// [`From`] (along with [`TryFrom`] a `DynQuantity` and
+/// [`FromStr`](core::str::FromStr)), [`PartialEq`] and [`PartialOrd`] with all
+/// units of the quantity, select [`core::ops`] traits, serde's `Serialize` and
+/// `Deserialize` and the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq`
+/// respectively, whether to register the unit in the registry, and the
+/// interval unit of units measuring points on an affine scale or quantities
+/// that can't be negative.
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(quantity))]
 pub(crate) struct QuantityArgs {
@@ -14,115 +22,468 @@ pub(crate) struct QuantityArgs {
     from: bool,
     #[darling(default)]
     ops: bool,
+    /// Compare with all units of the quantity.
+    #[darling(default)]
+    cmp: bool,
+    /// Serialize as the value with the symbol of the unit.
+    #[darling(default)]
+    serde: bool,
+    /// Compare values with the traits of the approx crate.
+    #[darling(default)]
+    approx: bool,
+    /// Register the unit in the registry under the quantity.
+    #[darling(default)]
+    register: bool,
+    /// Unit of differences between points, for units on an affine scale.
+    #[darling(default)]
+    pub(crate) interval: Option<syn::Path>,
+    /// Dimension of the differences, for units of quantities that can't be
+    /// negative, whose differences are of a distinct quantity.
+    #[darling(skip)]
+    pub(crate) difference: Option<Ident>,
 }
 
 /// Provide impl of quantity for the given unit.
 ///
+/// The unit is assigned the given dimension (a type alias from the
+/// `newnit::dimension` module), which makes it implement the quantity trait.
+/// If an interval unit is given in args, the unit measures points on an affine
+/// scale of the dimension instead, unless the dimension of the differences is
+/// given too, in which case only subtraction yields intervals.
+///
 /// Additionally provide other impls (see [`QuantityArgs`]) specified in args.
-pub(crate) fn impl_quantity(unit: &Ident, quantity: &Ident, args: &QuantityArgs) -> TokenStream {
-    let impl_from = args.from.then(|| {
+pub(crate) fn impl_quantity(
+    ast: &syn::DeriveInput,
+    quantity: &Ident,
+    dimension: &Ident,
+    args: &QuantityArgs,
+) -> TokenStream {
+    let storage = Storage::new(ast);
+    let unit = &storage.unit;
+    let value = &storage.value;
+    let params = storage.params(quote! {});
+    let where_clause = storage.where_clause(quote! {});
+
+    let dimension_type = match (&args.interval, &args.difference) {
+        (Some(_), None) => {
+            quote! { ::newnit::dimension::Affine<::newnit::dimension::#dimension> }
+        }
+        _ => quote! { ::newnit::dimension::#dimension },
+    };
+
+    // Quantities with differences of a distinct quantity can't be negative,
+    // unlike other quantities of their dimension.
+    let minimum = args.difference.as_ref().map(|_| {
         quote! {
-            impl<T: #quantity + Unit> From<&T> for #unit {
-                fn from(other: &T) -> Self {
-                    Self::from_base(other.to_base())
-                }
+            fn minimum() -> ::core::option::Option<::newnit::rational::Rational> {
+                ::core::option::Option::Some(::newnit::rational::Rational::ZERO)
             }
         }
     });
 
-    let impl_ops = args.ops.then(|| {
+    let impl_from = args.from.then(|| {
+        let quantity_params =
+            storage.params(quote! { T: #quantity<#value> + ::newnit::unit::Conversion });
         quote! {
-            impl<T: #quantity + Unit> std::ops::Add<&T> for #unit {
-                type Output = Self;
+            impl #quantity_params From<&T> for #unit #where_clause {
+                fn from(other: &T) -> Self {
+                    let other = <T as ::newnit::Unit<#value>>::to_value(other);
 
-                fn add(self, other: &T) -> Self::Output {
-                    Self::from_base(self.to_base() + other.to_base())
+                    Self(<Self as ::newnit::unit::Conversion>::convert_from::<T, #value>(other))
                 }
             }
 
-            impl<T: #quantity + Unit> std::ops::AddAssign<&T> for #unit {
-                fn add_assign(&mut self, other: &T) {
-                    self.0 = Self::from_base(self.to_base() + other.to_base()).to_value();
+            impl #params ::core::convert::TryFrom<::newnit::dyn_quantity::DynQuantity<#value>>
+                for #unit #where_clause
+            {
+                type Error = ::newnit::dyn_quantity::DimensionError;
+
+                fn try_from(
+                    quantity: ::newnit::dyn_quantity::DynQuantity<#value>,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    quantity.to_unit()
                 }
             }
 
-            impl<T: #quantity + Unit> std::ops::Div<&T> for #unit {
-                type Output = f64;
+            impl #params ::core::str::FromStr for #unit #where_clause {
+                type Err = ::newnit::parse::ParseError;
 
-                fn div(self, other: &T) -> Self::Output {
-                    self.to_base() / other.to_base()
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    ::newnit::parse::parse_unit(s)
                 }
             }
+        }
+    });
 
-            impl std::ops::Div<f64> for #unit {
-                type Output = Self;
+    let impl_cmp = args.cmp.then(|| {
+        let quantity_params =
+            storage.params(quote! { T: #quantity<#value> + ::newnit::unit::Conversion });
+        quote! {
+            impl #quantity_params ::core::cmp::PartialEq<T> for #unit #where_clause {
+                fn eq(&self, other: &T) -> bool {
+                    ::newnit::cmp::partial_cmp(self, other) == Some(::core::cmp::Ordering::Equal)
+                }
+            }
 
-                fn div(self, rhs: f64) -> Self::Output {
-                    Self(self.to_value() / rhs)
+            impl #quantity_params ::core::cmp::PartialOrd<T> for #unit #where_clause {
+                fn partial_cmp(&self, other: &T) -> Option<::core::cmp::Ordering> {
+                    ::newnit::cmp::partial_cmp(self, other)
                 }
             }
+        }
+    });
 
-            impl std::ops::DivAssign<f64> for #unit {
-                fn div_assign(&mut self, rhs: f64) {
-                    self.0 /= rhs;
+    let impl_ops = args.ops.then(|| match (&args.interval, &args.difference) {
+        // Quantities that can't be negative are added to each other or to
+        // their differences, and subtracted like points.
+        (Some(interval), Some(_)) => {
+            let operand = quote! {
+                R::Dimension: ::newnit::quantity::AffineOperand<#dimension_type, #unit, #unit>
+            };
+            let linear = linear_ops(&storage, &ast.ident, quantity, false);
+            let affine = affine_ops(&storage, &dimension_type, operand, interval);
+            quote! { #linear #affine }
+        }
+        (Some(interval), None) => {
+            let operand = quote! {
+                R: ::newnit::Quantity<#value, Dimension = ::newnit::dimension::#dimension>
+            };
+            affine_ops(&storage, &dimension_type, operand, interval)
+        }
+        (None, _) => linear_ops(&storage, &ast.ident, quantity, true),
+    });
+
+    let impl_serde = args.serde.then(|| {
+        let serialize_where_clause =
+            storage.where_clause(quote! { #value: ::newnit::serde::Serialize });
+        let deserialize_params = storage.params_with_lifetime(quote! { 'de });
+        let deserialize_where_clause =
+            storage.where_clause(quote! { #value: ::newnit::serde::Deserialize<'de> });
+
+        quote! {
+            impl #params ::newnit::serde::Serialize for #unit #serialize_where_clause {
+                fn serialize<S: ::newnit::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    ::newnit::serde::serialize(self, serializer)
                 }
             }
 
-            impl std::ops::Mul<f64> for #unit {
-                type Output = Self;
+            impl #deserialize_params ::newnit::serde::Deserialize<'de> for #unit
+                #deserialize_where_clause
+            {
+                fn deserialize<D: ::newnit::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    ::newnit::serde::deserialize(deserializer)
+                }
+            }
+        }
+    });
+
+    let impl_approx = args.approx.then(|| {
+        let abs_diff_where_clause =
+            storage.where_clause(quote! { #value: ::newnit::approx::AbsDiffEq });
+        let relative_where_clause =
+            storage.where_clause(quote! { #value: ::newnit::approx::RelativeEq });
+        let ulps_where_clause = storage.where_clause(quote! { #value: ::newnit::approx::UlpsEq });
+
+        quote! {
+            impl #params ::newnit::approx::AbsDiffEq for #unit #abs_diff_where_clause {
+                type Epsilon = <#value as ::newnit::approx::AbsDiffEq>::Epsilon;
 
-                fn mul(self, rhs: f64) -> Self::Output {
-                    Self(self.to_value() * rhs)
+                fn default_epsilon() -> Self::Epsilon {
+                    <#value as ::newnit::approx::AbsDiffEq>::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                    self.0.abs_diff_eq(&other.0, epsilon)
                 }
             }
 
-            impl std::ops::Mul<#unit> for f64 {
-                type Output = #unit;
+            impl #params ::newnit::approx::RelativeEq for #unit #relative_where_clause {
+                fn default_max_relative() -> Self::Epsilon {
+                    <#value as ::newnit::approx::RelativeEq>::default_max_relative()
+                }
 
-                fn mul(self, rhs: #unit) -> Self::Output {
-                    #unit(self * rhs.to_value())
+                fn relative_eq(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_relative: Self::Epsilon,
+                ) -> bool {
+                    self.0.relative_eq(&other.0, epsilon, max_relative)
                 }
             }
 
-            impl std::ops::MulAssign<f64> for #unit {
-                fn mul_assign(&mut self, rhs: f64) {
-                    self.0 *= rhs;
+            impl #params ::newnit::approx::UlpsEq for #unit #ulps_where_clause {
+                fn default_max_ulps() -> u32 {
+                    <#value as ::newnit::approx::UlpsEq>::default_max_ulps()
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                    self.0.ulps_eq(&other.0, epsilon, max_ulps)
                 }
             }
+        }
+    });
+
+    let impl_register = args.register.then(|| {
+        let name = &ast.ident;
+        assert!(
+            ast.generics.params.len() <= usize::from(storage.generic),
+            "Registered units can have no type parameters besides their value type."
+        );
+
+        match storage.generic {
+            true => quote! { ::newnit::register!(#name<V>: #quantity); },
+            false => quote! { ::newnit::register!(#name(#value): #quantity); },
+        }
+    });
+
+    let generated = quote! {
+        impl #params ::newnit::Quantity<#value> for #unit #where_clause {
+            type Dimension = #dimension_type;
+
+            #minimum
+        }
+
+        #impl_from
+
+        #impl_cmp
+
+        #impl_serde
+
+        #impl_approx
+
+        #impl_register
+
+        #impl_ops
+
+    };
+
+    generated.into()
+}
+
+/// Ops between units of a (linear) quantity and with their scalar type.
+///
+/// Addition and subtraction of units of the quantity are omitted unless
+/// `additive`.
+fn linear_ops(storage: &Storage, name: &Ident, quantity: &Ident, additive: bool) -> TokenStream2 {
+    let unit = &storage.unit;
+    let value = &storage.value;
+    let params = storage.params(quote! {});
+    let where_clause = storage.where_clause(quote! {});
+    let quantity_params =
+        storage.params(quote! { T: #quantity<#value> + ::newnit::unit::Conversion });
+
+    let ref_params = storage.params_with_lifetime(quote! { 'a });
+    let dyn_params = storage.params_with_lifetime(quote! { 'a, 'b });
+    let factor_params = storage.params(quote! { R: ::newnit::quantity::Factor<#unit, #value> });
+    let divisor_params = storage.params(quote! { R: ::newnit::quantity::Divisor<#unit, #value> });
 
-            impl std::ops::Neg for #unit {
+    // Negation is bounded for generic value types, and omitted for concrete
+    // unsigned ones.
+    let impl_neg = match (storage.generic, storage.is_signed()) {
+        (true, _) => {
+            Some(storage.where_clause(quote! { #value: ::core::ops::Neg<Output = #value> }))
+        }
+        (false, true) => Some(where_clause.clone()),
+        (false, false) => None,
+    }
+    .map(|neg_where_clause| {
+        quote! {
+            impl #params ::core::ops::Neg for #unit #neg_where_clause {
                 type Output = Self;
 
                 fn neg(self) -> Self::Output {
-                    Self(-self.to_value())
+                    Self(-self.0)
+                }
+            }
+        }
+    });
+
+    let impl_scalar_mul = storage.scalar_mul_impls(name);
+
+    let impl_additive = additive.then(|| {
+        quote! {
+            impl #quantity_params ::core::ops::Add<&T> for #unit #where_clause {
+                type Output = Self;
+
+                fn add(self, other: &T) -> Self::Output {
+                    let other = <T as ::newnit::Unit<#value>>::to_value(other);
+
+                    Self(self.0 + <Self as ::newnit::unit::Conversion>::convert_from::<T, #value>(other))
+                }
+            }
+
+            impl #quantity_params ::core::ops::AddAssign<&T> for #unit #where_clause {
+                fn add_assign(&mut self, other: &T) {
+                    let other = <T as ::newnit::Unit<#value>>::to_value(other);
+
+                    self.0 = self.0 + <Self as ::newnit::unit::Conversion>::convert_from::<T, #value>(other);
                 }
             }
 
-            impl<T: #quantity + Unit> std::ops::Sub<&T> for #unit {
+            impl #quantity_params ::core::ops::Sub<&T> for #unit #where_clause {
                 type Output = Self;
 
                 fn sub(self, other: &T) -> Self::Output {
-                    Self::from_base(self.to_base() - other.to_base())
+                    let other = <T as ::newnit::Unit<#value>>::to_value(other);
+
+                    Self(self.0 - <Self as ::newnit::unit::Conversion>::convert_from::<T, #value>(other))
                 }
             }
 
-            impl<T: #quantity + Unit> std::ops::SubAssign<&T> for #unit {
+            impl #quantity_params ::core::ops::SubAssign<&T> for #unit #where_clause {
                 fn sub_assign(&mut self, other: &T) {
-                    self.0 = Self::from_base(self.to_base() - other.to_base()).to_value();
+                    let other = <T as ::newnit::Unit<#value>>::to_value(other);
+
+                    self.0 = self.0 - <Self as ::newnit::unit::Conversion>::convert_from::<T, #value>(other);
                 }
             }
-
         }
     });
 
-    let generated = quote! {
-        impl #quantity for #unit {}
+    quote! {
+        #impl_additive
 
-        #impl_from
+        impl #divisor_params ::core::ops::Div<R> for #unit #where_clause {
+            type Output = R::Output;
 
-        #impl_ops
+            fn div(self, rhs: R) -> Self::Output {
+                R::divide(self, rhs)
+            }
+        }
+
+        impl #params ::core::ops::DivAssign<#value> for #unit #where_clause {
+            fn div_assign(&mut self, rhs: #value) {
+                self.0 = self.0 / rhs;
+            }
+        }
+
+        impl #factor_params ::core::ops::Mul<R> for #unit #where_clause {
+            type Output = R::Output;
+
+            fn mul(self, rhs: R) -> Self::Output {
+                R::multiply(self, rhs)
+            }
+        }
+
+        #impl_scalar_mul
+
+        impl #params ::core::ops::MulAssign<#value> for #unit #where_clause {
+            fn mul_assign(&mut self, rhs: #value) {
+                self.0 = self.0 * rhs;
+            }
+        }
+
+        #impl_neg
+
+        // Units don't implement `Product`, as the dimension of a product of
+        // units depends on the number of factors (see `Derived` instead).
+        impl #params ::core::iter::Sum for #unit #where_clause {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self(iter.fold(<#value as ::core::default::Default>::default(), |sum, unit| {
+                    sum + unit.0
+                }))
+            }
+        }
+
+        impl #ref_params ::core::iter::Sum<&'a Self> for #unit #where_clause {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self(iter.fold(<#value as ::core::default::Default>::default(), |sum, unit| {
+                    sum + unit.0
+                }))
+            }
+        }
+
+        impl #dyn_params ::core::iter::Sum<&'a (dyn #quantity<#value> + 'b)> for #unit #where_clause {
+            fn sum<I: Iterator<Item = &'a (dyn #quantity<#value> + 'b)>>(iter: I) -> Self {
+                let base = iter.fold(<#value as ::core::default::Default>::default(), |sum, unit| {
+                    sum + <(dyn #quantity<#value> + 'b) as ::newnit::Unit<#value>>::to_base(unit)
+                });
 
+                <Self as ::newnit::Unit<#value>>::from_base(base)
+            }
+        }
+    }
+}
+
+/// Ops between points of dimension `points` and their intervals.
+///
+/// Points can only be added to/ subtracted from the `operand`s bounding `R`
+/// (resulting in points), or subtracted from other points (resulting in
+/// intervals). The operands never have an offset, so they convert to the unit
+/// of the point by their factor alone.
+fn affine_ops(
+    storage: &Storage,
+    points: &TokenStream2,
+    operand: TokenStream2,
+    interval: &syn::Path,
+) -> TokenStream2 {
+    let unit = &storage.unit;
+    let value = &storage.value;
+    let operand_params =
+        storage.params(quote! { R: ::newnit::Quantity<#value> + ::newnit::unit::Conversion });
+    let operand_where_clause = storage.where_clause(operand);
+
+    // Units generic over their value type use intervals of the same value
+    // type, unless the interval type is given explicitly.
+    let interval = match interval.segments.last() {
+        Some(segment) if storage.generic && segment.arguments.is_empty() => {
+            quote! { #interval<#value> }
+        }
+        _ => quote! { #interval },
     };
 
-    generated.into()
+    let operand_trait = quote! { ::newnit::quantity::AffineOperand<#points, #unit, #interval> };
+    let difference = quote! { <R::Dimension as #operand_trait>::Difference };
+    let sub_where_clause = storage.where_clause(quote! {
+        R::Dimension: #operand_trait,
+        #difference: ::newnit::Unit<#value>,
+    });
+
+    // Operands are converted to the unit of the point directly, so that values
+    // are computed in the unit itself, without passing through base units.
+    quote! {
+        impl #operand_params ::core::ops::Add<&R> for #unit #operand_where_clause {
+            type Output = Self;
+
+            fn add(self, other: &R) -> Self::Output {
+                let other = <R as ::newnit::Unit<#value>>::to_value(other);
+
+                Self(self.0 + <Self as ::newnit::unit::Conversion>::convert_difference_from::<R, #value>(other))
+            }
+        }
+
+        impl #operand_params ::core::ops::AddAssign<&R> for #unit #operand_where_clause {
+            fn add_assign(&mut self, other: &R) {
+                let other = <R as ::newnit::Unit<#value>>::to_value(other);
+
+                self.0 = self.0 + <Self as ::newnit::unit::Conversion>::convert_difference_from::<R, #value>(other);
+            }
+        }
+
+        impl #operand_params ::core::ops::Sub<&R> for #unit #sub_where_clause {
+            type Output = #difference;
+
+            fn sub(self, other: &R) -> Self::Output {
+                let other = <R as ::newnit::Unit<#value>>::to_value(other);
+                let difference =
+                    <R::Dimension as #operand_trait>::difference::<R, #value>(self.0, other);
+
+                <Self::Output as ::newnit::Unit<#value>>::from_value(difference)
+            }
+        }
+
+        impl #operand_params ::core::ops::SubAssign<&R> for #unit #operand_where_clause {
+            fn sub_assign(&mut self, other: &R) {
+                let other = <R as ::newnit::Unit<#value>>::to_value(other);
+
+                self.0 = self.0 - <Self as ::newnit::unit::Conversion>::convert_difference_from::<R, #value>(other);
+            }
+        }
+    }
 }
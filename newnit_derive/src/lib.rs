@@ -1,46 +1,108 @@
 //! A derive macro package for deriving the `Unit` trait and quantity traits
 //! defined in the [`newnit`] library.
+//!
+//! # Quantity traits
+//!
+//! The quantity derive macros don't implement their quantity trait (e.g.
+//! `Length`) directly. Each implements `newnit::Quantity` for the unit with a
+//! dimension of `newnit::dimension` (e.g. `dimension::Length`), and the
+//! quantity trait is in turn implemented for every unit of that dimension. A
+//! unit therefore has the quantity trait of its dimension whichever way it was
+//! derived, and units of products and quotients of other units (e.g. `Derived`
+//! units of `Meter / Second`) have quantity traits too.
 
 use proc_macro::TokenStream;
 
 mod impl_quantity;
+mod storage;
 
+mod angle;
 mod area;
+mod capacitance;
+mod charge;
+mod conductance;
 mod current;
+mod energy;
+mod force;
+mod gauge_pressure;
+mod illuminance;
+mod inductance;
 mod length;
+mod luminance;
+mod luminous_energy;
+mod luminous_flux;
 mod luminous_intensity;
+mod magnetic_flux;
+mod magnetic_flux_density;
 mod mass;
+mod power;
+mod pressure;
+mod pressure_difference;
+mod resistance;
+mod solid_angle;
 mod substance_amount;
 mod temperature;
+mod temperature_interval;
 mod time;
 mod unit;
 mod velocity;
+mod voltage;
 mod volume;
 
 /// Derive Macro for the `Unit` trait.
 ///
-/// Use this macro with any tuple `struct` that has a [`f64`] as its first
-/// field.
+/// Use this macro with any tuple `struct` whose first field stores its value,
+/// either as a concrete scalar type (e.g. [`f64`], [`i32`]) or as a type
+/// parameter of the struct (e.g. `struct Inch<V = f64>(V)`), which is then
+/// bounded by `newnit::scalar::Scalar`. Besides `Unit`, the macro implements
+/// `newnit::unit::Conversion` with the given factor and offset.
 ///
 /// This macro declares additional parameters in its `#[unit()]` macro
 /// attribute:
-/// - factor: [`f64`] the factor for conversion to base unit
-/// - offset: [`f64`] (optional) the offset for conversion to base unit
+/// - factor: the factor for conversion to base unit
+/// - offset: (optional) the offset for conversion to base unit
+/// - of: (optional) a unit to convert to instead of the base unit
+/// - symbol: (optional) the symbol of the unit (e.g. `"in"`)
+/// - name: (optional) the singular name of the unit (e.g. `"inch"`)
+/// - plural: (optional) the plural name of the unit (e.g. `"inches"`), defaults
+///   to the name, like `newnit::Unit::plural`
+/// - system: (optional) the `newnit::unit::System` of the unit (e.g.
+///   `Imperial`), defaults to `Other`
+/// - alias: (optional, repeatable) an alternative symbol or name of the unit
+///   (e.g. `"L"`), used to parse values of the unit and to find it in the
+///   registry
 /// - display: [`bool`] whether to additionally derive the
-///   [`Display`](std::fmt::Display) trait.
+///   [`Display`](core::fmt::Display) trait, printing the symbol (e.g. `3 in`)
+///   or, in the alternate form `{:#}`, the name (e.g. `3 inches`).
+///
+/// The symbol, names, system and aliases implement `newnit::unit::Metadata`
+/// and the metadata methods of `Unit`. The symbol and names default to the name
+/// of the struct.
+///
+/// The factor and offset are exact rationals, given as decimal literals,
+/// `newnit::rational::Rational` constants or arithmetic expressions of them
+/// (e.g. `0.3048`, `1E-3`, `5 / 9`, `Rational::PI / 180`).
 ///
 /// The equation for conversion to base unit is defined as follows:
 ///   value_in_base_unit = value_in_this_unit * `factor` + `offset`
 ///
+/// or, if a unit is given in `of`:
+///   value_in_unit_of = value_in_this_unit * `factor` + `offset`
+///
 /// # Examples:
 /// ```ignore
 /// use newnit::Unit;
 /// use newnit_derive::Unit;
 ///
 /// #[derive(Unit)]
-/// #[unit(factor = 0.0254)] // 1 inch is 0.0254 meters
+/// #[unit(factor = 0.0254, symbol = "in")] // 1 inch is 0.0254 meters
+/// #[unit(name = "inch", plural = "inches", system = Imperial, display)]
 /// struct Inch(f64);
 ///
+/// #[derive(Unit)]
+/// #[unit(factor = 1 / 12, of = Inch)] // 1 line is 1/12 inch
+/// struct Line(f64);
+///
 /// let length = Inch(42.0);
 /// assert_eq!(length.to_base(), 42.0 * 0.0254);
 /// ```
@@ -52,23 +114,37 @@ pub fn unit_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `Length` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Length` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Length` and [`f64`],
-///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Length`
-///   - [`std::ops::AddAssign`] with another `Length`
-///   - [`std::ops::Div`] with another `Length`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Length`
-///   - [`std::ops::SubAssign`] with another `Length`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"3.5 km"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Length` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Length`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Length` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Length`
+///   - [`core::ops::AddAssign`] with another `Length`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Length`
+///   - [`core::ops::SubAssign`] with another `Length`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Length`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -77,7 +153,7 @@ pub fn unit_derive(input: TokenStream) -> TokenStream {
 /// use newnit_derive::{Length, Unit};
 ///
 /// #[derive(Unit, Length)]
-/// #[unit(factor = 0.0254)] // 1 inch is 0.0254 meters
+/// #[unit(factor = 0.0254, symbol = "in")] // 1 inch is 0.0254 meters
 /// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
 /// struct Inch(f64);
 ///
@@ -90,25 +166,93 @@ pub fn length_derive(input: TokenStream) -> TokenStream {
     length::derive(&ast)
 }
 
-/// Derive macro for the `Area` trait.
+/// Derive macro for the `Angle` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Angle` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Area` and [`f64`],
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"90°"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Angle` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Angle` trait
+///   in scope)
+/// - select [`core::ops`] operations with other units of `Angle` and [`f64`],
 ///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Area`
-///   - [`std::ops::AddAssign`] with another `Area`
-///   - [`std::ops::Div`] with another `Area`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Area`
-///   - [`std::ops::SubAssign`] with another `Area`
+///   - [`core::ops::Add`] with another `Angle`
+///   - [`core::ops::AddAssign`] with another `Angle`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Angle`
+///   - [`core::ops::SubAssign`] with another `Angle`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Angle`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{angle::Angle, Unit};
+/// use newnit_derive::{Angle, Unit};
+///
+/// #[derive(Unit, Angle)]
+/// #[unit(factor = 1E-3)] // 1 mrad is 0.001 rad
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MilliRadian(f64);
+///
+/// let angle = MilliRadian(42.0);
+/// assert_eq!(angle.to_base(), 42.0 * 1E-3);
+/// ```
+#[proc_macro_derive(Angle, attributes(quantity))]
+pub fn angle_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    angle::derive(&ast)
+}
+
+/// Derive macro for the `Area` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Area` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 ha"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Area` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Area` trait
+///   in scope)
+/// - select [`core::ops`] operations with other units of `Area` and the unit's
+///   scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Area`
+///   - [`core::ops::AddAssign`] with another `Area`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Area`
+///   - [`core::ops::SubAssign`] with another `Area`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Area`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -132,23 +276,37 @@ pub fn area_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `Volume` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Volume` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Volume` and [`f64`],
-///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Volume`
-///   - [`std::ops::AddAssign`] with another `Volume`
-///   - [`std::ops::Div`] with another `Volume`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Volume`
-///   - [`std::ops::SubAssign`] with another `Volume`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"1.5 dm³"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Volume` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Volume`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Volume` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Volume`
+///   - [`core::ops::AddAssign`] with another `Volume`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Volume`
+///   - [`core::ops::SubAssign`] with another `Volume`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Volume`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -172,23 +330,37 @@ pub fn volume_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `Mass` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Mass` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Mass` and [`f64`],
-///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Mass`
-///   - [`std::ops::AddAssign`] with another `Mass`
-///   - [`std::ops::Div`] with another `Mass`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Mass`
-///   - [`std::ops::SubAssign`] with another `Mass`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 lb"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Mass` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Mass` trait
+///   in scope)
+/// - select [`core::ops`] operations with other units of `Mass` and the unit's
+///   scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Mass`
+///   - [`core::ops::AddAssign`] with another `Mass`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Mass`
+///   - [`core::ops::SubAssign`] with another `Mass`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Mass`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -212,23 +384,37 @@ pub fn mass_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `Velocity` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Velocity` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Velocity` and [`f64`],
-///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Velocity`
-///   - [`std::ops::AddAssign`] with another `Velocity`
-///   - [`std::ops::Div`] with another `Velocity`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Velocity`
-///   - [`std::ops::SubAssign`] with another `Velocity`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"60 km/h"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Velocity` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Velocity`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Velocity` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Velocity`
+///   - [`core::ops::AddAssign`] with another `Velocity`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Velocity`
+///   - [`core::ops::SubAssign`] with another `Velocity`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Velocity`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -250,25 +436,801 @@ pub fn velocity_derive(input: TokenStream) -> TokenStream {
     velocity::derive(&ast)
 }
 
+/// Derive macro for the `Force` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Force` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"9.8 N"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Force` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Force` trait
+///   in scope)
+/// - select [`core::ops`] operations with other units of `Force` and the unit's
+///   scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Force`
+///   - [`core::ops::AddAssign`] with another `Force`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Force`
+///   - [`core::ops::SubAssign`] with another `Force`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Force`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{force::Force, Unit};
+/// use newnit_derive::{Force, Unit};
+///
+/// #[derive(Unit, Force)]
+/// #[unit(factor = 4.448_221_615_260_5)] // 1 lbf is 4.4482216152605 N
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct PoundForce(f64);
+///
+/// let force = PoundForce(42.0);
+/// assert_eq!(force.to_base(), 42.0 * 4.448_221_615_260_5);
+/// ```
+#[proc_macro_derive(Force, attributes(quantity))]
+pub fn force_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    force::derive(&ast)
+}
+
+/// Derive macro for the `Energy` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Energy` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"1.5 kWh"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Energy` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Energy`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Energy` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Energy`
+///   - [`core::ops::AddAssign`] with another `Energy`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Energy`
+///   - [`core::ops::SubAssign`] with another `Energy`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Energy`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{energy::Energy, Unit};
+/// use newnit_derive::{Energy, Unit};
+///
+/// #[derive(Unit, Energy)]
+/// #[unit(factor = 4.184)] // 1 cal is 4.184 J
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct Calorie(f64);
+///
+/// let energy = Calorie(42.0);
+/// assert_eq!(energy.to_base(), 42.0 * 4.184);
+/// ```
+#[proc_macro_derive(Energy, attributes(quantity))]
+pub fn energy_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    energy::derive(&ast)
+}
+
+/// Derive macro for the `Power` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Power` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"100 W"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Power` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Power` trait
+///   in scope)
+/// - select [`core::ops`] operations with other units of `Power` and the unit's
+///   scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Power`
+///   - [`core::ops::AddAssign`] with another `Power`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Power`
+///   - [`core::ops::SubAssign`] with another `Power`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Power`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{power::Power, Unit};
+/// use newnit_derive::{Power, Unit};
+///
+/// #[derive(Unit, Power)]
+/// #[unit(factor = 745.699_871_582_270_2)] // 1 hp is 745.6998715822702 W
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct HorsePower(f64);
+///
+/// let power = HorsePower(42.0);
+/// assert_eq!(power.to_base(), 42.0 * 745.699_871_582_270_2);
+/// ```
+#[proc_macro_derive(Power, attributes(quantity))]
+pub fn power_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    power::derive(&ast)
+}
+
+/// Derive macro for the `Pressure` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Pressure` [dimension](crate#quantity-traits).
+///
+/// Absolute pressures can't be negative, their differences are measured by
+/// units of `PressureDifference`. The interval unit of the derived unit is set
+/// by the `interval` parameter of the `#[quantity()]` macro attribute and
+/// defaults to `PascalDifferential`.
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"101.325 kPa"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Pressure` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Pressure`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Pressure`,
+///   `PressureDifference` and the unit's scalar type, where such operations
+///   make sense:
+///   - [`core::ops::Add`] with another `Pressure` or a `PressureDifference`
+///   - [`core::ops::AddAssign`] with another `Pressure` or a
+///     `PressureDifference`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Pressure`, resulting in the interval
+///     unit
+///   - [`core::ops::Sub`] with a `PressureDifference`
+///   - [`core::ops::SubAssign`] with another `Pressure` or a
+///     `PressureDifference`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Pressure`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{pressure::Pressure, Unit};
+/// use newnit_derive::{Pressure, Unit};
+///
+/// #[derive(Unit, Pressure)]
+/// #[unit(factor = 1E+5)] // 1 bar is 100 000 Pa
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct Bar(f64);
+///
+/// let pressure = Bar(42.0);
+/// assert_eq!(pressure.to_base(), 42.0 * 1E+5);
+/// ```
+#[proc_macro_derive(Pressure, attributes(quantity))]
+pub fn pressure_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    pressure::derive(&ast)
+}
+
+/// Derive macro for the `GaugePressure` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the affine `PressureDifference` [dimension](crate#quantity-traits).
+///
+/// Gauge pressures are points on a scale relative to the atmospheric pressure,
+/// their differences are measured by units of `PressureDifference`. The
+/// interval unit of the derived unit is set by the `interval` parameter of the
+/// `#[quantity()]` macro attribute and defaults to `PascalDifferential`.
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 barg"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `GaugePressure`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `GaugePressure` trait in scope)
+/// - select [`core::ops`] operations with other units of `GaugePressure` and
+///   `PressureDifference`, where such operations make sense:
+///   - [`core::ops::Add`] with a `PressureDifference`
+///   - [`core::ops::AddAssign`] with a `PressureDifference`
+///   - [`core::ops::Sub`] with another `GaugePressure`, resulting in the
+///     interval unit
+///   - [`core::ops::Sub`] with a `PressureDifference`
+///   - [`core::ops::SubAssign`] with a `PressureDifference`
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{pressure::GaugePressure, Unit};
+/// use newnit_derive::{GaugePressure, Unit};
+///
+/// #[derive(Unit, GaugePressure)]
+/// #[unit(factor = 1E+5)] // 1 barg is 100 000 Pa above the atmospheric pressure
+/// #[quantity(ops, interval = BarDifferential)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct BarGauge(f64);
+///
+/// let pressure = BarGauge(4.2);
+/// assert_eq!(pressure.to_base(), 4.2 * 1E+5);
+/// ```
+#[proc_macro_derive(GaugePressure, attributes(quantity))]
+pub fn gauge_pressure_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    gauge_pressure::derive(&ast)
+}
+
+/// Derive macro for the `PressureDifference` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `PressureDifference` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"5 kPa(d)"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `PressureDifference`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `PressureDifference` trait in scope)
+/// - select [`core::ops`] operations with other units of `PressureDifference`
+///   and the unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `PressureDifference`
+///   - [`core::ops::AddAssign`] with another `PressureDifference`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `PressureDifference`
+///   - [`core::ops::SubAssign`] with another `PressureDifference`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn PressureDifference`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{pressure::PressureDifference, Unit};
+/// use newnit_derive::{PressureDifference, Unit};
+///
+/// #[derive(Unit, PressureDifference)]
+/// #[unit(factor = 1E+5)] // a difference of 1 bar is 100 000 Pa
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct BarDifferential(f64);
+///
+/// let difference = BarDifferential(0.5);
+/// assert_eq!(difference.to_base(), 0.5 * 1E+5);
+/// ```
+#[proc_macro_derive(PressureDifference, attributes(quantity))]
+pub fn pressure_difference_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    pressure_difference::derive(&ast)
+}
+
+/// Derive macro for the `Charge` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Charge` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 mAh"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Charge` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Charge`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Charge` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Charge`
+///   - [`core::ops::AddAssign`] with another `Charge`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Charge`
+///   - [`core::ops::SubAssign`] with another `Charge`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Charge`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{charge::Charge, Unit};
+/// use newnit_derive::{Charge, Unit};
+///
+/// #[derive(Unit, Charge)]
+/// #[unit(factor = 3600.0)] // 1 Ah is 3600 C
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct AmpereHour(f64);
+///
+/// let charge = AmpereHour(42.0);
+/// assert_eq!(charge.to_base(), 42.0 * 3600.0);
+/// ```
+#[proc_macro_derive(Charge, attributes(quantity))]
+pub fn charge_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    charge::derive(&ast)
+}
+
+/// Derive macro for the `Voltage` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Voltage` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"230 V"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Voltage` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Voltage`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Voltage` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Voltage`
+///   - [`core::ops::AddAssign`] with another `Voltage`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Voltage`
+///   - [`core::ops::SubAssign`] with another `Voltage`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Voltage`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{voltage::Voltage, Unit};
+/// use newnit_derive::{Voltage, Unit};
+///
+/// #[derive(Unit, Voltage)]
+/// #[unit(factor = 1E+3)] // 1 kV is 1000 V
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct KiloVolt(f64);
+///
+/// let voltage = KiloVolt(42.0);
+/// assert_eq!(voltage.to_base(), 42.0 * 1E+3);
+/// ```
+#[proc_macro_derive(Voltage, attributes(quantity))]
+pub fn voltage_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    voltage::derive(&ast)
+}
+
+/// Derive macro for the `Resistance` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Resistance` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"4.7 kΩ"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Resistance` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Resistance`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Resistance` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Resistance`
+///   - [`core::ops::AddAssign`] with another `Resistance`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Resistance`
+///   - [`core::ops::SubAssign`] with another `Resistance`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Resistance`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{resistance::Resistance, Unit};
+/// use newnit_derive::{Resistance, Unit};
+///
+/// #[derive(Unit, Resistance)]
+/// #[unit(factor = 1E+6)] // 1 MΩ is 1 000 000 Ω
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MegaOhm(f64);
+///
+/// let resistance = MegaOhm(42.0);
+/// assert_eq!(resistance.to_base(), 42.0 * 1E+6);
+/// ```
+#[proc_macro_derive(Resistance, attributes(quantity))]
+pub fn resistance_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    resistance::derive(&ast)
+}
+
+/// Derive macro for the `Conductance` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Conductance` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 mS"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Conductance`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Conductance`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Conductance` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Conductance`
+///   - [`core::ops::AddAssign`] with another `Conductance`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Conductance`
+///   - [`core::ops::SubAssign`] with another `Conductance`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Conductance`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{conductance::Conductance, Unit};
+/// use newnit_derive::{Conductance, Unit};
+///
+/// #[derive(Unit, Conductance)]
+/// #[unit(factor = 1E-3)] // 1 mS is 0.001 S
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MilliSiemens(f64);
+///
+/// let conductance = MilliSiemens(42.0);
+/// assert_eq!(conductance.to_base(), 42.0 * 1E-3);
+/// ```
+#[proc_macro_derive(Conductance, attributes(quantity))]
+pub fn conductance_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    conductance::derive(&ast)
+}
+
+/// Derive macro for the `Capacitance` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Capacitance` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"100 µF"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Capacitance`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Capacitance`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Capacitance` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Capacitance`
+///   - [`core::ops::AddAssign`] with another `Capacitance`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Capacitance`
+///   - [`core::ops::SubAssign`] with another `Capacitance`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Capacitance`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{capacitance::Capacitance, Unit};
+/// use newnit_derive::{Capacitance, Unit};
+///
+/// #[derive(Unit, Capacitance)]
+/// #[unit(factor = 1E-6)] // 1 µF is 0.000 001 F
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MicroFarad(f64);
+///
+/// let capacitance = MicroFarad(42.0);
+/// assert_eq!(capacitance.to_base(), 42.0 * 1E-6);
+/// ```
+#[proc_macro_derive(Capacitance, attributes(quantity))]
+pub fn capacitance_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    capacitance::derive(&ast)
+}
+
+/// Derive macro for the `Inductance` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Inductance` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"10 mH"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Inductance` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Inductance`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Inductance` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Inductance`
+///   - [`core::ops::AddAssign`] with another `Inductance`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Inductance`
+///   - [`core::ops::SubAssign`] with another `Inductance`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Inductance`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{inductance::Inductance, Unit};
+/// use newnit_derive::{Inductance, Unit};
+///
+/// #[derive(Unit, Inductance)]
+/// #[unit(factor = 1E-3)] // 1 mH is 0.001 H
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MilliHenry(f64);
+///
+/// let inductance = MilliHenry(42.0);
+/// assert_eq!(inductance.to_base(), 42.0 * 1E-3);
+/// ```
+#[proc_macro_derive(Inductance, attributes(quantity))]
+pub fn inductance_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    inductance::derive(&ast)
+}
+
+/// Derive macro for the `MagneticFlux` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `MagneticFlux` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 Wb"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `MagneticFlux`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `MagneticFlux` trait in scope)
+/// - select [`core::ops`] operations with other units of `MagneticFlux` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `MagneticFlux`
+///   - [`core::ops::AddAssign`] with another `MagneticFlux`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `MagneticFlux`
+///   - [`core::ops::SubAssign`] with another `MagneticFlux`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn MagneticFlux`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{magnetic_flux::MagneticFlux, Unit};
+/// use newnit_derive::{MagneticFlux, Unit};
+///
+/// #[derive(Unit, MagneticFlux)]
+/// #[unit(factor = 1E-8)] // 1 Mx is 1E-8 Wb
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct Maxwell(f64);
+///
+/// let magnetic_flux = Maxwell(42.0);
+/// assert_eq!(magnetic_flux.to_base(), 42.0 * 1E-8);
+/// ```
+#[proc_macro_derive(MagneticFlux, attributes(quantity))]
+pub fn magnetic_flux_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    magnetic_flux::derive(&ast)
+}
+
+/// Derive macro for the `MagneticFluxDensity` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `MagneticFluxDensity` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"1.5 T"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `MagneticFluxDensity`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `MagneticFluxDensity` trait in scope)
+/// - select [`core::ops`] operations with other units of `MagneticFluxDensity`
+///   and the unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `MagneticFluxDensity`
+///   - [`core::ops::AddAssign`] with another `MagneticFluxDensity`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `MagneticFluxDensity`
+///   - [`core::ops::SubAssign`] with another `MagneticFluxDensity`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn MagneticFluxDensity`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{magnetic_flux_density::MagneticFluxDensity, Unit};
+/// use newnit_derive::{MagneticFluxDensity, Unit};
+///
+/// #[derive(Unit, MagneticFluxDensity)]
+/// #[unit(factor = 1E-4)] // 1 G is 1E-4 T
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct Gauss(f64);
+///
+/// let magnetic_flux_density = Gauss(42.0);
+/// assert_eq!(magnetic_flux_density.to_base(), 42.0 * 1E-4);
+/// ```
+#[proc_macro_derive(MagneticFluxDensity, attributes(quantity))]
+pub fn magnetic_flux_density_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    magnetic_flux_density::derive(&ast)
+}
+
 /// Derive macro for the `Time` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Time` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Time` and [`f64`],
-///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Time`
-///   - [`std::ops::AddAssign`] with another `Time`
-///   - [`std::ops::Div`] with another `Time`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Time`
-///   - [`std::ops::SubAssign`] with another `Time`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"1.5 h"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Time` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Time` trait
+///   in scope)
+/// - select [`core::ops`] operations with other units of `Time` and the unit's
+///   scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Time`
+///   - [`core::ops::AddAssign`] with another `Time`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Time`
+///   - [`core::ops::SubAssign`] with another `Time`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Time`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -292,23 +1254,37 @@ pub fn time_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `Current` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Current` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Current` and [`f64`],
-///   where such operations make sense:
-///   - [`std::ops::Add`] with another `Current`
-///   - [`std::ops::AddAssign`] with another `Current`
-///   - [`std::ops::Div`] with another `Current`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Current`
-///   - [`std::ops::SubAssign`] with another `Current`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 mA"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Current` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Current`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Current` and the
+///   unit's scalar type, where such operations make sense:
+///   - [`core::ops::Add`] with another `Current`
+///   - [`core::ops::AddAssign`] with another `Current`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Current`
+///   - [`core::ops::SubAssign`] with another `Current`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Current`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -332,23 +1308,37 @@ pub fn current_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `LuminousIntensity` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `LuminousIntensity` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `LuminousIntensity` and
-///   [`f64`], where such operations make sense:
-///   - [`std::ops::Add`] with another `LuminousIntensity`
-///   - [`std::ops::AddAssign`] with another `LuminousIntensity`
-///   - [`std::ops::Div`] with another `LuminousIntensity`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `LuminousIntensity`
-///   - [`std::ops::SubAssign`] with another `LuminousIntensity`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"100 cd"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `LuminousIntensity`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `LuminousIntensity` trait in scope)
+/// - select [`core::ops`] operations with other units of `LuminousIntensity`
+///   and [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `LuminousIntensity`
+///   - [`core::ops::AddAssign`] with another `LuminousIntensity`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `LuminousIntensity`
+///   - [`core::ops::SubAssign`] with another `LuminousIntensity`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn LuminousIntensity`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -370,25 +1360,309 @@ pub fn luminous_intensity_derive(input: TokenStream) -> TokenStream {
     luminous_intensity::derive(&ast)
 }
 
+/// Derive macro for the `LuminousFlux` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `LuminousFlux` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"800 lm"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `LuminousFlux`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `LuminousFlux` trait in scope)
+/// - select [`core::ops`] operations with other units of `LuminousFlux` and
+///   [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `LuminousFlux`
+///   - [`core::ops::AddAssign`] with another `LuminousFlux`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `LuminousFlux`
+///   - [`core::ops::SubAssign`] with another `LuminousFlux`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn LuminousFlux`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{luminous_flux::LuminousFlux, Unit};
+/// use newnit_derive::{LuminousFlux, Unit};
+///
+/// #[derive(Unit, LuminousFlux)]
+/// #[unit(factor = 1E-3)] // 1 mlm is 0.001 lm
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MilliLumen(f64);
+///
+/// let luminous_flux = MilliLumen(42.0);
+/// assert_eq!(luminous_flux.to_base(), 42.0 * 1E-3);
+/// ```
+#[proc_macro_derive(LuminousFlux, attributes(quantity))]
+pub fn luminous_flux_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    luminous_flux::derive(&ast)
+}
+
+/// Derive macro for the `Illuminance` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Illuminance` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"300 lx"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Illuminance`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Illuminance`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Illuminance` and
+///   [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `Illuminance`
+///   - [`core::ops::AddAssign`] with another `Illuminance`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Illuminance`
+///   - [`core::ops::SubAssign`] with another `Illuminance`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Illuminance`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{illuminance::Illuminance, Unit};
+/// use newnit_derive::{Illuminance, Unit};
+///
+/// #[derive(Unit, Illuminance)]
+/// #[unit(factor = 1E+3)] // 1 klx is 1000 lx
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct KiloLux(f64);
+///
+/// let illuminance = KiloLux(42.0);
+/// assert_eq!(illuminance.to_base(), 42.0 * 1E+3);
+/// ```
+#[proc_macro_derive(Illuminance, attributes(quantity))]
+pub fn illuminance_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    illuminance::derive(&ast)
+}
+
+/// Derive macro for the `Luminance` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Luminance` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"100 nt"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Luminance` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Luminance`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Luminance` and
+///   [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `Luminance`
+///   - [`core::ops::AddAssign`] with another `Luminance`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `Luminance`
+///   - [`core::ops::SubAssign`] with another `Luminance`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn Luminance`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{luminance::Luminance, Unit};
+/// use newnit_derive::{Luminance, Unit};
+///
+/// #[derive(Unit, Luminance)]
+/// #[unit(factor = 1E+4)] // 1 sb is 10000 cd/m²
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct Stilb(f64);
+///
+/// let luminance = Stilb(42.0);
+/// assert_eq!(luminance.to_base(), 42.0 * 1E+4);
+/// ```
+#[proc_macro_derive(Luminance, attributes(quantity))]
+pub fn luminance_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    luminance::derive(&ast)
+}
+
+/// Derive macro for the `LuminousEnergy` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `LuminousEnergy` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"1 lm·h"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `LuminousEnergy`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `LuminousEnergy` trait in scope)
+/// - select [`core::ops`] operations with other units of `LuminousEnergy` and
+///   [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `LuminousEnergy`
+///   - [`core::ops::AddAssign`] with another `LuminousEnergy`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `LuminousEnergy`
+///   - [`core::ops::SubAssign`] with another `LuminousEnergy`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn LuminousEnergy`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{luminous_energy::LuminousEnergy, Unit};
+/// use newnit_derive::{LuminousEnergy, Unit};
+///
+/// #[derive(Unit, LuminousEnergy)]
+/// #[unit(factor = 60.0)] // 1 lm·min is 60 lm·s
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct LumenMinute(f64);
+///
+/// let luminous_energy = LumenMinute(42.0);
+/// assert_eq!(luminous_energy.to_base(), 42.0 * 60.0);
+/// ```
+#[proc_macro_derive(LuminousEnergy, attributes(quantity))]
+pub fn luminous_energy_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    luminous_energy::derive(&ast)
+}
+
+/// Derive macro for the `SolidAngle` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `SolidAngle` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 sr"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `SolidAngle` (`cmp`),
+///   compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `SolidAngle`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `SolidAngle` and
+///   [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `SolidAngle`
+///   - [`core::ops::AddAssign`] with another `SolidAngle`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `SolidAngle`
+///   - [`core::ops::SubAssign`] with another `SolidAngle`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn SolidAngle`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{solid_angle::SolidAngle, Unit};
+/// use newnit_derive::{SolidAngle, Unit};
+///
+/// #[derive(Unit, SolidAngle)]
+/// #[unit(factor = 1E-3)] // 1 msr is 0.001 sr
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct MilliSteradian(f64);
+///
+/// let solid_angle = MilliSteradian(42.0);
+/// assert_eq!(solid_angle.to_base(), 42.0 * 1E-3);
+/// ```
+#[proc_macro_derive(SolidAngle, attributes(quantity))]
+pub fn solid_angle_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    solid_angle::derive(&ast)
+}
+
 /// Derive macro for the `SubstanceAmount` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `SubstanceAmount` [dimension](crate#quantity-traits).
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `SubstanceAmount` and
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"2 mol"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `SubstanceAmount`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `SubstanceAmount` trait in scope)
+/// - select [`core::ops`] operations with other units of `SubstanceAmount` and
 ///   [`f64`], where such operations make sense:
-///   - [`std::ops::Add`] with another `SubstanceAmount`
-///   - [`std::ops::AddAssign`] with another `SubstanceAmount`
-///   - [`std::ops::Div`] with another `SubstanceAmount`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `SubstanceAmount`
-///   - [`std::ops::SubAssign`] with another `SubstanceAmount`
+///   - [`core::ops::Add`] with another `SubstanceAmount`
+///   - [`core::ops::AddAssign`] with another `SubstanceAmount`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `SubstanceAmount`
+///   - [`core::ops::SubAssign`] with another `SubstanceAmount`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn SubstanceAmount`)
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -412,23 +1686,34 @@ pub fn substance_amount_derive(input: TokenStream) -> TokenStream {
 
 /// Derive macro for the `Temperature` trait.
 ///
-/// This trait requires `Unit` as a supertrait.
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the affine `Temperature` [dimension](crate#quantity-traits).
+///
+/// Temperatures are points on a scale, their differences are measured by
+/// units of `TemperatureInterval`. The interval unit of the derived unit is
+/// set by the `interval` parameter of the `#[quantity()]` macro attribute and
+/// defaults to `DeltaKelvin`. The same parameter makes a unit of any other
+/// quantity measure points on an affine scale of that quantity.
 ///
 /// You can opt in for additional implementations of one or more of:
-/// - [`From`]
-/// - [`PartialEq`]
-/// - select [`std::ops`] operations with other units of `Temperature` and
-///   [`f64`], where such operations make sense:
-///   - [`std::ops::Add`] with another `Temperature`
-///   - [`std::ops::AddAssign`] with another `Temperature`
-///   - [`std::ops::Div`] with another `Temperature`
-///   - [`std::ops::Div`] with [`f64`]
-///   - [`std::ops::DivAssign`] with [`f64`]
-///   - [`std::ops::Mul`] with [`f64`]
-///   - [`std::ops::MulAssign`] with [`f64`]
-///   - [`std::ops::Neg`]
-///   - [`std::ops::Sub`] with another `Temperature`
-///   - [`std::ops::SubAssign`] with another `Temperature`
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"21.5 °C"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `Temperature`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the `Temperature`
+///   trait in scope)
+/// - select [`core::ops`] operations with other units of `Temperature` and
+///   `TemperatureInterval`, where such operations make sense:
+///   - [`core::ops::Add`] with a `TemperatureInterval`
+///   - [`core::ops::AddAssign`] with a `TemperatureInterval`
+///   - [`core::ops::Sub`] with another `Temperature`, resulting in the interval
+///     unit
+///   - [`core::ops::Sub`] with a `TemperatureInterval`
+///   - [`core::ops::SubAssign`] with a `TemperatureInterval`
 ///
 /// by specifying them in an (optional) `#[quantity()]` macro attribute.
 /// /// # Examples:
@@ -438,7 +1723,7 @@ pub fn substance_amount_derive(input: TokenStream) -> TokenStream {
 ///
 /// #[derive(Unit, Temperature)]
 /// #[unit(factor = 0.556, offset = 255.372)] // 1˚F is around 0.556 + 255.372 K
-/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// #[quantity(ops, interval = DeltaFahrenheit)]  // opt in to provided Ops implementations, but not to the From implementation
 /// struct Fahrenheit(f64);
 ///
 /// let temperature = Fahrenheit(42.0);
@@ -449,3 +1734,57 @@ pub fn temperature_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).expect("Failed to parse input code.");
     temperature::derive(&ast)
 }
+
+/// Derive macro for the `TemperatureInterval` trait.
+///
+/// This trait requires `Unit` as a supertrait. The macro implements `Quantity`
+/// with the `Temperature` [dimension](crate#quantity-traits).
+///
+/// You can opt in for additional implementations of one or more of:
+/// - [`From`] other units of the quantity, along with [`TryFrom`] a
+///   `DynQuantity` and [`FromStr`](core::str::FromStr) (e.g. `"5 Δ°C"`)
+/// - [`PartialEq`] and [`PartialOrd`] with other units of `TemperatureInterval`
+///   (`cmp`), compared in base units
+/// - serde's `Serialize` and `Deserialize` as the value with the symbol of the
+///   unit (`serde`, requires the `serde` feature of `newnit`)
+/// - the approx crate's `AbsDiffEq`, `RelativeEq` and `UlpsEq` comparing the
+///   values (`approx`, requires the `approx` feature of `newnit`)
+/// - registration in `newnit::registry` (`register`, requires the
+///   `TemperatureInterval` trait in scope)
+/// - select [`core::ops`] operations with other units of `TemperatureInterval`
+///   and [`f64`], where such operations make sense:
+///   - [`core::ops::Add`] with another `TemperatureInterval`
+///   - [`core::ops::AddAssign`] with another `TemperatureInterval`
+///   - [`core::ops::Div`] with any other unit, resulting in a `Derived` unit of
+///     the quotient dimension
+///   - [`core::ops::Div`] with the scalar type
+///   - [`core::ops::DivAssign`] with the scalar type
+///   - [`core::ops::Mul`] with any other unit, resulting in a `Derived` unit of
+///     the product dimension
+///   - [`core::ops::Mul`] with the scalar type
+///   - [`core::ops::MulAssign`] with the scalar type
+///   - [`core::ops::Neg`]
+///   - [`core::ops::Sub`] with another `TemperatureInterval`
+///   - [`core::ops::SubAssign`] with another `TemperatureInterval`
+///   - [`core::iter::Sum`] of the unit, references to it and trait objects
+///     (`&dyn TemperatureInterval`)
+///
+/// by specifying them in an (optional) `#[quantity()]` macro attribute.
+/// /// # Examples:
+/// ```ignore
+/// use newnit::{temperature::TemperatureInterval, Unit};
+/// use newnit_derive::{TemperatureInterval, Unit};
+///
+/// #[derive(Unit, TemperatureInterval)]
+/// #[unit(factor = 0.556)] // a difference of 1˚F is around 0.556 K
+/// #[quantity(ops)]  // opt in to provided Ops implementations, but not to the From implementation
+/// struct DeltaFahrenheit(f64);
+///
+/// let difference = DeltaFahrenheit(42.0);
+/// assert_eq!(difference.to_base(), 42.0 * 0.556);
+/// ```
+#[proc_macro_derive(TemperatureInterval, attributes(quantity))]
+pub fn temperature_interval_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).expect("Failed to parse input code.");
+    temperature_interval::derive(&ast)
+}
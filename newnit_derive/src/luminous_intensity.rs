@@ -7,9 +7,7 @@ use crate::impl_quantity::{QuantityArgs, impl_quantity};
 pub fn derive(ast: &syn::DeriveInput) -> TokenStream {
     let args = QuantityArgs::from_derive_input(ast).expect("Failed parsing derive arguments.");
 
-    let unit = &ast.ident;
-
     let quantity = Ident::new("LuminousIntensity", Span::call_site());
 
-    impl_quantity(unit, &quantity, &args)
+    impl_quantity(ast, &quantity, &quantity, &args)
 }
@@ -0,0 +1,22 @@
+use darling::FromDeriveInput;
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+
+use crate::impl_quantity::{QuantityArgs, impl_quantity};
+use crate::storage::Storage;
+
+pub fn derive(ast: &syn::DeriveInput) -> TokenStream {
+    let mut args = QuantityArgs::from_derive_input(ast).expect("Failed parsing derive arguments.");
+
+    // Absolute pressures can't be negative, their differences are pressure
+    // differences, stored in the same type as the pressure.
+    args.interval.get_or_insert_with(|| {
+        let value = Storage::new(ast).value;
+        syn::parse_quote!(::newnit::pressure::metric::PascalDifferential<#value>)
+    });
+    args.difference = Some(Ident::new("PressureDifference", Span::call_site()));
+
+    let quantity = Ident::new("Pressure", Span::call_site());
+
+    impl_quantity(ast, &quantity, &quantity, &args)
+}
@@ -0,0 +1,161 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::punctuated::Punctuated;
+use syn::{Fields, GenericParam, Token, Type, WherePredicate};
+
+/// Primitive types for which multiplication from the left hand side is
+/// provided for units generic over their value type.
+const PRIMITIVES: [&str; 12] = [
+    "f32", "f64", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128",
+];
+
+/// Unsigned primitive types, which don't support negation.
+const UNSIGNED: [&str; 6] = ["u8", "u16", "u32", "u64", "u128", "usize"];
+
+/// Storage of the value of a unit.
+///
+/// Units are tuple structs whose first field stores their value, either as a
+/// concrete scalar type (e.g. `struct Inch(f64)`) or as one of the type
+/// parameters of the struct (e.g. `struct Inch<V = f64>(V)`).
+pub(crate) struct Storage {
+    /// The unit type, including its type parameters.
+    pub(crate) unit: TokenStream2,
+    /// The type of the stored value.
+    pub(crate) value: Type,
+    /// Whether the value type is a type parameter of the unit.
+    pub(crate) generic: bool,
+    /// The default of the value type (e.g. `f64` of `Inch<V = f64>`), if the
+    /// value type is the only type parameter of the unit.
+    pub(crate) default: Option<Type>,
+    /// Type parameters of impls for the unit (with a trailing comma).
+    params: Punctuated<GenericParam, Token![,]>,
+    /// Where predicates of impls for the unit (with a trailing comma).
+    predicates: Punctuated<WherePredicate, Token![,]>,
+}
+
+impl Storage {
+    pub(crate) fn new(ast: &syn::DeriveInput) -> Self {
+        let value = match &ast.data {
+            syn::Data::Struct(data) => match &data.fields {
+                Fields::Unnamed(fields) => fields.unnamed.first().map(|field| field.ty.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+        .expect("Units must be tuple structs storing their value in the first field.");
+
+        let generic = ast.generics.type_params().any(|param| {
+            matches!(&value, Type::Path(path) if path.qself.is_none() && path.path.is_ident(&param.ident))
+        });
+
+        let default = match ast.generics.params.len() {
+            1 if generic => ast
+                .generics
+                .type_params()
+                .next()
+                .and_then(|param| param.default.clone()),
+            _ => None,
+        };
+
+        let mut params = ast.generics.params.clone();
+        for param in params.iter_mut() {
+            if let GenericParam::Type(param) = param {
+                param.eq_token = None;
+                param.default = None;
+            }
+        }
+        if !params.empty_or_trailing() {
+            params.push_punct(Default::default());
+        }
+
+        let mut predicates = ast
+            .generics
+            .where_clause
+            .as_ref()
+            .map(|clause| clause.predicates.clone())
+            .unwrap_or_default();
+        if generic {
+            predicates.push(syn::parse_quote!(#value: ::newnit::scalar::Scalar));
+        }
+        if !predicates.empty_or_trailing() {
+            predicates.push_punct(Default::default());
+        }
+
+        let name = &ast.ident;
+        let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+        Self {
+            unit: quote! { #name #ty_generics },
+            value,
+            generic,
+            default,
+            params,
+            predicates,
+        }
+    }
+
+    /// Type parameters of an impl for the unit, followed by `extra` ones.
+    pub(crate) fn params(&self, extra: TokenStream2) -> TokenStream2 {
+        let params = &self.params;
+        quote! { <#params #extra> }
+    }
+
+    /// Type parameters of an impl for the unit, preceded by the `lifetime`.
+    pub(crate) fn params_with_lifetime(&self, lifetime: TokenStream2) -> TokenStream2 {
+        let params = &self.params;
+        quote! { <#lifetime, #params> }
+    }
+
+    /// Where clause of an impl for the unit, followed by `extra` predicates.
+    pub(crate) fn where_clause(&self, extra: TokenStream2) -> TokenStream2 {
+        let predicates = &self.predicates;
+        quote! { where #predicates #extra }
+    }
+
+    /// Whether the value type supports negation.
+    ///
+    /// Only known for concrete types, generic value types are bounded instead.
+    pub(crate) fn is_signed(&self) -> bool {
+        let value = self.value.to_token_stream().to_string();
+        !UNSIGNED.contains(&value.as_str())
+    }
+
+    /// Impls of multiplication of the unit from the left hand side by its
+    /// scalar type.
+    ///
+    /// These can't be provided generically for foreign scalar types, so for
+    /// units generic over their value type they are provided for primitives
+    /// only (and only if the value type is the only type parameter).
+    pub(crate) fn scalar_mul_impls(&self, name: &syn::Ident) -> TokenStream2 {
+        let scalar_mul = |scalar: &TokenStream2, unit: &TokenStream2| {
+            quote! {
+                impl ::core::ops::Mul<#unit> for #scalar {
+                    type Output = #unit;
+
+                    fn mul(self, rhs: #unit) -> Self::Output {
+                        rhs * self
+                    }
+                }
+            }
+        };
+
+        if !self.generic {
+            return match self.params.is_empty() {
+                true => scalar_mul(&self.value.to_token_stream(), &self.unit),
+                false => TokenStream2::new(),
+            };
+        }
+
+        if self.params.len() != 1 {
+            return TokenStream2::new();
+        }
+
+        PRIMITIVES
+            .iter()
+            .map(|primitive| {
+                let scalar = syn::Ident::new(primitive, proc_macro2::Span::call_site());
+                scalar_mul(&quote! { #scalar }, &quote! { #name<#scalar> })
+            })
+            .collect()
+    }
+}
@@ -3,13 +3,19 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 
 use crate::impl_quantity::{QuantityArgs, impl_quantity};
+use crate::storage::Storage;
 
 pub fn derive(ast: &syn::DeriveInput) -> TokenStream {
-    let args = QuantityArgs::from_derive_input(ast).expect("Failed parsing derive arguments.");
+    let mut args = QuantityArgs::from_derive_input(ast).expect("Failed parsing derive arguments.");
 
-    let unit = &ast.ident;
+    // Temperatures are always points on the thermodynamic scale, with
+    // intervals stored in the same type as the temperature.
+    args.interval.get_or_insert_with(|| {
+        let value = Storage::new(ast).value;
+        syn::parse_quote!(::newnit::temperature::DeltaKelvin<#value>)
+    });
 
     let quantity = Ident::new("Temperature", Span::call_site());
 
-    impl_quantity(unit, &quantity, &args)
+    impl_quantity(ast, &quantity, &quantity, &args)
 }
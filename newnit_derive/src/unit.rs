@@ -1,14 +1,43 @@
 use darling::FromDeriveInput;
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
+use syn::{BinOp, Expr, Lit, UnOp};
+
+use crate::storage::Storage;
 
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(unit))]
 struct UnitArgs {
-    factor: f64,
+    factor: Expr,
+
+    #[darling(default)] // Default to 0 if missing
+    offset: Option<Expr>,
+
+    /// Unit the factor and offset are relative to, instead of the base unit.
+    #[darling(default)]
+    of: Option<syn::Path>,
 
-    #[darling(default)] // Default to 0.0 if missing
-    offset: f64,
+    /// Symbol of the unit, used to parse and display values of the unit.
+    #[darling(default)]
+    symbol: Option<String>,
+
+    /// Singular name of the unit.
+    #[darling(default)]
+    name: Option<String>,
+
+    /// Plural name of the unit, defaults to the name.
+    #[darling(default)]
+    plural: Option<String>,
+
+    /// System of measurement of the unit (a variant of `System`).
+    #[darling(default)]
+    system: Option<syn::Ident>,
+
+    /// Alternative symbols or names of the unit, used to parse values of the
+    /// unit.
+    #[darling(multiple)]
+    alias: Vec<String>,
 
     #[darling(default)]
     display: bool,
@@ -17,46 +46,191 @@ struct UnitArgs {
 pub fn derive(ast: &syn::DeriveInput) -> TokenStream {
     let args = UnitArgs::from_derive_input(ast).expect("Missing factor for unit conversion.");
 
+    let storage = Storage::new(ast);
+    let unit = &storage.unit;
+    let value = &storage.value;
+    let params = storage.params(quote! {});
+    let where_clause = storage.where_clause(quote! {});
+
     let name = &ast.ident;
-    let factor = args.factor;
-    let offset = args.offset;
+    let mut factor = rational(&args.factor);
+    let mut offset = match &args.offset {
+        Some(offset) => rational(offset),
+        None => quote! { ::newnit::rational::Rational::ZERO },
+    };
+
+    // value_in_base_unit = (value * factor + offset) * of::FACTOR + of::OFFSET
+    if let Some(of) = &args.of {
+        let of = quote! { <#of as ::newnit::unit::Conversion> };
+        offset = quote! { #offset.product(#of::FACTOR).sum(#of::OFFSET) };
+        factor = quote! { #factor.product(#of::FACTOR) };
+    }
+
+    let symbol = args.symbol.unwrap_or_else(|| name.to_string());
+    let singular = args.name.unwrap_or_else(|| name.to_string());
+    let plural = args.plural.unwrap_or_else(|| singular.clone());
+    let system = args.system.unwrap_or_else(|| syn::parse_quote!(Other));
+    let aliases = &args.alias;
 
+    let one = quote! {
+        <#value as ::newnit::scalar::Scalar>::from_rational(::newnit::rational::Rational::ONE)
+    };
     let impl_display = args.display.then(|| {
         quote! {
-            impl std::fmt::Display for #name {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    write!(f, "{} {}", self.to_value(), stringify!(#name))
+            /// Displays the value with the symbol of the unit (e.g. `3 km`),
+            /// or with its name in the alternate form (e.g. `3 kilometres`).
+            impl #params ::core::fmt::Display for #unit #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.0, f)?;
+
+                    let unit = match f.alternate() {
+                        false => #symbol,
+                        true if self.0 == #one => #singular,
+                        true => #plural,
+                    };
+                    ::core::write!(f, " {}", unit)
                 }
             }
 
         }
     });
 
+    // Float literals (e.g. `Meter(2.0)`) aren't inferred to be of the default
+    // value type of a generic unit, so the methods returning the value are
+    // also provided for it directly, taking precedence over those of `Unit`.
+    let impl_default = storage.default.as_ref().map(|default| {
+        quote! {
+            impl #name<#default> {
+                /// Returns the wrapped value.
+                pub fn to_value(&self) -> #default {
+                    self.0
+                }
+
+                /// Converts the value of the unit to its equivalent value in
+                /// the base unit.
+                pub fn to_base(&self) -> #default {
+                    <Self as ::newnit::unit::Conversion>::to_base_value(self.0)
+                }
+            }
+        }
+    });
+
     let generated = quote! {
-        impl Unit for #name {
-            fn to_base(&self) -> f64 {
-                self.to_value() * #factor + #offset
+        impl #params ::newnit::Unit<#value> for #unit #where_clause {
+            fn to_base(&self) -> #value {
+                <Self as ::newnit::unit::Conversion>::to_base_value(self.0)
             }
 
-            fn to_value(&self) -> f64 {
+            fn to_value(&self) -> #value {
                 self.0
             }
 
-            fn set_value(&mut self, value: f64) {
+            fn set_value(&mut self, value: #value) {
                 self.0 = value;
             }
 
-            fn set_from_base(&mut self, base: f64) {
-                self.0 = (base - #offset) / #factor;
+            fn set_from_base(&mut self, base: #value) {
+                self.0 = <Self as ::newnit::unit::Conversion>::from_base_value(base);
+            }
+
+            fn from_base(base: #value) -> Self {
+                Self(<Self as ::newnit::unit::Conversion>::from_base_value(base))
+            }
+
+            fn from_value(value: #value) -> Self {
+                Self(value)
+            }
+
+            fn symbol(&self) -> &'static str {
+                #symbol
             }
 
-            fn from_base(base: f64) -> Self {
-                Self((base - #offset) / #factor)
+            fn name(&self) -> &'static str {
+                #singular
             }
+
+            fn plural(&self) -> &'static str {
+                #plural
+            }
+
+            fn system(&self) -> ::newnit::unit::System {
+                ::newnit::unit::System::#system
+            }
+        }
+
+        impl #params ::newnit::unit::Conversion for #unit #where_clause {
+            const FACTOR: ::newnit::rational::Rational = #factor;
+            const OFFSET: ::newnit::rational::Rational = #offset;
+        }
+
+        impl #params ::newnit::unit::Metadata for #unit #where_clause {
+            const SYMBOL: &'static str = #symbol;
+            const NAME: &'static str = #singular;
+            const PLURAL: &'static str = #plural;
+            const SYSTEM: ::newnit::unit::System = ::newnit::unit::System::#system;
+            const ALIASES: &'static [&'static str] = &[#(#aliases),*];
         }
 
         #impl_display
 
+        #impl_default
     };
     generated.into()
 }
+
+/// Exact rational value of a conversion factor or offset.
+///
+/// The expression may be a (decimal) literal, a path to a `Rational` constant
+/// (e.g. `Rational::PI`), or an arithmetic expression of them (e.g. `5 / 9`,
+/// `273.15 - 32 * 5 / 9`).
+fn rational(expr: &Expr) -> TokenStream2 {
+    match expr {
+        Expr::Lit(expr) => match &expr.lit {
+            Lit::Int(lit) => decimal(lit.base10_digits()),
+            Lit::Float(lit) => decimal(lit.base10_digits()),
+            _ => panic!("Conversion factors must be numbers."),
+        },
+        Expr::Binary(expr) => {
+            let left = rational(&expr.left);
+            let right = rational(&expr.right);
+            match expr.op {
+                BinOp::Add(_) => quote! { #left.sum(#right) },
+                BinOp::Sub(_) => quote! { #left.difference(#right) },
+                BinOp::Mul(_) => quote! { #left.product(#right) },
+                BinOp::Div(_) => quote! { #left.quotient(#right) },
+                _ => panic!("Conversion factors support only `+`, `-`, `*` and `/`."),
+            }
+        }
+        Expr::Unary(expr) if matches!(expr.op, UnOp::Neg(_)) => {
+            let value = rational(&expr.expr);
+            quote! { #value.negated() }
+        }
+        Expr::Path(expr) => quote! { #expr },
+        Expr::Paren(expr) => rational(&expr.expr),
+        Expr::Group(expr) => rational(&expr.expr),
+        _ => panic!(
+            "Conversion factors must be numbers, constants or arithmetic expressions of them."
+        ),
+    }
+}
+
+/// Exact rational value of a decimal literal (e.g. `0.3048`, `1E+6`).
+fn decimal(digits: &str) -> TokenStream2 {
+    let (mantissa, exponent) = match digits.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent
+                .parse::<i32>()
+                .expect("Invalid exponent of conversion factor."),
+        ),
+        None => (digits, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let numer = format!("{integer}{fraction}")
+        .parse::<i128>()
+        .expect("Too many digits in conversion factor.");
+    let exponent = exponent - fraction.len() as i32;
+
+    quote! { ::newnit::rational::Rational::new(#numer, 1, #exponent) }
+}